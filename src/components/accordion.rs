@@ -0,0 +1,409 @@
+//! Accordion Component - Vertically stacked headings that reveal sections of content
+//!
+//! Features:
+//! - ✅ type="single" (optionally collapsible) or type="multiple"
+//! - ✅ Controlled/uncontrolled open items (value, default_value, on_value_change)
+//! - ✅ Keyboard: arrows move between headers (orientation and RTL aware), Home/End
+//! - ✅ Measured content size as `--accordion-content-height`/`--accordion-content-width`
+//! - ✅ Built on Collapsible: content stays mounted until its closing animation ends
+//! - ✅ data-state open/closed and data-disabled, like Checkbox
+
+use super::collapsible::{Collapsible, CollapsibleContent, CollapsibleTrigger};
+use crate::hooks::{use_controllable_state, use_related_ids};
+use crate::utils::{get_roving_focus_movement, move_index, query_elements, JumpKeys, Movement};
+use leptos::context::Provider;
+use leptos::ev;
+use leptos::html::Div;
+use leptos::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::HtmlElement;
+
+/// Selector for the triggers that can receive focus
+const TRIGGER_SELECTOR: &str = "[data-accordion-trigger]:not([disabled])";
+
+/// Helper function to generate accordion root CSS classes
+fn get_accordion_classes(user_class: String) -> String {
+    let base = "w-full data-[orientation=horizontal]:flex";
+
+    format!("{base} {user_class}")
+}
+
+/// Helper function to generate accordion item CSS classes
+fn get_accordion_item_classes(user_class: String) -> String {
+    let base = "border-b border-white/20 last:border-b-0";
+
+    format!("{base} {user_class}")
+}
+
+/// Helper function to generate accordion trigger CSS classes
+fn get_accordion_trigger_classes(user_class: String) -> String {
+    let base = "flex w-full flex-1 items-center justify-between gap-2 py-2 text-left text-sm font-medium text-white transition-all duration-200";
+    let focus = "focus:outline-none focus-visible:ring-2 focus-visible:ring-black";
+    let states = "hover:underline [&[data-state=open]>svg]:rotate-180";
+    let disabled = "data-[disabled]:opacity-50 data-[disabled]:cursor-not-allowed data-[disabled]:hover:no-underline";
+
+    format!("{base} {focus} {states} {disabled} {user_class}")
+}
+
+/// Helper function to generate accordion content CSS classes
+fn get_accordion_content_classes(user_class: String) -> String {
+    let base = "text-sm text-white/80";
+    // Re-export the collapsible's measured size under the accordion's names
+    let size = "[--accordion-content-height:var(--collapsible-content-height)] [--accordion-content-width:var(--collapsible-content-width)]";
+
+    format!("{base} {size} {user_class}")
+}
+
+/// Movement for a navigation key between accordion headers
+///
+/// Only the arrows along `orientation` move; `rtl` swaps Left and Right.
+pub fn get_accordion_movement(key: &str, orientation: &str, rtl: bool) -> Option<Movement> {
+    get_roving_focus_movement(key, Some(orientation), rtl, JumpKeys::HomeEnd)
+}
+
+/// Open items after toggling `item`
+///
+/// In single mode opening an item closes the others, and the open item only
+/// closes when `collapsible` is set.
+pub(crate) fn toggle_accordion_value(
+    open_items: &[String],
+    item: &str,
+    multiple: bool,
+    collapsible: bool,
+) -> Vec<String> {
+    let is_open = open_items.iter().any(|open| open == item);
+
+    match (multiple, is_open) {
+        (true, true) => open_items
+            .iter()
+            .filter(|open| *open != item)
+            .cloned()
+            .collect(),
+        (true, false) => {
+            let mut next = open_items.to_vec();
+            next.push(item.to_string());
+            next
+        }
+        (false, true) if collapsible => Vec::new(),
+        (false, true) => open_items.to_vec(),
+        (false, false) => vec![item.to_string()],
+    }
+}
+
+/// Context value shared between Accordion and its items
+#[derive(Clone, Copy)]
+pub struct AccordionContextValue {
+    /// Values of the open items
+    pub value: Signal<Vec<String>>,
+    pub multiple: Signal<bool>,
+    pub collapsible: Signal<bool>,
+    pub disabled: Signal<bool>,
+    pub orientation: Signal<String>,
+    /// Open or close an item by value
+    pub toggle: Callback<String>,
+}
+
+/// Context value shared between AccordionItem and its parts
+#[derive(Clone, Copy)]
+pub struct AccordionItemContextValue {
+    pub open: Signal<bool>,
+    pub disabled: Signal<bool>,
+    pub trigger_id: Signal<String>,
+}
+
+/// Accordion component - Hook-first implementation
+///
+/// Uses our proven hook library for state management and ARIA compliance.
+/// Styled with Tailwind CSS 4 ONLY - no custom CSS allowed.
+///
+/// `value` lists the open items in both modes (at most one in single mode).
+///
+/// # Example
+/// ```rust,ignore
+/// view! {
+///     <Accordion r#type="single" collapsible=true default_value=vec!["shipping".to_string()]>
+///         <AccordionItem value="shipping">
+///             <AccordionHeader>
+///                 <AccordionTrigger>"Do you ship abroad?"</AccordionTrigger>
+///             </AccordionHeader>
+///             <AccordionContent>"Yes, to most countries."</AccordionContent>
+///         </AccordionItem>
+///     </Accordion>
+/// }
+/// ```
+#[component]
+pub fn Accordion(
+    /// "single" (default) or "multiple"
+    #[prop(into, optional)]
+    r#type: MaybeProp<String>,
+
+    // Core state management (from our hook library)
+    #[prop(into, optional)] value: MaybeProp<Vec<String>>,
+    #[prop(into, optional)] default_value: MaybeProp<Vec<String>>,
+    #[prop(into, optional)] on_value_change: Option<Callback<Vec<String>>>,
+
+    /// In single mode, allow closing the open item (default: false)
+    #[prop(into, optional)]
+    collapsible: MaybeProp<bool>,
+    #[prop(into, optional)] disabled: MaybeProp<bool>,
+
+    // Keyboard navigation
+    /// "vertical" (default) or "horizontal"
+    #[prop(into, optional)]
+    orientation: MaybeProp<String>,
+    /// Reading direction ("ltr" or "rtl") for Left/Right
+    #[prop(into, optional)]
+    dir: MaybeProp<String>,
+
+    // Styling
+    #[prop(into, optional)] class: MaybeProp<String>,
+
+    children: Children,
+) -> impl IntoView {
+    // Phase 0: Compose hooks - no manual state management!
+    let state = use_controllable_state(value, default_value, on_value_change);
+    let root_ref = NodeRef::<Div>::new();
+
+    // Pre-compute common values to reduce complexity
+    let is_multiple = Signal::derive(move || r#type.get().as_deref() == Some("multiple"));
+    let is_collapsible = Signal::derive(move || collapsible.get().unwrap_or(false));
+    let is_disabled = Signal::derive(move || disabled.get().unwrap_or(false));
+    let current_orientation =
+        Signal::derive(move || orientation.get().unwrap_or_else(|| "vertical".to_string()));
+
+    let toggle = Callback::new(move |item: String| {
+        let open_items = state.value.get_untracked();
+        let next = toggle_accordion_value(
+            &open_items,
+            &item,
+            is_multiple.get_untracked(),
+            is_collapsible.get_untracked(),
+        );
+        if next != open_items {
+            state.set_value.run(next);
+        }
+    });
+
+    // Context for child components
+    let context_value = AccordionContextValue {
+        value: state.value,
+        multiple: is_multiple,
+        collapsible: is_collapsible,
+        disabled: is_disabled,
+        orientation: current_orientation,
+        toggle,
+    };
+
+    // Arrow keys move focus between the headers of this accordion
+    let on_keydown = move |ev: ev::KeyboardEvent| {
+        let Some(root) = root_ref.get_untracked() else {
+            return;
+        };
+        let triggers: Vec<HtmlElement> = query_elements(&root, TRIGGER_SELECTOR)
+            .into_iter()
+            .filter(|trigger| {
+                trigger
+                    .closest("[data-accordion-root]")
+                    .ok()
+                    .flatten()
+                    .is_some_and(|owner| owner == **root)
+            })
+            .collect();
+        let Some(current) = ev
+            .target()
+            .and_then(|target| target.dyn_into::<HtmlElement>().ok())
+            .and_then(|target| triggers.iter().position(|trigger| *trigger == target))
+        else {
+            return;
+        };
+        let rtl = dir.get_untracked().as_deref() == Some("rtl");
+        let Some(movement) =
+            get_accordion_movement(&ev.key(), &current_orientation.get_untracked(), rtl)
+        else {
+            return;
+        };
+
+        ev.prevent_default();
+        if let Some(next) = move_index(Some(current), triggers.len(), movement, true)
+            .and_then(|index| triggers.get(index))
+        {
+            let _ = next.focus();
+        }
+    };
+
+    view! {
+        <Provider value=context_value>
+            <div
+                node_ref=root_ref
+                data-accordion-root=""
+                dir=move || dir.get()
+                data-orientation=move || current_orientation.get()
+                data-disabled=move || if is_disabled.get() { Some("") } else { None }
+                // 🚨 TAILWIND CSS 4 ONLY - Layout follows the orientation
+                class=move || get_accordion_classes(class.get().unwrap_or_default())
+                on:keydown=on_keydown
+            >
+                {children()}
+            </div>
+        </Provider>
+    }
+}
+
+/// AccordionItem - One collapsible section
+#[component]
+pub fn AccordionItem(
+    #[prop(into)] value: String,
+    #[prop(into, optional)] disabled: MaybeProp<bool>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+
+    children: Children,
+) -> impl IntoView {
+    let context = expect_context::<AccordionContextValue>();
+    let item_value = StoredValue::new(value);
+    let ids = use_related_ids("accordion");
+
+    // Pre-compute common values to reduce complexity
+    let is_open = Signal::derive(move || {
+        item_value.with_value(|value| context.value.with(|open| open.contains(value)))
+    });
+    let is_disabled =
+        Signal::derive(move || context.disabled.get() || disabled.get().unwrap_or(false));
+
+    // Context for the header, trigger and content
+    let item_context = AccordionItemContextValue {
+        open: is_open,
+        disabled: is_disabled,
+        trigger_id: ids.trigger_id,
+    };
+
+    view! {
+        <Provider value=item_context>
+            // Each item is a collapsible controlled by the accordion's value
+            <Collapsible
+                open=is_open
+                disabled=is_disabled
+                on_open_change=Callback::new(move |_| context.toggle.run(item_value.get_value()))
+                class=Signal::derive(move || get_accordion_item_classes(class.get().unwrap_or_default()))
+                attr:data-orientation=move || context.orientation.get()
+            >
+                {children()}
+            </Collapsible>
+        </Provider>
+    }
+}
+
+/// AccordionHeader - Heading that wraps the trigger
+#[component]
+pub fn AccordionHeader(
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: Children,
+) -> impl IntoView {
+    let context = expect_context::<AccordionContextValue>();
+    let item = expect_context::<AccordionItemContextValue>();
+
+    view! {
+        <h3
+            data-state=move || if item.open.get() { "open" } else { "closed" }
+            data-disabled=move || if item.disabled.get() { Some("") } else { None }
+            data-orientation=move || context.orientation.get()
+            class=move || format!("flex {}", class.get().unwrap_or_default())
+        >
+            {children()}
+        </h3>
+    }
+}
+
+/// AccordionTrigger - Button that opens and closes its item
+#[component]
+pub fn AccordionTrigger(
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: Children,
+) -> impl IntoView {
+    let context = expect_context::<AccordionContextValue>();
+    let item = expect_context::<AccordionItemContextValue>();
+
+    // The open item of a non-collapsible single accordion cannot be closed
+    let is_locked = Signal::derive(move || {
+        item.open.get() && !context.multiple.get() && !context.collapsible.get()
+    });
+
+    view! {
+        <CollapsibleTrigger
+            class=Signal::derive(move || get_accordion_trigger_classes(class.get().unwrap_or_default()))
+            attr:id=move || item.trigger_id.get()
+            attr:data-accordion-trigger=""
+            attr:aria-disabled=move || if is_locked.get() { Some("true") } else { None }
+            attr:data-orientation=move || context.orientation.get()
+        >
+            {children()}
+        </CollapsibleTrigger>
+    }
+}
+
+/// AccordionContent - The collapsible region of an item
+///
+/// Exposes its measured size as `--accordion-content-height` and
+/// `--accordion-content-width` so Tailwind keyframes can animate between
+/// zero and the real size.
+#[component]
+pub fn AccordionContent(
+    /// Keep the content in the DOM (hidden) while closed
+    #[prop(into, optional)]
+    force_mount: MaybeProp<bool>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+
+    children: ChildrenFn,
+) -> impl IntoView {
+    let context = expect_context::<AccordionContextValue>();
+    let item = expect_context::<AccordionItemContextValue>();
+
+    view! {
+        <CollapsibleContent
+            force_mount=force_mount
+            class=Signal::derive(move || get_accordion_content_classes(class.get().unwrap_or_default()))
+            attr:role="region"
+            attr:aria-labelledby=move || item.trigger_id.get()
+            attr:data-orientation=move || context.orientation.get()
+        >
+            {children()}
+        </CollapsibleContent>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn items(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn test_single_mode_opens_one_item() {
+        assert_eq!(
+            toggle_accordion_value(&items(&["a"]), "b", false, false),
+            items(&["b"])
+        );
+    }
+
+    #[test]
+    fn test_single_mode_collapsible() {
+        assert_eq!(
+            toggle_accordion_value(&items(&["a"]), "a", false, false),
+            items(&["a"])
+        );
+        assert!(toggle_accordion_value(&items(&["a"]), "a", false, true).is_empty());
+    }
+
+    #[test]
+    fn test_multiple_mode_toggles_independently() {
+        assert_eq!(
+            toggle_accordion_value(&items(&["a"]), "b", true, false),
+            items(&["a", "b"])
+        );
+        assert_eq!(
+            toggle_accordion_value(&items(&["a", "b"]), "a", true, false),
+            items(&["b"])
+        );
+    }
+}
@@ -0,0 +1,168 @@
+//! AlertDialog Component - Modal confirmation that interrupts the user
+//!
+//! Features:
+//! - ✅ role="alertdialog" with aria-labelledby / aria-describedby
+//! - ✅ Forced choice: outside clicks never dismiss, only Action / Cancel / Escape
+//! - ✅ Initial focus lands on AlertDialogCancel (the safe choice)
+//! - ✅ Built on use_dialog_behavior (use_escape_key_when + use_related_ids)
+//! - ✅ Shares Dialog's portal, overlay, title and description parts
+
+use super::dialog::{
+    get_dialog_button_classes, DialogContentView, DialogContextValue, DialogDescription,
+    DialogOverlay, DialogPortal, DialogTitle, DialogTrigger,
+};
+use crate::hooks::{use_dialog_behavior, UseDialogBehaviorOptions};
+use leptos::context::Provider;
+use leptos::prelude::*;
+
+/// Attribute marking the cancel button so the focus trap can find it on open
+const CANCEL_MARKER: &str = "data-alert-dialog-cancel";
+
+/// AlertDialog component - Hook-first implementation
+///
+/// # Example
+/// ```rust,ignore
+/// view! {
+///     <AlertDialog>
+///         <AlertDialogTrigger>"Delete workspace"</AlertDialogTrigger>
+///         <AlertDialogPortal>
+///             <AlertDialogOverlay />
+///             <AlertDialogContent>
+///                 <AlertDialogTitle>"Are you absolutely sure?"</AlertDialogTitle>
+///                 <AlertDialogDescription>"This cannot be undone."</AlertDialogDescription>
+///                 <AlertDialogCancel>"Cancel"</AlertDialogCancel>
+///                 <AlertDialogAction on_click=delete>"Yes, delete"</AlertDialogAction>
+///             </AlertDialogContent>
+///         </AlertDialogPortal>
+///     </AlertDialog>
+/// }
+/// ```
+#[component]
+pub fn AlertDialog(
+    // Core state management (from our hook library)
+    #[prop(into, optional)] open: MaybeProp<bool>,
+    #[prop(into, optional)] default_open: MaybeProp<bool>,
+    #[prop(into, optional)] on_open_change: Option<Callback<bool>>,
+
+    children: Children,
+) -> impl IntoView {
+    let dialog = use_dialog_behavior(
+        open,
+        default_open,
+        on_open_change,
+        UseDialogBehaviorOptions {
+            close_on_outside_click: false,
+            initial_focus: Some(format!("[{CANCEL_MARKER}]")),
+            id_prefix: "alert-dialog",
+            ..Default::default()
+        },
+    );
+
+    view! {
+        <Provider value=DialogContextValue::new(dialog)>
+            {children()}
+        </Provider>
+    }
+}
+
+/// AlertDialogTrigger - Button that opens the alert dialog
+#[component]
+pub fn AlertDialogTrigger(
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: Children,
+) -> impl IntoView {
+    view! { <DialogTrigger class=class>{children()}</DialogTrigger> }
+}
+
+/// AlertDialogPortal - Teleports the overlay and content into `document.body` while open
+#[component]
+pub fn AlertDialogPortal(
+    #[prop(into, optional)] force_mount: MaybeProp<bool>,
+    children: ChildrenFn,
+) -> impl IntoView {
+    view! { <DialogPortal force_mount=force_mount children=children /> }
+}
+
+/// AlertDialogOverlay - Dimmed layer behind the content (clicks on it are ignored)
+#[component]
+pub fn AlertDialogOverlay(#[prop(into, optional)] class: MaybeProp<String>) -> impl IntoView {
+    view! { <DialogOverlay class=class /> }
+}
+
+/// AlertDialogContent - The alert window itself (`role="alertdialog"`)
+#[component]
+pub fn AlertDialogContent(
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: ChildrenFn,
+) -> impl IntoView {
+    view! { <DialogContentView role="alertdialog" class=class children=children /> }
+}
+
+/// AlertDialogTitle - Accessible name of the alert dialog
+#[component]
+pub fn AlertDialogTitle(
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: Children,
+) -> impl IntoView {
+    view! { <DialogTitle class=class>{children()}</DialogTitle> }
+}
+
+/// AlertDialogDescription - Accessible description of the alert dialog
+#[component]
+pub fn AlertDialogDescription(
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: Children,
+) -> impl IntoView {
+    view! { <DialogDescription class=class>{children()}</DialogDescription> }
+}
+
+/// AlertDialogAction - Confirms the action, then closes the dialog
+#[component]
+pub fn AlertDialogAction(
+    #[prop(into, optional)] on_click: Option<Callback<()>>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: Children,
+) -> impl IntoView {
+    let DialogContextValue { dialog, .. } = expect_context::<DialogContextValue>();
+
+    view! {
+        <button
+            type="button"
+            class=move || get_dialog_button_classes(class.get().unwrap_or_default())
+            on:click=move |_| {
+                if let Some(callback) = on_click {
+                    callback.run(());
+                }
+                dialog.close.run(());
+            }
+        >
+            {children()}
+        </button>
+    }
+}
+
+/// AlertDialogCancel - Dismisses the dialog; receives focus when it opens
+#[component]
+pub fn AlertDialogCancel(
+    #[prop(into, optional)] on_click: Option<Callback<()>>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: Children,
+) -> impl IntoView {
+    let DialogContextValue { dialog, .. } = expect_context::<DialogContextValue>();
+
+    view! {
+        <button
+            type="button"
+            data-alert-dialog-cancel=""
+            class=move || get_dialog_button_classes(class.get().unwrap_or_default())
+            on:click=move |_| {
+                if let Some(callback) = on_click {
+                    callback.run(());
+                }
+                dialog.close.run(());
+            }
+        >
+            {children()}
+        </button>
+    }
+}
@@ -0,0 +1,171 @@
+//! Avatar Component - An image with a fallback for representing a user
+//!
+//! Features:
+//! - ✅ Image only shows once it has loaded (use_image_loading_status)
+//! - ✅ Loading status (idle, loading, loaded, error) as a signal and data-status
+//! - ✅ Fallback (e.g. initials) after an optional delay_ms, avoiding flicker on fast loads
+//! - ✅ Server render shows the fallback; the client hydrates the same markup
+
+use crate::hooks::{use_image_loading_status, ImageLoadingStatus};
+use leptos::context::Provider;
+use leptos::prelude::*;
+use std::time::Duration;
+
+/// Helper function to generate avatar root CSS classes
+fn get_avatar_classes(user_class: String) -> String {
+    let base = "relative inline-flex h-10 w-10 shrink-0 select-none items-center justify-center overflow-hidden rounded-full align-middle";
+
+    format!("{base} {user_class}")
+}
+
+/// Helper function to generate avatar image CSS classes
+fn get_avatar_image_classes(user_class: String) -> String {
+    let base = "h-full w-full rounded-[inherit] object-cover";
+
+    format!("{base} {user_class}")
+}
+
+/// Helper function to generate avatar fallback CSS classes
+fn get_avatar_fallback_classes(user_class: String) -> String {
+    let base =
+        "flex h-full w-full items-center justify-center bg-white/10 text-sm font-medium text-white";
+
+    format!("{base} {user_class}")
+}
+
+/// Context value shared between Avatar and its parts
+#[derive(Clone, Copy)]
+pub struct AvatarContextValue {
+    /// Loading status of the AvatarImage (idle without one)
+    pub status: Signal<ImageLoadingStatus>,
+    set_status: RwSignal<ImageLoadingStatus>,
+}
+
+/// Avatar component - Hook-first implementation
+///
+/// Uses our proven hook library for state management and ARIA compliance.
+/// Styled with Tailwind CSS 4 ONLY - no custom CSS allowed.
+///
+/// # Example
+/// ```rust,ignore
+/// view! {
+///     <Avatar>
+///         <AvatarImage src="/users/colm.png" alt="Colm Tuite" />
+///         <AvatarFallback delay_ms=600>"CT"</AvatarFallback>
+///     </Avatar>
+/// }
+/// ```
+#[component]
+pub fn Avatar(
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: Children,
+) -> impl IntoView {
+    let status = RwSignal::new(ImageLoadingStatus::Idle);
+
+    // Context for child components
+    let context_value = AvatarContextValue {
+        status: status.into(),
+        set_status: status,
+    };
+
+    view! {
+        <Provider value=context_value>
+            <span
+                // Data attributes for Tailwind CSS 4 styling
+                data-status=move || status.get().to_string()
+                // 🚨 TAILWIND CSS 4 ONLY - Round, clipped frame
+                class=move || get_avatar_classes(class.get().unwrap_or_default())
+            >
+                {children()}
+            </span>
+        </Provider>
+    }
+}
+
+/// AvatarImage - The picture, rendered only once it has loaded
+#[component]
+pub fn AvatarImage(
+    #[prop(into)] src: Signal<String>,
+    #[prop(into, optional)] alt: MaybeProp<String>,
+    /// Called with every status after the image starts loading
+    #[prop(into, optional)]
+    on_loading_status_change: Option<Callback<ImageLoadingStatus>>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+) -> impl IntoView {
+    let context = expect_context::<AvatarContextValue>();
+    let status = use_image_loading_status(Signal::derive(move || Some(src.get())));
+
+    // Share the status with the root and the fallback
+    Effect::new(move |_| {
+        let status = status.get();
+        context.set_status.set(status);
+        if status != ImageLoadingStatus::Idle {
+            if let Some(callback) = on_loading_status_change {
+                callback.run(status);
+            }
+        }
+    });
+    on_cleanup(move || {
+        context.set_status.try_set(ImageLoadingStatus::Idle);
+    });
+
+    view! {
+        <Show when=move || status.get() == ImageLoadingStatus::Loaded>
+            <img
+                src=move || src.get()
+                alt=move || alt.get()
+                data-status=move || status.get().to_string()
+                // 🚨 TAILWIND CSS 4 ONLY - Fills the avatar frame
+                class=move || get_avatar_image_classes(class.get().unwrap_or_default())
+            />
+        </Show>
+    }
+}
+
+/// AvatarFallback - Shown until (or instead of) the loaded image
+///
+/// Without `delay_ms` it is part of the server render, so the page shows
+/// initials before hydration and the client hydrates the same markup.
+#[component]
+pub fn AvatarFallback(
+    /// Wait this long before showing the fallback (client only)
+    #[prop(into, optional)]
+    delay_ms: MaybeProp<u32>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+
+    children: ChildrenFn,
+) -> impl IntoView {
+    let context = expect_context::<AvatarContextValue>();
+    let can_render = RwSignal::new(delay_ms.get_untracked().is_none());
+
+    Effect::new(move |_| {
+        let Some(delay) = delay_ms.get() else {
+            return;
+        };
+        let handle = set_timeout_with_handle(
+            move || can_render.set(true),
+            Duration::from_millis(delay.into()),
+        )
+        .ok();
+        on_cleanup(move || {
+            if let Some(handle) = handle {
+                handle.clear();
+            }
+        });
+    });
+
+    let is_rendered =
+        move || can_render.get() && context.status.get() != ImageLoadingStatus::Loaded;
+
+    view! {
+        <Show when=is_rendered>
+            <span
+                data-status=move || context.status.get().to_string()
+                // 🚨 TAILWIND CSS 4 ONLY - Centered initials
+                class=move || get_avatar_fallback_classes(class.get().unwrap_or_default())
+            >
+                {children()}
+            </span>
+        </Show>
+    }
+}
@@ -1,6 +1,8 @@
-use crate::hooks::{use_checkbox_state, CheckedState, UseCheckboxStateReturn};
+use super::label::use_labelled_by;
+use crate::hooks::{use_checkbox_state, use_presence, CheckedState, UseCheckboxStateReturn};
 use leptos::context::Provider;
 use leptos::ev;
+use leptos::html::Div;
 use leptos::prelude::*;
 
 /// Helper function to generate checkbox CSS classes
@@ -43,6 +45,7 @@ fn CheckboxView(
     is_disabled: Signal<bool>,
     is_required: Signal<bool>,
     final_id: Signal<String>,
+    labelled_by: Signal<Option<String>>,
     input_value: Signal<String>,
     name: MaybeProp<String>,
     form: MaybeProp<String>,
@@ -64,6 +67,7 @@ fn CheckboxView(
                 class="absolute opacity-0 pointer-events-none"
                 style="position: absolute; opacity: 0; pointer-events: none; margin: 0; width: 1px; height: 1px;"
                 tabindex="-1"
+                aria-hidden="true"
             />
 
             <button
@@ -72,6 +76,7 @@ fn CheckboxView(
                 role="checkbox"
                 // ARIA attributes from our hook
                 aria-checked=move || checkbox_state.get_aria_checked.get()
+                aria-labelledby=move || labelled_by.get()
                 aria-disabled=move || if is_disabled.get() { Some("true") } else { None }
                 aria-required=move || if is_required.get() { Some("true") } else { None }
                 // Data attributes for Tailwind CSS 4 styling
@@ -128,6 +133,7 @@ pub fn Checkbox(
     let is_required = Signal::derive(move || required.get().unwrap_or(false));
     let final_id = Signal::derive(move || id.get().unwrap_or_else(|| "checkbox".to_string()));
     let input_value = Signal::derive(move || value.get().unwrap_or_else(|| "on".to_string()));
+    let labelled_by = use_labelled_by(final_id);
 
     // Context for child components
     let context_value = CheckboxContextValue {
@@ -142,6 +148,7 @@ pub fn Checkbox(
                 is_disabled=is_disabled
                 is_required=is_required
                 final_id=final_id
+                labelled_by=labelled_by
                 input_value=input_value
                 name=name
                 form=form
@@ -153,6 +160,8 @@ pub fn Checkbox(
 }
 
 /// CheckboxIndicator - Shows when checkbox is checked or indeterminate
+///
+/// Stays mounted while its `animate-out` classes run (use_presence).
 #[component]
 pub fn CheckboxIndicator(
     #[prop(into, optional)] force_mount: MaybeProp<bool>,
@@ -160,17 +169,20 @@ pub fn CheckboxIndicator(
     children: ChildrenFn,
 ) -> impl IntoView {
     let context = expect_context::<CheckboxContextValue>();
+    let indicator_ref = NodeRef::<Div>::new();
 
-    let is_present = Signal::derive(move || {
+    let is_visible = Signal::derive(move || {
         let state = context.state.get();
-        force_mount.get().unwrap_or(false)
-            || state == CheckedState::True
-            || state == CheckedState::Indeterminate
+        state == CheckedState::True || state == CheckedState::Indeterminate
     });
+    let presence = use_presence(is_visible, indicator_ref);
+    let is_present =
+        Signal::derive(move || force_mount.get().unwrap_or(false) || presence.is_present.get());
 
     view! {
         <Show when=move || is_present.get()>
             <div
+                node_ref=indicator_ref
                 data-state=move || match context.state.get() {
                     CheckedState::True => "checked",
                     CheckedState::False => "unchecked",
@@ -183,6 +195,7 @@ pub fn CheckboxIndicator(
                     let user = class.get().unwrap_or_default();
                     format!("{base} {animation} {user}")
                 }
+                on:animationend=move |ev| presence.on_animation_end.run(ev)
             >
                 {children()}
             </div>
@@ -0,0 +1,214 @@
+//! Collapsible Component - A panel that expands and collapses
+//!
+//! Features:
+//! - ✅ Controlled/uncontrolled open state (open, default_open, on_open_change)
+//! - ✅ Content stays mounted until its closing animation ends (use_presence)
+//! - ✅ Measured content size as `--collapsible-content-height`/`--collapsible-content-width`
+//! - ✅ aria-expanded/aria-controls wired through use_related_ids
+//! - ✅ Building block for Accordion
+
+use crate::hooks::{use_controllable_state, use_presence, use_related_ids};
+use leptos::context::Provider;
+use leptos::html::Div;
+use leptos::prelude::*;
+use web_sys::HtmlElement;
+
+/// Helper function to generate collapsible root CSS classes
+fn get_collapsible_classes(user_class: String) -> String {
+    let base = "w-full";
+
+    format!("{base} {user_class}")
+}
+
+/// Helper function to generate collapsible trigger CSS classes
+fn get_collapsible_trigger_classes(user_class: String) -> String {
+    let base = "text-sm font-medium text-white cursor-pointer";
+    let focus = "focus:outline-none focus-visible:ring-2 focus-visible:ring-black";
+    let disabled = "data-[disabled]:opacity-50 data-[disabled]:cursor-not-allowed";
+
+    format!("{base} {focus} {disabled} {user_class}")
+}
+
+/// Helper function to generate collapsible content CSS classes
+fn get_collapsible_content_classes(user_class: String) -> String {
+    let base = "overflow-hidden";
+    let animation =
+        "data-[state=open]:animate-collapsible-down data-[state=closed]:animate-collapsible-up";
+
+    format!("{base} {animation} {user_class}")
+}
+
+/// Context value shared between Collapsible and its parts
+#[derive(Clone, Copy)]
+pub struct CollapsibleContextValue {
+    pub open: Signal<bool>,
+    pub set_open: Callback<bool>,
+    pub disabled: Signal<bool>,
+    pub content_id: Signal<String>,
+}
+
+/// Collapsible component - Hook-first implementation
+///
+/// Uses our proven hook library for state management and ARIA compliance.
+/// Styled with Tailwind CSS 4 ONLY - no custom CSS allowed.
+///
+/// # Example
+/// ```rust,ignore
+/// view! {
+///     <Collapsible default_open=true>
+///         <CollapsibleTrigger>"3 starred repositories"</CollapsibleTrigger>
+///         <CollapsibleContent>
+///             <p>"leptos-rs/leptos"</p>
+///         </CollapsibleContent>
+///     </Collapsible>
+/// }
+/// ```
+#[component]
+pub fn Collapsible(
+    // Core state management (from our hook library)
+    #[prop(into, optional)] open: MaybeProp<bool>,
+    #[prop(into, optional)] default_open: MaybeProp<bool>,
+    #[prop(into, optional)] on_open_change: Option<Callback<bool>>,
+
+    #[prop(into, optional)] disabled: MaybeProp<bool>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+
+    children: Children,
+) -> impl IntoView {
+    // Phase 0: Compose hooks - no manual state management!
+    let state = use_controllable_state(
+        open,
+        default_open.get_untracked().unwrap_or(false).into(),
+        on_open_change,
+    );
+    let ids = use_related_ids("collapsible");
+
+    let is_disabled = Signal::derive(move || disabled.get().unwrap_or(false));
+
+    // Context for child components
+    let context_value = CollapsibleContextValue {
+        open: state.value,
+        set_open: state.set_value,
+        disabled: is_disabled,
+        content_id: ids.content_id,
+    };
+
+    view! {
+        <Provider value=context_value>
+            <div
+                // Data attributes for Tailwind CSS 4 styling
+                data-state=move || if state.value.get() { "open" } else { "closed" }
+                data-disabled=move || if is_disabled.get() { Some("") } else { None }
+                // 🚨 TAILWIND CSS 4 ONLY - Professional layout styling
+                class=move || get_collapsible_classes(class.get().unwrap_or_default())
+            >
+                {children()}
+            </div>
+        </Provider>
+    }
+}
+
+/// CollapsibleTrigger - Button that toggles the content
+#[component]
+pub fn CollapsibleTrigger(
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: Children,
+) -> impl IntoView {
+    let context = expect_context::<CollapsibleContextValue>();
+
+    view! {
+        <button
+            type="button"
+            // ARIA attributes
+            aria-expanded=move || if context.open.get() { "true" } else { "false" }
+            aria-controls=move || context.content_id.get()
+            // Data attributes for Tailwind CSS 4 styling
+            data-state=move || if context.open.get() { "open" } else { "closed" }
+            data-disabled=move || if context.disabled.get() { Some("") } else { None }
+            disabled=move || context.disabled.get()
+            // 🚨 TAILWIND CSS 4 ONLY - Professional trigger styling
+            class=move || get_collapsible_trigger_classes(class.get().unwrap_or_default())
+            on:click=move |_| {
+                if !context.disabled.get_untracked() {
+                    context.set_open.run(!context.open.get_untracked());
+                }
+            }
+        >
+            {children()}
+        </button>
+    }
+}
+
+/// CollapsibleContent - The region shown while open
+///
+/// Exposes its measured size as `--collapsible-content-height` and
+/// `--collapsible-content-width` so Tailwind keyframes can animate between
+/// zero and the real size.
+#[component]
+pub fn CollapsibleContent(
+    /// Keep the content in the DOM (hidden) while closed
+    #[prop(into, optional)]
+    force_mount: MaybeProp<bool>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+
+    children: ChildrenFn,
+) -> impl IntoView {
+    let context = expect_context::<CollapsibleContextValue>();
+    let content_ref = NodeRef::<Div>::new();
+
+    // Content open on first render appears without animating
+    let mount_animation_prevented = StoredValue::new(context.open.get_untracked());
+
+    // Measure after every open change (created before the presence check so
+    // the mount-time `animation-name: none` is gone when it runs)
+    Effect::new(move |_| {
+        let _ = context.open.get();
+        let Some(content) = content_ref.get() else {
+            return;
+        };
+        request_animation_frame(move || {
+            let style = HtmlElement::style(&content);
+            let _ = style.set_property("animation-name", "none");
+            let _ = style.set_property("transition-duration", "0s");
+            let rect = content.get_bounding_client_rect();
+            let _ = style.set_property(
+                "--collapsible-content-height",
+                &format!("{}px", rect.height()),
+            );
+            let _ = style.set_property(
+                "--collapsible-content-width",
+                &format!("{}px", rect.width()),
+            );
+            if mount_animation_prevented.get_value() {
+                mount_animation_prevented.set_value(false);
+            } else {
+                let _ = style.remove_property("animation-name");
+                let _ = style.remove_property("transition-duration");
+            }
+        });
+    });
+
+    let presence = use_presence(context.open, content_ref);
+    let is_rendered =
+        Signal::derive(move || force_mount.get().unwrap_or(false) || presence.is_present.get());
+
+    view! {
+        <Show when=move || is_rendered.get()>
+            <div
+                node_ref=content_ref
+                id=move || context.content_id.get()
+                hidden=move || !presence.is_present.get()
+                // Skip the opening animation of content that starts open (SSR included)
+                style=move || mount_animation_prevented.get_value().then_some("animation-name: none")
+                // Data attributes for Tailwind CSS 4 styling
+                data-state=move || if context.open.get() { "open" } else { "closed" }
+                data-disabled=move || if context.disabled.get() { Some("") } else { None }
+                // 🚨 TAILWIND CSS 4 ONLY - Height animation driven by the CSS variable
+                class=move || get_collapsible_content_classes(class.get().unwrap_or_default())
+                on:animationend=move |ev| presence.on_animation_end.run(ev)
+            >
+                {children()}
+            </div>
+        </Show>
+    }
+}
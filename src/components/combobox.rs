@@ -0,0 +1,866 @@
+//! Combobox Component - Text input with a filtered listbox (autocomplete)
+//!
+//! Features:
+//! - ✅ Controlled/uncontrolled value (Vec of selected values) and input text
+//! - ✅ Strict, free-text and multi-select (chips) modes
+//! - ✅ `aria-activedescendant` navigation: focus stays in the input
+//! - ✅ Built-in case-insensitive filtering, or none for server-filtered options
+//! - ✅ Loading and empty states for async sources: render the options from your
+//!   Resource (or server function) and pass its pending state as `loading`
+//! - ✅ Hidden inputs for form submission
+
+use crate::hooks::{
+    use_controllable_state, use_floating_position, use_id_with_prefix,
+    use_outside_click_multiple_when, Align, FloatingOptions,
+};
+use crate::utils::{move_index, query_elements, Movement};
+use leptos::context::Provider;
+use leptos::html::{Div, Input};
+use leptos::prelude::*;
+use std::collections::HashMap;
+use wasm_bindgen::JsCast;
+use web_sys::{HtmlElement, KeyboardEvent, MouseEvent};
+
+/// Selector for the options that can be highlighted
+const OPTION_SELECTOR: &str = "[role='option']:not([data-disabled]):not([hidden])";
+
+/// How selections are made and what the value holds
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum ComboboxMode {
+    /// The value is always one of the options; unmatched text is reverted on close
+    #[default]
+    Strict,
+    /// The typed text is the value; picking a suggestion stores its value
+    FreeText,
+    /// Several options can be selected and are shown as chips
+    Multiple,
+}
+
+/// Helper function to generate combobox input CSS classes
+fn get_combobox_input_classes(user_class: String) -> String {
+    let base = "h-9 min-w-[6rem] flex-1 bg-transparent px-2 text-sm text-gray-900 placeholder:text-gray-500";
+    let focus = "focus:outline-none";
+    let disabled = "disabled:cursor-not-allowed disabled:opacity-50";
+
+    format!("{base} {focus} {disabled} {user_class}")
+}
+
+/// Helper function to generate combobox content CSS classes
+fn get_combobox_content_classes(user_class: String) -> String {
+    let base = "z-50 max-h-64 overflow-y-auto rounded-md border border-gray-200 bg-white p-1 text-gray-900 shadow-md";
+    let animation = "data-[state=open]:animate-in data-[state=open]:fade-in-0";
+
+    format!("{base} {animation} {user_class}")
+}
+
+/// Helper function to generate combobox item CSS classes
+fn get_combobox_item_classes(user_class: String) -> String {
+    let base = "relative flex cursor-default select-none items-center rounded-sm py-1.5 pl-8 pr-2 text-sm outline-none";
+    let states = "data-[highlighted]:bg-hover-purple data-[highlighted]:text-white data-[disabled]:pointer-events-none data-[disabled]:opacity-50";
+
+    format!("{base} {states} {user_class}")
+}
+
+/// Whether an option label matches the typed query (case-insensitive substring)
+///
+/// An empty query matches everything.
+pub(crate) fn combobox_filter(label: &str, query: &str) -> bool {
+    let query = query.trim();
+    query.is_empty() || label.to_lowercase().contains(&query.to_lowercase())
+}
+
+/// An option currently rendered in the listbox
+#[derive(Clone)]
+struct ComboboxItemData {
+    value: String,
+    label: String,
+}
+
+/// Context value shared between Combobox and its parts
+#[derive(Clone, Copy)]
+pub struct ComboboxContextValue {
+    /// Selected values (at most one in the single modes)
+    pub value: Signal<Vec<String>>,
+    pub set_value: Callback<Vec<String>>,
+    /// Text in the input
+    pub input_value: Signal<String>,
+    pub set_input_value: Callback<String>,
+    pub mode: Signal<ComboboxMode>,
+    pub open: RwSignal<bool>,
+    pub loading: Signal<bool>,
+    pub disabled: Signal<bool>,
+    pub required: Signal<bool>,
+    /// Element id of the highlighted option (`aria-activedescendant`)
+    highlighted: RwSignal<Option<String>>,
+    /// The user typed since the list was opened (filtering applies)
+    is_filtering: RwSignal<bool>,
+    should_filter: Signal<bool>,
+    items: RwSignal<Vec<ComboboxItemData>>,
+    /// Labels of every option seen so far, for chips and the input text
+    labels: RwSignal<HashMap<String, String>>,
+    input_id: Signal<String>,
+    content_id: Signal<String>,
+    root_ref: NodeRef<Div>,
+    input_ref: NodeRef<Input>,
+    content_ref: NodeRef<Div>,
+}
+
+impl ComboboxContextValue {
+    /// Label for a value (the value itself when the option was never rendered)
+    fn label_of(&self, value: &str) -> String {
+        self.labels
+            .with_untracked(|labels| labels.get(value).cloned())
+            .unwrap_or_else(|| value.to_string())
+    }
+
+    /// Whether an option with this label is shown for the current input
+    fn matches(&self, label: &str) -> bool {
+        !self.should_filter.get()
+            || !self.is_filtering.get()
+            || combobox_filter(label, &self.input_value.get())
+    }
+
+    /// Options that can be highlighted, in DOM order
+    fn options(&self) -> Vec<HtmlElement> {
+        self.content_ref
+            .get_untracked()
+            .map(|content| query_elements(&content, OPTION_SELECTOR))
+            .unwrap_or_default()
+    }
+
+    /// Highlight an option and keep it scrolled into view
+    fn highlight(&self, option: Option<&HtmlElement>) {
+        self.highlighted.set(option.map(|option| option.id()));
+        if let (Some(option), Some(content)) = (option, self.content_ref.get_untracked()) {
+            let top = option.offset_top();
+            let bottom = top + option.offset_height();
+            if top < content.scroll_top() {
+                content.set_scroll_top(top);
+            } else if bottom > content.scroll_top() + content.client_height() {
+                content.set_scroll_top(bottom - content.client_height());
+            }
+        }
+    }
+
+    fn open_list(&self) {
+        if !self.disabled.get_untracked() {
+            self.is_filtering.set(false);
+            self.open.set(true);
+        }
+    }
+
+    /// Close the list; strict mode reverts unmatched text, multiple mode clears it
+    fn close(&self) {
+        self.open.set(false);
+        self.highlighted.set(None);
+        self.is_filtering.set(false);
+        match self.mode.get_untracked() {
+            ComboboxMode::Strict => {
+                let text = self
+                    .value
+                    .get_untracked()
+                    .first()
+                    .map(|value| self.label_of(value))
+                    .unwrap_or_default();
+                self.set_input_value.run(text);
+            }
+            ComboboxMode::Multiple => self.set_input_value.run(String::new()),
+            ComboboxMode::FreeText => {}
+        }
+    }
+
+    /// Select (or, in multiple mode, toggle) a value
+    fn select(&self, value: String) {
+        match self.mode.get_untracked() {
+            ComboboxMode::Multiple => {
+                let mut values = self.value.get_untracked();
+                if let Some(index) = values.iter().position(|selected| *selected == value) {
+                    values.remove(index);
+                } else {
+                    values.push(value);
+                }
+                self.set_value.run(values);
+                self.set_input_value.run(String::new());
+                self.is_filtering.set(false);
+            }
+            ComboboxMode::FreeText | ComboboxMode::Strict => {
+                self.set_input_value.run(self.label_of(&value));
+                self.set_value.run(vec![value]);
+                self.close();
+            }
+        }
+    }
+
+    /// Remove one value (chip remove button, Backspace in an empty input)
+    fn remove(&self, value: &str) {
+        let mut values = self.value.get_untracked();
+        values.retain(|selected| selected != value);
+        self.set_value.run(values);
+    }
+}
+
+/// Context value shared between ComboboxItem and its indicator
+#[derive(Clone, Copy)]
+struct ComboboxItemContextValue {
+    is_selected: Signal<bool>,
+}
+
+/// Combobox component - Hook-first implementation
+///
+/// Options can come from an async source: disable the built-in filter, drive
+/// the query from the input text and pass the loading state. The Combobox
+/// does not fetch anything itself; it renders whatever items are its children
+/// and shows a selected option's label as soon as that item appears.
+///
+/// # Example
+/// ```rust,ignore
+/// let (query, set_query) = signal(String::new());
+/// let users = Resource::new(move || query.get(), |query| search_users(query));
+///
+/// view! {
+///     <Combobox
+///         name="assignee"
+///         should_filter=false
+///         loading=Signal::derive(move || users.get().is_none())
+///         on_input_value_change=Callback::new(move |text| set_query.set(text))
+///     >
+///         <ComboboxInput placeholder="Search people…" />
+///         <ComboboxContent>
+///             <ComboboxLoading>"Loading…"</ComboboxLoading>
+///             <ComboboxEmpty>"No people found"</ComboboxEmpty>
+///             {move || users.get().unwrap_or_default().into_iter().map(|user| view! {
+///                 <ComboboxItem value=user.id text_value=user.name.clone()>{user.name}</ComboboxItem>
+///             }).collect_view()}
+///         </ComboboxContent>
+///     </Combobox>
+/// }
+/// ```
+#[component]
+pub fn Combobox(
+    // Core state management (from our hook library)
+    #[prop(into, optional)] value: MaybeProp<Vec<String>>,
+    #[prop(into, optional)] default_value: MaybeProp<Vec<String>>,
+    #[prop(into, optional)] on_value_change: Option<Callback<Vec<String>>>,
+    #[prop(into, optional)] input_value: MaybeProp<String>,
+    #[prop(into, optional)] default_input_value: MaybeProp<String>,
+    #[prop(into, optional)] on_input_value_change: Option<Callback<String>>,
+
+    /// Strict (default), free-text or multiple selection
+    #[prop(into, optional)]
+    mode: MaybeProp<ComboboxMode>,
+    /// Filter options by the input text (default: true; disable for server-side filtering)
+    #[prop(into, optional)]
+    should_filter: MaybeProp<bool>,
+    /// Options are being loaded, e.g. `users.get().is_none()` for a Resource
+    /// (shows ComboboxLoading, hides ComboboxEmpty)
+    #[prop(into, optional)]
+    loading: MaybeProp<bool>,
+
+    // Form integration
+    #[prop(into, optional)] disabled: MaybeProp<bool>,
+    #[prop(into, optional)] required: MaybeProp<bool>,
+    #[prop(into, optional)] name: MaybeProp<String>,
+    #[prop(into, optional)] form: MaybeProp<String>,
+
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: Children,
+) -> impl IntoView {
+    let state = use_controllable_state(value, default_value, on_value_change);
+    let input_state =
+        use_controllable_state(input_value, default_input_value, on_input_value_change);
+    let is_disabled = Signal::derive(move || disabled.get().unwrap_or(false));
+    let is_required = Signal::derive(move || required.get().unwrap_or(false));
+
+    let context = ComboboxContextValue {
+        value: state.value,
+        set_value: state.set_value,
+        input_value: input_state.value,
+        set_input_value: input_state.set_value,
+        mode: Signal::derive(move || mode.get().unwrap_or_default()),
+        open: RwSignal::new(false),
+        loading: Signal::derive(move || loading.get().unwrap_or(false)),
+        disabled: is_disabled,
+        required: is_required,
+        highlighted: RwSignal::new(None),
+        is_filtering: RwSignal::new(false),
+        should_filter: Signal::derive(move || should_filter.get().unwrap_or(true)),
+        items: RwSignal::new(Vec::new()),
+        labels: RwSignal::new(HashMap::new()),
+        input_id: use_id_with_prefix("combobox-input"),
+        content_id: use_id_with_prefix("combobox-content"),
+        root_ref: NodeRef::new(),
+        input_ref: NodeRef::new(),
+        content_ref: NodeRef::new(),
+    };
+
+    // Single modes show the selected option's label in the input while it is
+    // not being edited, once that label is known (which may be long after
+    // mount when options load asynchronously)
+    Effect::new(move |_| {
+        if context.is_filtering.get() {
+            return;
+        }
+        let Some(value) = context.value.get().first().cloned() else {
+            return;
+        };
+        let text = match context.mode.get() {
+            ComboboxMode::Strict => context.labels.with(|labels| labels.get(&value).cloned()),
+            // Typed text is its own label
+            ComboboxMode::FreeText => context
+                .labels
+                .with(|labels| labels.get(&value).cloned())
+                .or_else(|| {
+                    context
+                        .input_value
+                        .with_untracked(String::is_empty)
+                        .then_some(value)
+                }),
+            ComboboxMode::Multiple => None,
+        };
+        if let Some(text) = text {
+            if context.input_value.get_untracked() != text {
+                context.set_input_value.run(text);
+            }
+        }
+    });
+
+    let is_open = Signal::derive(move || context.open.get());
+    use_outside_click_multiple_when(
+        is_open,
+        vec![Box::new(context.root_ref)],
+        Callback::new(move |_| context.close()),
+    );
+
+    view! {
+        <Provider value=context>
+            <div
+                node_ref=context.root_ref
+                data-state=move || if context.open.get() { "open" } else { "closed" }
+                data-disabled=move || is_disabled.get().then_some("")
+                class=move || {
+                    format!(
+                        "relative flex min-h-9 w-64 flex-wrap items-center gap-1 rounded bg-white px-1 shadow-sm focus-within:ring-2 focus-within:ring-black {}",
+                        class.get().unwrap_or_default(),
+                    )
+                }
+            >
+                {children()}
+                // Hidden inputs for form integration, one per selected value
+                {move || {
+                    context
+                        .value
+                        .get()
+                        .into_iter()
+                        .filter(|value| !value.is_empty())
+                        .map(|value| {
+                            view! {
+                                <input
+                                    type="hidden"
+                                    name=move || name.get()
+                                    form=move || form.get()
+                                    value=value
+                                />
+                            }
+                        })
+                        .collect_view()
+                }}
+            </div>
+        </Provider>
+    }
+}
+
+/// ComboboxInput - The text field (`role="combobox"`)
+#[component]
+pub fn ComboboxInput(
+    #[prop(into, optional)] placeholder: MaybeProp<String>,
+    /// Accessible name when the input has no visible label
+    #[prop(into, optional)]
+    aria_label: MaybeProp<String>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+) -> impl IntoView {
+    let context = expect_context::<ComboboxContextValue>();
+
+    let move_highlight = move |movement: Movement| {
+        let options = context.options();
+        let current = context
+            .highlighted
+            .get_untracked()
+            .and_then(|id| options.iter().position(|option| option.id() == id));
+        let next = match current {
+            Some(_) => move_index(current, options.len(), movement, false),
+            None if movement == Movement::Previous => options.len().checked_sub(1),
+            None => move_index(None, options.len(), movement, false),
+        };
+        context.highlight(next.and_then(|index| options.get(index)));
+    };
+
+    view! {
+        <input
+            node_ref=context.input_ref
+            id=move || context.input_id.get()
+            type="text"
+            role="combobox"
+            autocomplete="off"
+            aria-label=move || aria_label.get()
+            aria-autocomplete="list"
+            aria-expanded=move || if context.open.get() { "true" } else { "false" }
+            aria-controls=move || context.content_id.get()
+            aria-activedescendant=move || context.open.get().then(|| context.highlighted.get()).flatten()
+            aria-busy=move || context.loading.get().then_some("true")
+            placeholder=move || placeholder.get()
+            disabled=move || context.disabled.get()
+            // Native validation: nothing selected and nothing typed
+            required=move || {
+                context.required.get()
+                    && context.value.get().is_empty()
+                    && context.input_value.get().is_empty()
+            }
+            prop:value=move || context.input_value.get()
+            class=move || get_combobox_input_classes(class.get().unwrap_or_default())
+            on:input=move |event| {
+                let text = event_target_value(&event);
+                if context.mode.get_untracked() == ComboboxMode::FreeText {
+                    context.set_value.run(if text.is_empty() { Vec::new() } else { vec![text.clone()] });
+                }
+                context.set_input_value.run(text);
+                context.is_filtering.set(true);
+                context.open.set(true);
+            }
+            on:click=move |_| {
+                if !context.open.get_untracked() {
+                    context.open_list();
+                }
+            }
+            on:keydown=move |event: KeyboardEvent| {
+                let is_open = context.open.get_untracked();
+                match event.key().as_str() {
+                    "ArrowDown" => {
+                        event.prevent_default();
+                        if is_open {
+                            move_highlight(Movement::Next);
+                        } else {
+                            context.open_list();
+                        }
+                    }
+                    "ArrowUp" => {
+                        event.prevent_default();
+                        if is_open {
+                            move_highlight(Movement::Previous);
+                        } else {
+                            context.open_list();
+                        }
+                    }
+                    "Enter" if is_open => {
+                        let highlighted = context.highlighted.get_untracked().and_then(|id| {
+                            context.options().into_iter().find(|option| option.id() == id)
+                        });
+                        if let Some(value) = highlighted.and_then(|option| option.get_attribute("data-value")) {
+                            event.prevent_default();
+                            context.select(value);
+                        }
+                    }
+                    "Escape" if is_open => {
+                        event.prevent_default();
+                        context.close();
+                    }
+                    "Tab" if is_open => context.close(),
+                    "Backspace" if context.mode.get_untracked() == ComboboxMode::Multiple
+                        && context.input_value.get_untracked().is_empty() =>
+                    {
+                        if let Some(last) = context.value.get_untracked().last() {
+                            context.remove(last);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        />
+    }
+}
+
+/// ComboboxTrigger - Button that toggles the list
+#[component]
+pub fn ComboboxTrigger(
+    /// Accessible name of the button (default: "Show options")
+    #[prop(into, optional)]
+    aria_label: MaybeProp<String>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+) -> impl IntoView {
+    let context = expect_context::<ComboboxContextValue>();
+
+    view! {
+        <button
+            type="button"
+            tabindex="-1"
+            aria-label=move || aria_label.get().unwrap_or_else(|| "Show options".to_string())
+            aria-controls=move || context.content_id.get()
+            aria-expanded=move || if context.open.get() { "true" } else { "false" }
+            disabled=move || context.disabled.get()
+            class=move || format!("inline-flex h-7 w-7 items-center justify-center rounded text-gray-500 {}", class.get().unwrap_or_default())
+            on:mousedown=move |event: MouseEvent| event.prevent_default()
+            on:click=move |_| {
+                if context.open.get_untracked() {
+                    context.close();
+                } else {
+                    context.open_list();
+                }
+                if let Some(input) = context.input_ref.get_untracked() {
+                    let _ = input.focus();
+                }
+            }
+        >
+            <svg aria-hidden="true" width="12" height="12" viewBox="0 0 15 15">
+                <path d="M4 6H11L7.5 10.5L4 6Z" fill="currentColor" />
+            </svg>
+        </button>
+    }
+}
+
+/// ComboboxChips - Selected values as removable chips (multiple mode)
+#[component]
+pub fn ComboboxChips(#[prop(into, optional)] class: MaybeProp<String>) -> impl IntoView {
+    let context = expect_context::<ComboboxContextValue>();
+
+    view! {
+        {move || {
+            context
+                .value
+                .get()
+                .into_iter()
+                .map(|value| {
+                    let value = StoredValue::new(value);
+                    let label = move || {
+                        context.labels.track();
+                        value.with_value(|value| context.label_of(value))
+                    };
+                    view! {
+                        <span
+                            data-combobox-chip=""
+                            class=move || {
+                                format!(
+                                    "inline-flex items-center gap-1 rounded bg-gray-100 py-0.5 pl-2 pr-1 text-xs text-gray-900 {}",
+                                    class.get().unwrap_or_default(),
+                                )
+                            }
+                        >
+                            {label}
+                            <button
+                                type="button"
+                                tabindex="-1"
+                                aria-label=move || format!("Remove {}", label())
+                                disabled=move || context.disabled.get()
+                                class="rounded px-1 text-gray-500 hover:text-gray-900"
+                                on:mousedown=move |event: MouseEvent| event.prevent_default()
+                                on:click=move |_| value.with_value(|value| context.remove(value))
+                            >
+                                "×"
+                            </button>
+                        </span>
+                    }
+                })
+                .collect_view()
+        }}
+    }
+}
+
+/// ComboboxContent - The listbox, positioned below the combobox
+///
+/// Stays mounted while closed so chip and input labels are known up front.
+#[component]
+pub fn ComboboxContent(
+    /// Distance in px from the combobox (default: 4)
+    #[prop(into, optional)]
+    side_offset: MaybeProp<f64>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: Children,
+) -> impl IntoView {
+    let context = expect_context::<ComboboxContextValue>();
+    let is_open = Signal::derive(move || context.open.get());
+    let (anchor_width, set_anchor_width) = signal(0.0);
+
+    let options = Signal::derive(move || FloatingOptions {
+        align: Align::Start,
+        side_offset: side_offset.get().unwrap_or(4.0),
+        ..Default::default()
+    });
+    let position = use_floating_position(
+        is_open,
+        move || context.root_ref.get().map(|root| root.unchecked_into()),
+        context.content_ref,
+        options,
+    );
+
+    // Highlight the selected option (or the first one) when the list opens or
+    // the matches change
+    Effect::new(move |_| {
+        if !is_open.get() {
+            return;
+        }
+        context.input_value.track();
+        context.items.track();
+        request_animation_frame(move || {
+            if let Some(root) = context.root_ref.get_untracked() {
+                set_anchor_width.set(f64::from(root.offset_width()));
+            }
+            let options = context.options();
+            let selected = context.value.get_untracked();
+            let target = options
+                .iter()
+                .find(|option| {
+                    !context.is_filtering.get_untracked()
+                        && option
+                            .get_attribute("data-value")
+                            .is_some_and(|value| selected.contains(&value))
+                })
+                .or_else(|| options.first());
+            context.highlight(target);
+        });
+    });
+
+    view! {
+        <div
+            node_ref=context.content_ref
+            id=move || context.content_id.get()
+            role="listbox"
+            aria-labelledby=move || context.input_id.get()
+            aria-multiselectable=move || (context.mode.get() == ComboboxMode::Multiple).then_some("true")
+            aria-busy=move || context.loading.get().then_some("true")
+            hidden=move || !is_open.get()
+            data-state=move || if is_open.get() { "open" } else { "closed" }
+            style=move || {
+                if is_open.get() {
+                    format!("{} min-width: {}px;", position.style.get(), anchor_width.get())
+                } else {
+                    "display: none;".to_string()
+                }
+            }
+            class=move || get_combobox_content_classes(class.get().unwrap_or_default())
+        >
+            {children()}
+        </div>
+    }
+}
+
+/// ComboboxItem - One option (`role="option"`)
+#[component]
+pub fn ComboboxItem(
+    #[prop(into)] value: String,
+    /// Text used for filtering, the input and chips (default: the value)
+    #[prop(into, optional)]
+    text_value: MaybeProp<String>,
+    #[prop(into, optional)] disabled: MaybeProp<bool>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: Children,
+) -> impl IntoView {
+    let context = expect_context::<ComboboxContextValue>();
+    let id = use_id_with_prefix("combobox-option");
+    let item_value = StoredValue::new(value);
+    let label = StoredValue::new(
+        text_value
+            .get_untracked()
+            .unwrap_or_else(|| item_value.get_value()),
+    );
+    let is_disabled = Signal::derive(move || disabled.get().unwrap_or(false));
+    let is_selected =
+        Signal::derive(move || item_value.with_value(|value| context.value.get().contains(value)));
+    let is_visible = Signal::derive(move || label.with_value(|label| context.matches(label)));
+    let is_highlighted = Signal::derive(move || context.highlighted.get() == Some(id.get()));
+
+    // Register for the empty state, chips and the input text
+    let data = ComboboxItemData {
+        value: item_value.get_value(),
+        label: label.get_value(),
+    };
+    context.labels.update(|labels| {
+        labels.insert(data.value.clone(), data.label.clone());
+    });
+    context.items.update(|items| items.push(data));
+    on_cleanup(move || {
+        context.items.try_update(|items| {
+            item_value.with_value(|value| items.retain(|item| item.value != *value))
+        });
+    });
+
+    view! {
+        <Provider value=ComboboxItemContextValue { is_selected }>
+            <div
+                id=move || id.get()
+                role="option"
+                aria-selected=move || if is_selected.get() { "true" } else { "false" }
+                aria-disabled=move || is_disabled.get().then_some("true")
+                hidden=move || !is_visible.get()
+                data-value=move || item_value.get_value()
+                data-state=move || if is_selected.get() { "checked" } else { "unchecked" }
+                data-highlighted=move || is_highlighted.get().then_some("")
+                data-disabled=move || is_disabled.get().then_some("")
+                class=move || get_combobox_item_classes(class.get().unwrap_or_default())
+                // Keep focus in the input
+                on:mousedown=move |event: MouseEvent| event.prevent_default()
+                on:pointermove=move |event| {
+                    if !is_disabled.get_untracked() && !is_highlighted.get_untracked() {
+                        let option = event
+                            .current_target()
+                            .and_then(|target| target.dyn_into::<HtmlElement>().ok());
+                        context.highlighted.set(option.map(|option| option.id()));
+                    }
+                }
+                on:click=move |_| {
+                    if !is_disabled.get_untracked() {
+                        context.select(item_value.get_value());
+                    }
+                }
+            >
+                {children()}
+            </div>
+        </Provider>
+    }
+}
+
+/// ComboboxItemIndicator - Rendered only while its item is selected
+#[component]
+pub fn ComboboxItemIndicator(
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: ChildrenFn,
+) -> impl IntoView {
+    let item = expect_context::<ComboboxItemContextValue>();
+    let children = StoredValue::new(children);
+
+    view! {
+        <Show when=move || item.is_selected.get()>
+            <span class=move || {
+                format!(
+                    "absolute left-2 inline-flex h-4 w-4 items-center justify-center {}",
+                    class.get().unwrap_or_default(),
+                )
+            }>
+                {children.read_value()()}
+            </span>
+        </Show>
+    }
+}
+
+/// ComboboxLoading - Shown while the Combobox `loading` prop is true
+#[component]
+pub fn ComboboxLoading(
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: ChildrenFn,
+) -> impl IntoView {
+    let context = expect_context::<ComboboxContextValue>();
+    let children = StoredValue::new(children);
+
+    view! {
+        <Show when=move || context.loading.get()>
+            <div
+                role="status"
+                class=move || format!("px-2 py-1.5 text-sm text-gray-500 {}", class.get().unwrap_or_default())
+            >
+                {children.read_value()()}
+            </div>
+        </Show>
+    }
+}
+
+/// ComboboxEmpty - Shown when nothing is loading and no option matches
+#[component]
+pub fn ComboboxEmpty(
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: ChildrenFn,
+) -> impl IntoView {
+    let context = expect_context::<ComboboxContextValue>();
+    let children = StoredValue::new(children);
+
+    let is_empty = move || {
+        !context.loading.get()
+            && context
+                .items
+                .with(|items| !items.iter().any(|item| context.matches(&item.label)))
+    };
+
+    view! {
+        <Show when=is_empty>
+            <div
+                role="status"
+                class=move || format!("px-2 py-1.5 text-sm text-gray-500 {}", class.get().unwrap_or_default())
+            >
+                {children.read_value()()}
+            </div>
+        </Show>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_mode_is_strict() {
+        assert_eq!(ComboboxMode::default(), ComboboxMode::Strict);
+    }
+
+    #[test]
+    fn test_filter_is_case_insensitive_substring() {
+        assert!(combobox_filter("Grace Hopper", "hop"));
+        assert!(combobox_filter("Grace Hopper", "GRACE"));
+        assert!(!combobox_filter("Grace Hopper", "turing"));
+    }
+
+    #[test]
+    fn test_empty_query_matches_everything() {
+        assert!(combobox_filter("Leptos", ""));
+        assert!(combobox_filter("Leptos", "   "));
+    }
+
+    fn context(mode: ComboboxMode) -> ComboboxContextValue {
+        let value = RwSignal::new(Vec::<String>::new());
+        let input_value = RwSignal::new(String::new());
+        ComboboxContextValue {
+            value: value.into(),
+            set_value: Callback::new(move |next| value.set(next)),
+            input_value: input_value.into(),
+            set_input_value: Callback::new(move |next| input_value.set(next)),
+            mode: Signal::derive(move || mode),
+            open: RwSignal::new(true),
+            loading: Signal::derive(|| false),
+            disabled: Signal::derive(|| false),
+            required: Signal::derive(|| false),
+            highlighted: RwSignal::new(None),
+            is_filtering: RwSignal::new(true),
+            should_filter: Signal::derive(|| true),
+            items: RwSignal::new(Vec::new()),
+            labels: RwSignal::new(HashMap::from([(
+                "u1".to_string(),
+                "Ada Lovelace".to_string(),
+            )])),
+            input_id: Signal::derive(String::new),
+            content_id: Signal::derive(String::new),
+            root_ref: NodeRef::new(),
+            input_ref: NodeRef::new(),
+            content_ref: NodeRef::new(),
+        }
+    }
+
+    #[test]
+    fn test_selection_stores_the_value_and_shows_the_label() {
+        for mode in [ComboboxMode::Strict, ComboboxMode::FreeText] {
+            let context = context(mode);
+            context.select("u1".to_string());
+            assert_eq!(context.value.get_untracked(), vec!["u1".to_string()]);
+            assert_eq!(context.input_value.get_untracked(), "Ada Lovelace");
+            assert!(!context.open.get_untracked());
+        }
+    }
+
+    #[test]
+    fn test_multiple_selection_toggles_values() {
+        let context = context(ComboboxMode::Multiple);
+        context.select("u1".to_string());
+        context.select("u2".to_string());
+        assert_eq!(
+            context.value.get_untracked(),
+            vec!["u1".to_string(), "u2".to_string()]
+        );
+        context.select("u1".to_string());
+        assert_eq!(context.value.get_untracked(), vec!["u2".to_string()]);
+        assert_eq!(context.input_value.get_untracked(), "");
+    }
+}
@@ -0,0 +1,294 @@
+//! ContextMenu Component - Menu opened at the pointer by right-click or long-press
+//!
+//! Features:
+//! - ✅ Opens at the pointer position on `contextmenu` and on touch long-press
+//! - ✅ Keyboard access via Shift+F10 / the ContextMenu key, opening at the focused element
+//! - ✅ Shares item semantics with DropdownMenu (checkbox / radio items, submenus, data-highlighted)
+//! - ✅ Escape (use_escape_key) and outside click / right-click dismissal (use_outside_click)
+//! - ✅ Focus returns to the previously focused element when the menu closes
+
+use super::dropdown_menu::{DropdownMenuContextValue, MenuContentView};
+use crate::hooks::{
+    use_dropdown_behavior, use_floating_position, use_outside_click_with_config_when, Align,
+    FloatingOptions, Side, UseOutsideClickConfig,
+};
+use crate::utils::{active_element, Movement};
+use leptos::context::Provider;
+use leptos::html::{Div, Span};
+use leptos::prelude::*;
+use std::time::Duration;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement, KeyboardEvent, MouseEvent, PointerEvent};
+
+/// How long a touch has to rest before the menu opens
+const LONG_PRESS_MS: u64 = 700;
+
+// Item parts are shared with DropdownMenu
+pub use super::dropdown_menu::{
+    DropdownMenuCheckboxItem as ContextMenuCheckboxItem,
+    DropdownMenuCheckboxItemProps as ContextMenuCheckboxItemProps,
+    DropdownMenuGroup as ContextMenuGroup, DropdownMenuGroupProps as ContextMenuGroupProps,
+    DropdownMenuItem as ContextMenuItem, DropdownMenuItemIndicator as ContextMenuItemIndicator,
+    DropdownMenuItemIndicatorProps as ContextMenuItemIndicatorProps,
+    DropdownMenuItemProps as ContextMenuItemProps, DropdownMenuLabel as ContextMenuLabel,
+    DropdownMenuLabelProps as ContextMenuLabelProps,
+    DropdownMenuRadioGroup as ContextMenuRadioGroup,
+    DropdownMenuRadioGroupProps as ContextMenuRadioGroupProps,
+    DropdownMenuRadioItem as ContextMenuRadioItem,
+    DropdownMenuRadioItemProps as ContextMenuRadioItemProps,
+    DropdownMenuSeparator as ContextMenuSeparator,
+    DropdownMenuSeparatorProps as ContextMenuSeparatorProps, DropdownMenuSub as ContextMenuSub,
+    DropdownMenuSubContent as ContextMenuSubContent,
+    DropdownMenuSubContentProps as ContextMenuSubContentProps,
+    DropdownMenuSubProps as ContextMenuSubProps, DropdownMenuSubTrigger as ContextMenuSubTrigger,
+    DropdownMenuSubTriggerProps as ContextMenuSubTriggerProps,
+};
+
+/// Context value shared between ContextMenu and its trigger / content
+#[derive(Clone, Copy)]
+pub struct ContextMenuContextValue {
+    /// Open the menu at a viewport position, optionally focusing an item
+    pub open_at: Callback<(f64, f64, Option<Movement>)>,
+    /// Invisible element placed at the pointer that the content is anchored to
+    pub anchor_ref: NodeRef<Div>,
+    /// Viewport position the menu was opened at
+    pub point: RwSignal<(f64, f64)>,
+    /// Notified once the anchor has been moved to `point`
+    pub anchor_moved: Trigger,
+}
+
+/// ContextMenu component - Hook-first implementation
+///
+/// # Example
+/// ```rust,ignore
+/// view! {
+///     <ContextMenu>
+///         <ContextMenuTrigger class="block h-40 border border-dashed">
+///             "Right-click here"
+///         </ContextMenuTrigger>
+///         <ContextMenuContent>
+///             <ContextMenuItem on_select=rename>"Rename"</ContextMenuItem>
+///             <ContextMenuSeparator />
+///             <ContextMenuItem on_select=delete>"Delete"</ContextMenuItem>
+///         </ContextMenuContent>
+///     </ContextMenu>
+/// }
+/// ```
+#[component]
+pub fn ContextMenu(
+    #[prop(into, optional)] on_open_change: Option<Callback<bool>>,
+
+    children: Children,
+) -> impl IntoView {
+    let menu = use_dropdown_behavior(
+        MaybeProp::default(),
+        MaybeProp::default(),
+        on_open_change,
+        "context-menu",
+    );
+    let anchor_ref = NodeRef::<Div>::new();
+    let point = RwSignal::new((0.0, 0.0));
+    let return_focus = StoredValue::new_local(None::<HtmlElement>);
+
+    let open_at = Callback::new(move |(x, y, focus): (f64, f64, Option<Movement>)| {
+        if !menu.open.get_untracked() {
+            return_focus.set_value(active_element());
+        }
+        point.set((x, y));
+        menu.open_with_focus.run(focus);
+    });
+
+    // A right-click anywhere else closes the menu (the trigger reopens it at the new point)
+    use_outside_click_with_config_when(
+        menu.open,
+        menu.content_ref,
+        Callback::new(move |_| menu.close.run(())),
+        UseOutsideClickConfig {
+            events: vec!["contextmenu"],
+            capture: true,
+            enabled: true,
+        },
+    );
+
+    // Return focus when the menu closed while focus was inside it
+    Effect::new(move |was_open: Option<bool>| {
+        let is_open = menu.open.get();
+        if was_open == Some(true) && !is_open {
+            let focus_lost = active_element().is_none_or(|element| element.tag_name() == "BODY");
+            if focus_lost {
+                if let Some(element) = return_focus.try_update_value(Option::take).flatten() {
+                    let _ = element.focus();
+                }
+            }
+        }
+        is_open
+    });
+
+    // Move the anchor to the point before the content measures it
+    let anchor_moved = Trigger::new();
+    Effect::new(move |_| {
+        let (x, y) = point.get();
+        let Some(anchor) = anchor_ref.get() else {
+            return;
+        };
+        let style = HtmlElement::style(&anchor);
+        let _ = style.set_property("left", &format!("{x}px"));
+        let _ = style.set_property("top", &format!("{y}px"));
+        anchor_moved.notify();
+    });
+
+    let context_value = ContextMenuContextValue {
+        open_at,
+        anchor_ref,
+        point,
+        anchor_moved,
+    };
+
+    view! {
+        <Provider value=DropdownMenuContextValue { menu }>
+            <Provider value=context_value>
+                <div
+                    node_ref=anchor_ref
+                    aria-hidden="true"
+                    style="position: fixed; left: 0px; top: 0px; width: 0px; height: 0px; pointer-events: none;"
+                />
+                {children()}
+            </Provider>
+        </Provider>
+    }
+}
+
+/// ContextMenuTrigger - Area that opens the menu on right-click or long-press
+///
+/// Focusable, so Shift+F10 and the ContextMenu key open the menu from the keyboard.
+#[component]
+pub fn ContextMenuTrigger(
+    #[prop(into, optional)] disabled: MaybeProp<bool>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: Children,
+) -> impl IntoView {
+    let DropdownMenuContextValue { menu } = expect_context::<DropdownMenuContextValue>();
+    let context = expect_context::<ContextMenuContextValue>();
+    let is_disabled = Signal::derive(move || disabled.get().unwrap_or(false));
+    let trigger_ref = NodeRef::<Span>::new();
+    let long_press_timer = StoredValue::new(None::<TimeoutHandle>);
+
+    let clear_long_press = move || {
+        if let Some(handle) = long_press_timer.try_update_value(Option::take).flatten() {
+            handle.clear();
+        }
+    };
+    on_cleanup(clear_long_press);
+
+    view! {
+        <span
+            node_ref=trigger_ref
+            id=move || menu.trigger_id.get()
+            tabindex=move || if is_disabled.get() { "-1" } else { "0" }
+            data-state=move || menu.get_state_attr.get()
+            data-disabled=move || is_disabled.get().then_some("")
+            style="-webkit-touch-callout: none;"
+            class=move || class.get()
+            on:contextmenu=move |event: MouseEvent| {
+                if is_disabled.get_untracked() {
+                    return;
+                }
+                event.prevent_default();
+                clear_long_press();
+                context
+                    .open_at
+                    .run((f64::from(event.client_x()), f64::from(event.client_y()), None));
+            }
+            on:pointerdown=move |event: PointerEvent| {
+                if is_disabled.get_untracked() || event.pointer_type() == "mouse" {
+                    return;
+                }
+                clear_long_press();
+                let (x, y) = (f64::from(event.client_x()), f64::from(event.client_y()));
+                let handle = set_timeout_with_handle(
+                    move || context.open_at.run((x, y, None)),
+                    Duration::from_millis(LONG_PRESS_MS),
+                )
+                .ok();
+                long_press_timer.set_value(handle);
+            }
+            on:pointermove=move |event: PointerEvent| {
+                if event.pointer_type() != "mouse" {
+                    clear_long_press();
+                }
+            }
+            on:pointerup=move |_| clear_long_press()
+            on:pointercancel=move |_| clear_long_press()
+            on:keydown=move |event: KeyboardEvent| {
+                let is_menu_key = (event.shift_key() && event.key() == "F10")
+                    || event.key() == "ContextMenu";
+                if is_disabled.get_untracked() || !is_menu_key {
+                    return;
+                }
+                event.prevent_default();
+
+                // Open next to whatever has focus inside the trigger
+                let target = event
+                    .target()
+                    .and_then(|target| target.dyn_into::<Element>().ok())
+                    .or_else(|| trigger_ref.get_untracked().map(|trigger| trigger.unchecked_into()));
+                if let Some(target) = target {
+                    let rect = target.get_bounding_client_rect();
+                    context.open_at.run((rect.left(), rect.bottom(), Some(Movement::First)));
+                }
+            }
+        >
+            {children()}
+        </span>
+    }
+}
+
+/// ContextMenuContent - The floating menu, placed at the pointer (`role="menu"`)
+#[component]
+pub fn ContextMenuContent(
+    /// Flip/shift to stay in the viewport (default: true)
+    #[prop(into, optional)]
+    avoid_collisions: MaybeProp<bool>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: ChildrenFn,
+) -> impl IntoView {
+    let DropdownMenuContextValue { menu } = expect_context::<DropdownMenuContextValue>();
+    let context = expect_context::<ContextMenuContextValue>();
+
+    let options = Signal::derive(move || FloatingOptions {
+        side: Side::Right,
+        align: Align::Start,
+        side_offset: 2.0,
+        avoid_collisions: avoid_collisions.get().unwrap_or(true),
+        ..Default::default()
+    });
+
+    let position = use_floating_position(
+        menu.open,
+        move || {
+            // Re-measure whenever the anchor moves, e.g. reopening elsewhere
+            context.anchor_moved.track();
+            context
+                .anchor_ref
+                .get_untracked()
+                .map(|anchor| anchor.unchecked_into())
+        },
+        menu.content_ref,
+        options,
+    );
+
+    view! {
+        <MenuContentView
+            open=menu.open
+            get_state_attr=menu.get_state_attr
+            initial_focus=menu.initial_focus
+            content_id=menu.content_id
+            labelled_by=menu.trigger_id
+            content_ref=menu.content_ref
+            position=position
+            on_close_sub=None
+            class=class
+            children=children
+        />
+    }
+}
@@ -0,0 +1,300 @@
+//! Dialog Component - Modal window overlaid on the page
+//!
+//! Features:
+//! - ✅ Controlled/uncontrolled open state (open, default_open, on_open_change)
+//! - ✅ Focus trap with focus restore to the trigger
+//! - ✅ Escape key and outside click dismissal
+//! - ✅ Body scroll lock while open
+//! - ✅ aria-labelledby / aria-describedby wired to DialogTitle / DialogDescription
+//! - ✅ Overlay and content stay mounted for their closing animation (use_presence)
+
+use crate::hooks::{
+    use_dialog_behavior, use_presence, UseDialogBehaviorOptions, UseDialogBehaviorReturn,
+    UsePresenceReturn,
+};
+use leptos::context::Provider;
+use leptos::html::Div;
+use leptos::portal::Portal;
+use leptos::prelude::*;
+
+/// Helper function to generate dialog overlay CSS classes
+fn get_dialog_overlay_classes(user_class: String) -> String {
+    let base = "fixed inset-0 z-50 bg-black/60";
+    let animation = "data-[state=open]:animate-fade-in data-[state=closed]:animate-fade-out";
+
+    format!("{base} {animation} {user_class}")
+}
+
+/// Helper function to generate dialog content CSS classes
+fn get_dialog_content_classes(user_class: String) -> String {
+    let base = "fixed left-1/2 top-1/2 z-50 w-[90vw] max-w-md -translate-x-1/2 -translate-y-1/2 rounded-md bg-white p-6 text-gray-900 shadow-lg";
+    let focus = "focus:outline-none";
+    let animation = "data-[state=open]:animate-dialog-content-in data-[state=closed]:animate-dialog-content-out";
+
+    format!("{base} {focus} {animation} {user_class}")
+}
+
+/// Helper function to generate dialog button CSS classes (trigger and close)
+pub(crate) fn get_dialog_button_classes(user_class: String) -> String {
+    let base = "inline-flex items-center justify-center rounded px-3 py-1.5 text-sm font-medium transition-colors";
+    let focus = "focus:outline-none focus:ring-2 focus:ring-black focus:ring-offset-0";
+
+    format!("{base} {focus} {user_class}")
+}
+
+/// Helper function to get aria-modal attribute value
+fn get_aria_modal(modal: bool) -> Option<&'static str> {
+    if modal {
+        Some("true")
+    } else {
+        None
+    }
+}
+
+/// Context value shared between Dialog and its parts
+#[derive(Clone, Copy)]
+pub struct DialogContextValue {
+    pub dialog: UseDialogBehaviorReturn,
+    /// Whether a DialogDescription is rendered (aria-describedby)
+    pub has_description: Signal<bool>,
+    set_has_description: WriteSignal<bool>,
+    content_presence: UsePresenceReturn,
+}
+
+impl DialogContextValue {
+    /// Context for Dialog and AlertDialog; the content stays present while it animates out
+    pub(crate) fn new(dialog: UseDialogBehaviorReturn) -> Self {
+        let (has_description, set_has_description) = signal(false);
+        Self {
+            dialog,
+            has_description: has_description.into(),
+            set_has_description,
+            content_presence: use_presence(dialog.open, dialog.content_ref),
+        }
+    }
+}
+
+/// Dialog component - Hook-first implementation
+///
+/// Owns the open state and dismissal behaviour; renders no DOM of its own.
+///
+/// # Example
+/// ```rust,ignore
+/// view! {
+///     <Dialog>
+///         <DialogTrigger>"Edit profile"</DialogTrigger>
+///         <DialogPortal>
+///             <DialogOverlay />
+///             <DialogContent>
+///                 <DialogTitle>"Edit profile"</DialogTitle>
+///                 <DialogDescription>"Make changes to your profile."</DialogDescription>
+///                 <DialogClose>"Save"</DialogClose>
+///             </DialogContent>
+///         </DialogPortal>
+///     </Dialog>
+/// }
+/// ```
+#[component]
+pub fn Dialog(
+    // Core state management (from our hook library)
+    #[prop(into, optional)] open: MaybeProp<bool>,
+    #[prop(into, optional)] default_open: MaybeProp<bool>,
+    #[prop(into, optional)] on_open_change: Option<Callback<bool>>,
+
+    /// Modal dialogs trap focus and lock scrolling (default: true)
+    #[prop(into, optional)]
+    modal: MaybeProp<bool>,
+
+    children: Children,
+) -> impl IntoView {
+    let dialog = use_dialog_behavior(
+        open,
+        default_open,
+        on_open_change,
+        UseDialogBehaviorOptions {
+            modal: modal.get_untracked().unwrap_or(true),
+            ..Default::default()
+        },
+    );
+
+    view! {
+        <Provider value=DialogContextValue::new(dialog)>
+            {children()}
+        </Provider>
+    }
+}
+
+/// DialogTrigger - Button that toggles the dialog
+#[component]
+pub fn DialogTrigger(
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: Children,
+) -> impl IntoView {
+    let DialogContextValue { dialog, .. } = expect_context::<DialogContextValue>();
+
+    view! {
+        <button
+            id=move || dialog.trigger_id.get()
+            type="button"
+            aria-haspopup="dialog"
+            aria-expanded=move || if dialog.open.get() { "true" } else { "false" }
+            aria-controls=move || dialog.content_id.get()
+            data-state=move || dialog.get_state_attr.get()
+            class=move || get_dialog_button_classes(class.get().unwrap_or_default())
+            on:click=move |_| dialog.toggle.run(())
+        >
+            {children()}
+        </button>
+    }
+}
+
+/// DialogPortal - Teleports the overlay and content into `document.body` while open
+#[component]
+pub fn DialogPortal(
+    #[prop(into, optional)] force_mount: MaybeProp<bool>,
+    children: ChildrenFn,
+) -> impl IntoView {
+    let context = expect_context::<DialogContextValue>();
+    let children = StoredValue::new(children);
+
+    // Kept while the content animates out, so the portal isn't torn down under it
+    view! {
+        <Show when=move || force_mount.get().unwrap_or(false) || context.content_presence.is_present.get()>
+            <Portal>{children.read_value()()}</Portal>
+        </Show>
+    }
+}
+
+/// DialogOverlay - Dimmed layer covering the page behind the content
+#[component]
+pub fn DialogOverlay(#[prop(into, optional)] class: MaybeProp<String>) -> impl IntoView {
+    let DialogContextValue { dialog, .. } = expect_context::<DialogContextValue>();
+    let overlay_ref = NodeRef::<Div>::new();
+    let presence = use_presence(dialog.open, overlay_ref);
+
+    view! {
+        <Show when=move || presence.is_present.get()>
+            <div
+                node_ref=overlay_ref
+                data-state=move || dialog.get_state_attr.get()
+                class=move || get_dialog_overlay_classes(class.get().unwrap_or_default())
+                on:animationend=move |ev| presence.on_animation_end.run(ev)
+                on:animationcancel=move |ev| presence.on_animation_cancel.run(ev)
+            />
+        </Show>
+    }
+}
+
+/// DialogContent - The dialog window itself (`role="dialog"`)
+#[component]
+pub fn DialogContent(
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: ChildrenFn,
+) -> impl IntoView {
+    view! {
+        <DialogContentView role="dialog" class=class children=children />
+    }
+}
+
+/// Renders the content element for Dialog and AlertDialog
+#[component]
+pub(crate) fn DialogContentView(
+    role: &'static str,
+    class: MaybeProp<String>,
+    children: ChildrenFn,
+) -> impl IntoView {
+    let DialogContextValue {
+        dialog,
+        has_description,
+        content_presence: presence,
+        ..
+    } = expect_context::<DialogContextValue>();
+    let children = StoredValue::new(children);
+
+    view! {
+        <Show when=move || presence.is_present.get()>
+            <div
+                node_ref=dialog.content_ref
+                id=move || dialog.content_id.get()
+                role=role
+                aria-modal=get_aria_modal(dialog.modal)
+                aria-labelledby=move || dialog.title_id.get()
+                // Only point at a description that exists
+                aria-describedby=move || has_description.get().then(|| dialog.description_id.get())
+                data-state=move || dialog.get_state_attr.get()
+                tabindex="-1"
+                class=move || get_dialog_content_classes(class.get().unwrap_or_default())
+                on:animationend=move |ev| presence.on_animation_end.run(ev)
+                on:animationcancel=move |ev| presence.on_animation_cancel.run(ev)
+            >
+                {children.read_value()()}
+            </div>
+        </Show>
+    }
+}
+
+/// DialogTitle - Accessible name of the dialog
+#[component]
+pub fn DialogTitle(
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: Children,
+) -> impl IntoView {
+    let DialogContextValue { dialog, .. } = expect_context::<DialogContextValue>();
+
+    view! {
+        <h2
+            id=move || dialog.title_id.get()
+            class=move || format!("text-lg font-semibold {}", class.get().unwrap_or_default())
+        >
+            {children()}
+        </h2>
+    }
+}
+
+/// DialogDescription - Accessible description of the dialog
+#[component]
+pub fn DialogDescription(
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: Children,
+) -> impl IntoView {
+    let DialogContextValue {
+        dialog,
+        set_has_description,
+        ..
+    } = expect_context::<DialogContextValue>();
+
+    set_has_description.set(true);
+    on_cleanup(move || {
+        set_has_description.try_set(false);
+    });
+
+    view! {
+        <p
+            id=move || dialog.description_id.get()
+            class=move || format!("mt-2 text-sm text-gray-600 {}", class.get().unwrap_or_default())
+        >
+            {children()}
+        </p>
+    }
+}
+
+/// DialogClose - Button that closes the dialog
+#[component]
+pub fn DialogClose(
+    #[prop(into, optional)] class: MaybeProp<String>,
+    #[prop(into, optional)] aria_label: MaybeProp<String>,
+    children: Children,
+) -> impl IntoView {
+    let DialogContextValue { dialog, .. } = expect_context::<DialogContextValue>();
+
+    view! {
+        <button
+            type="button"
+            aria-label=move || aria_label.get()
+            class=move || get_dialog_button_classes(class.get().unwrap_or_default())
+            on:click=move |_| dialog.close.run(())
+        >
+            {children()}
+        </button>
+    }
+}
@@ -0,0 +1,911 @@
+//! DropdownMenu Component - Menu of actions opened from a button
+//!
+//! Features:
+//! - ✅ Controlled/uncontrolled open state (open, default_open, on_open_change)
+//! - ✅ Items, checkbox items (CheckedState), radio groups, labels, groups and separators
+//! - ✅ Nested submenus opened with hover, click, Right arrow or Enter
+//! - ✅ Keyboard: Up/Down, Home/End, typeahead, Enter/Space, Left to close a submenu, Escape
+//! - ✅ Pointer grace area so diagonal movement into a submenu keeps it open
+//! - ✅ data-highlighted / data-state / data-disabled for Tailwind styling
+
+use super::separator::Separator;
+use crate::hooks::{
+    focus_menu_item, get_menu_items, get_pointer_grace_area, use_checkbox_state,
+    use_controllable_state, use_dropdown_behavior, use_floating_position, use_related_ids,
+    use_typeahead, Align, CheckedState, FloatingOptions, Side, UseDropdownBehaviorReturn,
+    UseFloatingPositionReturn,
+};
+use crate::utils::{active_element, Movement};
+use leptos::context::Provider;
+use leptos::html::Div;
+use leptos::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement, KeyboardEvent, PointerEvent};
+
+/// Helper function to generate menu content CSS classes
+pub(crate) fn get_menu_content_classes(user_class: String) -> String {
+    let base =
+        "z-50 min-w-[8rem] rounded-md border border-gray-200 bg-white p-1 text-gray-900 shadow-md";
+    let focus = "focus:outline-none";
+    let animation = "data-[state=open]:animate-in data-[state=open]:fade-in-0 data-[state=open]:zoom-in-95 data-[side=bottom]:slide-in-from-top-2 data-[side=top]:slide-in-from-bottom-2 data-[side=left]:slide-in-from-right-2 data-[side=right]:slide-in-from-left-2";
+
+    format!("{base} {focus} {animation} {user_class}")
+}
+
+/// Helper function to generate menu item CSS classes
+fn get_menu_item_classes(inset: bool, user_class: String) -> String {
+    let base = "relative flex cursor-default select-none items-center rounded-sm px-2 py-1.5 text-sm outline-none";
+    let inset = if inset { "pl-8" } else { "" };
+    let states = "data-[highlighted]:bg-hover-purple data-[highlighted]:text-white data-[state=open]:bg-gray-100 data-[disabled]:pointer-events-none data-[disabled]:opacity-50";
+
+    format!("{base} {inset} {states} {user_class}")
+}
+
+/// Helper function to generate dropdown trigger CSS classes
+fn get_dropdown_trigger_classes(user_class: String) -> String {
+    let base = "inline-flex items-center justify-center rounded px-3 py-1.5 text-sm font-medium transition-colors";
+    let focus = "focus:outline-none focus:ring-2 focus:ring-black focus:ring-offset-0";
+
+    format!("{base} {focus} {user_class}")
+}
+
+/// Context value shared by every part of one menu tree (root and submenus)
+///
+/// Also provided by ContextMenu and Menubar, which reuse the item parts.
+#[derive(Clone, Copy)]
+pub struct DropdownMenuContextValue {
+    pub menu: UseDropdownBehaviorReturn,
+}
+
+/// Context value for one level of a menu (the root content or a submenu content)
+#[derive(Clone, Copy)]
+pub(crate) struct MenuLevelContextValue {
+    content_ref: NodeRef<Div>,
+    /// Content id of the submenu currently open at this level
+    open_sub: RwSignal<Option<String>>,
+    /// Typeahead search in progress (Space types instead of selecting)
+    search: Signal<String>,
+}
+
+/// Context value shared between DropdownMenuSub and its trigger / content
+#[derive(Clone, Copy)]
+struct MenuSubContextValue {
+    open: Signal<bool>,
+    set_open: Callback<bool>,
+    get_state_attr: Memo<&'static str>,
+    initial_focus: RwSignal<Option<Movement>>,
+    trigger_id: Signal<String>,
+    content_id: Signal<String>,
+    trigger_ref: NodeRef<Div>,
+    content_ref: NodeRef<Div>,
+}
+
+/// Context value shared between DropdownMenuRadioGroup and its items
+#[derive(Clone, Copy)]
+struct MenuRadioGroupContextValue {
+    value: Signal<String>,
+    set_value: Callback<String>,
+}
+
+/// Context value read by DropdownMenuItemIndicator
+#[derive(Clone, Copy)]
+struct MenuItemIndicatorContextValue {
+    checked: Signal<CheckedState>,
+}
+
+/// Label used for typeahead: `data-text-value` when set, otherwise the text content
+fn get_item_label(item: &HtmlElement) -> String {
+    item.get_attribute("data-text-value")
+        .or_else(|| item.text_content())
+        .unwrap_or_default()
+}
+
+/// Focus the content element of a menu level (clears the highlighted item)
+fn focus_content(content_ref: NodeRef<Div>) {
+    if let Some(content) = content_ref.get_untracked() {
+        let _ = content.focus();
+    }
+}
+
+/// DropdownMenu component - Hook-first implementation
+///
+/// # Example
+/// ```rust,ignore
+/// view! {
+///     <DropdownMenu>
+///         <DropdownMenuTrigger>"Options"</DropdownMenuTrigger>
+///         <DropdownMenuContent side_offset=4.0>
+///             <DropdownMenuItem on_select=edit>"Edit"</DropdownMenuItem>
+///             <DropdownMenuCheckboxItem checked=show_archived on_checked_change=set_show_archived>
+///                 <DropdownMenuItemIndicator>"✓"</DropdownMenuItemIndicator>
+///                 "Show archived"
+///             </DropdownMenuCheckboxItem>
+///             <DropdownMenuSeparator />
+///             <DropdownMenuSub>
+///                 <DropdownMenuSubTrigger>"Share"</DropdownMenuSubTrigger>
+///                 <DropdownMenuSubContent>
+///                     <DropdownMenuItem>"Email"</DropdownMenuItem>
+///                 </DropdownMenuSubContent>
+///             </DropdownMenuSub>
+///         </DropdownMenuContent>
+///     </DropdownMenu>
+/// }
+/// ```
+#[component]
+pub fn DropdownMenu(
+    // Core state management (from our hook library)
+    #[prop(into, optional)] open: MaybeProp<bool>,
+    #[prop(into, optional)] default_open: MaybeProp<bool>,
+    #[prop(into, optional)] on_open_change: Option<Callback<bool>>,
+
+    children: Children,
+) -> impl IntoView {
+    let menu = use_dropdown_behavior(open, default_open, on_open_change, "dropdown-menu");
+
+    view! {
+        <Provider value=DropdownMenuContextValue { menu }>
+            {children()}
+        </Provider>
+    }
+}
+
+/// DropdownMenuTrigger - Button that opens the menu
+#[component]
+pub fn DropdownMenuTrigger(
+    #[prop(into, optional)] disabled: MaybeProp<bool>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: Children,
+) -> impl IntoView {
+    let DropdownMenuContextValue { menu } = expect_context::<DropdownMenuContextValue>();
+    let is_disabled = Signal::derive(move || disabled.get().unwrap_or(false));
+
+    view! {
+        <button
+            node_ref=menu.trigger_ref
+            id=move || menu.trigger_id.get()
+            type="button"
+            disabled=move || is_disabled.get()
+            aria-haspopup="menu"
+            aria-expanded=move || if menu.open.get() { "true" } else { "false" }
+            aria-controls=move || menu.open.get().then(|| menu.content_id.get())
+            data-state=move || menu.get_state_attr.get()
+            data-disabled=move || is_disabled.get().then_some("")
+            class=move || get_dropdown_trigger_classes(class.get().unwrap_or_default())
+            on:pointerdown=move |event: PointerEvent| {
+                if is_disabled.get_untracked() || event.button() != 0 || event.ctrl_key() {
+                    return;
+                }
+                // Keep focus off the trigger so the content can take it
+                if !menu.open.get_untracked() {
+                    event.prevent_default();
+                }
+                menu.toggle.run(());
+            }
+            on:keydown=move |event: KeyboardEvent| {
+                if is_disabled.get_untracked() {
+                    return;
+                }
+                match event.key().as_str() {
+                    "Enter" | " " => {
+                        event.prevent_default();
+                        if menu.open.get_untracked() {
+                            menu.close.run(());
+                        } else {
+                            menu.open_with_focus.run(Some(Movement::First));
+                        }
+                    }
+                    "ArrowDown" => {
+                        event.prevent_default();
+                        menu.open_with_focus.run(Some(Movement::First));
+                    }
+                    _ => {}
+                }
+            }
+        >
+            {children()}
+        </button>
+    }
+}
+
+/// DropdownMenuContent - The floating menu (`role="menu"`)
+#[component]
+pub fn DropdownMenuContent(
+    /// Preferred side of the trigger (default: bottom)
+    #[prop(into, optional)]
+    side: MaybeProp<Side>,
+    /// Preferred alignment against the trigger (default: start)
+    #[prop(into, optional)]
+    align: MaybeProp<Align>,
+    /// Distance in px from the trigger
+    #[prop(into, optional)]
+    side_offset: MaybeProp<f64>,
+    /// Offset in px from the aligned edge
+    #[prop(into, optional)]
+    align_offset: MaybeProp<f64>,
+    /// Flip/shift to stay in the viewport (default: true)
+    #[prop(into, optional)]
+    avoid_collisions: MaybeProp<bool>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: ChildrenFn,
+) -> impl IntoView {
+    let DropdownMenuContextValue { menu } = expect_context::<DropdownMenuContextValue>();
+
+    let options = Signal::derive(move || FloatingOptions {
+        side: side.get().unwrap_or_default(),
+        align: align.get().unwrap_or(Align::Start),
+        side_offset: side_offset.get().unwrap_or(0.0),
+        align_offset: align_offset.get().unwrap_or(0.0),
+        avoid_collisions: avoid_collisions.get().unwrap_or(true),
+        ..Default::default()
+    });
+
+    let position = use_floating_position(
+        menu.open,
+        move || {
+            menu.trigger_ref
+                .get()
+                .map(|trigger| trigger.unchecked_into())
+        },
+        menu.content_ref,
+        options,
+    );
+
+    view! {
+        <MenuContentView
+            open=menu.open
+            get_state_attr=menu.get_state_attr
+            initial_focus=menu.initial_focus
+            content_id=menu.content_id
+            labelled_by=menu.trigger_id
+            content_ref=menu.content_ref
+            position=position
+            on_close_sub=None
+            class=class
+            children=children
+        />
+    }
+}
+
+/// Renders the content element for every menu level (root content and submenus)
+#[component]
+pub(crate) fn MenuContentView(
+    open: Signal<bool>,
+    get_state_attr: Memo<&'static str>,
+    initial_focus: RwSignal<Option<Movement>>,
+    content_id: Signal<String>,
+    labelled_by: Signal<String>,
+    content_ref: NodeRef<Div>,
+    position: UseFloatingPositionReturn,
+    /// Set for submenus: Left arrow closes the submenu
+    on_close_sub: Option<Callback<()>>,
+    /// Keys the menu itself does not handle (Menubar uses Left/Right)
+    #[prop(optional)]
+    on_unhandled_keydown: Option<Callback<KeyboardEvent>>,
+    class: MaybeProp<String>,
+    children: ChildrenFn,
+) -> impl IntoView {
+    let DropdownMenuContextValue { menu } = expect_context::<DropdownMenuContextValue>();
+    let children = StoredValue::new(children);
+    let typeahead = use_typeahead();
+
+    let level = MenuLevelContextValue {
+        content_ref,
+        open_sub: RwSignal::new(None),
+        search: typeahead.search,
+    };
+
+    // Focus the requested item (or the content) once it is mounted and placed
+    Effect::new(move |_| {
+        if !open.get() || position.placement.get().is_none() {
+            return;
+        }
+        let Some(content) = content_ref.get() else {
+            return;
+        };
+        if !content.is_connected() {
+            return;
+        }
+        let moved = initial_focus
+            .get_untracked()
+            .is_some_and(|movement| focus_menu_item(&content, movement));
+        if !moved {
+            let _ = content.focus();
+        }
+    });
+
+    let on_keydown = move |event: KeyboardEvent| {
+        let Some(content) = content_ref.get_untracked() else {
+            return;
+        };
+        let content: Element = content.unchecked_into();
+        let key = event.key();
+
+        let movement = match key.as_str() {
+            "ArrowDown" => Some(Movement::Next),
+            "ArrowUp" => Some(Movement::Previous),
+            "Home" => Some(Movement::First),
+            "End" => Some(Movement::Last),
+            _ => None,
+        };
+        if let Some(movement) = movement {
+            event.prevent_default();
+            event.stop_propagation();
+            focus_menu_item(&content, movement);
+            return;
+        }
+
+        match key.as_str() {
+            "ArrowLeft" if on_close_sub.is_some() => {
+                event.prevent_default();
+                event.stop_propagation();
+                if let Some(close_sub) = on_close_sub {
+                    close_sub.run(());
+                }
+            }
+            "Tab" => {
+                event.prevent_default();
+                menu.dismiss.run(());
+            }
+            _ if key.chars().count() == 1
+                && !event.ctrl_key()
+                && !event.alt_key()
+                && !event.meta_key() =>
+            {
+                if key == " " && typeahead.search.get_untracked().is_empty() {
+                    return;
+                }
+                event.stop_propagation();
+                let items = get_menu_items(&content);
+                let current = active_element()
+                    .and_then(|active| items.iter().position(|item| *item == active));
+                let labels = items.iter().map(get_item_label).collect();
+                if let Some(index) = typeahead.on_key.run((key, labels, current)) {
+                    let _ = items[index].focus();
+                }
+            }
+            _ => {
+                if let Some(callback) = on_unhandled_keydown {
+                    callback.run(event);
+                }
+            }
+        }
+    };
+
+    view! {
+        <Show when=move || open.get()>
+            <Provider value=level>
+                <div
+                    node_ref=content_ref
+                    id=move || content_id.get()
+                    role="menu"
+                    aria-orientation="vertical"
+                    aria-labelledby=move || labelled_by.get()
+                    tabindex="-1"
+                    data-state=move || get_state_attr.get()
+                    data-side=move || position.side.get().as_str()
+                    data-align=move || position.align.get().as_str()
+                    style=move || position.style.get()
+                    class=move || get_menu_content_classes(class.get().unwrap_or_default())
+                    on:keydown=on_keydown
+                    on:pointerenter=move |_| menu.set_pointer_grace.run(None)
+                >
+                    {children.read_value()()}
+                </div>
+            </Provider>
+        </Show>
+    }
+}
+
+/// Shared element and interaction logic for Item, CheckboxItem and RadioItem
+#[component]
+fn MenuItemView(
+    role: &'static str,
+    disabled: Signal<bool>,
+    text_value: MaybeProp<String>,
+    close_on_select: Signal<bool>,
+    on_select: Callback<()>,
+    #[prop(optional)] aria_checked: Option<Signal<&'static str>>,
+    #[prop(optional)] data_state: Option<Signal<&'static str>>,
+    inset: bool,
+    class: MaybeProp<String>,
+    children: Children,
+) -> impl IntoView {
+    let DropdownMenuContextValue { menu } = expect_context::<DropdownMenuContextValue>();
+    let level = expect_context::<MenuLevelContextValue>();
+    let item_ref = NodeRef::<Div>::new();
+    let highlighted = RwSignal::new(false);
+
+    let select = move || {
+        if disabled.get_untracked() {
+            return;
+        }
+        on_select.run(());
+        if close_on_select.get_untracked() {
+            menu.dismiss.run(());
+        }
+    };
+
+    view! {
+        <div
+            node_ref=item_ref
+            role=role
+            tabindex="-1"
+            aria-checked=move || aria_checked.map(|checked| checked.get())
+            aria-disabled=move || disabled.get().then_some("true")
+            data-state=move || data_state.map(|state| state.get())
+            data-disabled=move || disabled.get().then_some("")
+            data-highlighted=move || highlighted.get().then_some("")
+            data-text-value=move || text_value.get()
+            class=move || get_menu_item_classes(inset, class.get().unwrap_or_default())
+            on:focus=move |_| highlighted.set(true)
+            on:blur=move |_| highlighted.set(false)
+            on:pointermove=move |event: PointerEvent| {
+                if event.pointer_type() != "mouse"
+                    || menu
+                        .is_pointer_in_grace
+                        .run((f64::from(event.client_x()), f64::from(event.client_y())))
+                {
+                    return;
+                }
+                level.open_sub.set(None);
+                if disabled.get_untracked() {
+                    focus_content(level.content_ref);
+                } else if !highlighted.get_untracked() {
+                    if let Some(item) = item_ref.get_untracked() {
+                        let _ = item.focus();
+                    }
+                }
+            }
+            on:pointerleave=move |event: PointerEvent| {
+                if event.pointer_type() == "mouse"
+                    && !menu
+                        .is_pointer_in_grace
+                        .run((f64::from(event.client_x()), f64::from(event.client_y())))
+                {
+                    focus_content(level.content_ref);
+                }
+            }
+            on:click=move |_| select()
+            on:keydown=move |event: KeyboardEvent| {
+                let key = event.key();
+                if key == "Enter" || (key == " " && level.search.get_untracked().is_empty()) {
+                    event.prevent_default();
+                    select();
+                }
+            }
+        >
+            {children()}
+        </div>
+    }
+}
+
+/// DropdownMenuItem - An action in the menu
+#[component]
+pub fn DropdownMenuItem(
+    #[prop(into, optional)] on_select: Option<Callback<()>>,
+    #[prop(into, optional)] disabled: MaybeProp<bool>,
+    /// Label used for typeahead when the content is not plain text
+    #[prop(into, optional)]
+    text_value: MaybeProp<String>,
+    /// Close the menu after selecting (default: true)
+    #[prop(into, optional)]
+    close_on_select: MaybeProp<bool>,
+    /// Indent to line up with checkbox / radio items
+    #[prop(optional)]
+    inset: bool,
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: Children,
+) -> impl IntoView {
+    view! {
+        <MenuItemView
+            role="menuitem"
+            disabled=Signal::derive(move || disabled.get().unwrap_or(false))
+            text_value=text_value
+            close_on_select=Signal::derive(move || close_on_select.get().unwrap_or(true))
+            on_select=Callback::new(move |_: ()| {
+                if let Some(callback) = on_select {
+                    callback.run(());
+                }
+            })
+            inset=inset
+            class=class
+        >
+            {children()}
+        </MenuItemView>
+    }
+}
+
+/// DropdownMenuCheckboxItem - An item that toggles a CheckedState
+#[component]
+pub fn DropdownMenuCheckboxItem(
+    // Core state management (from our hook library)
+    #[prop(into, optional)] checked: MaybeProp<CheckedState>,
+    #[prop(into, optional)] default_checked: MaybeProp<CheckedState>,
+    #[prop(into, optional)] on_checked_change: Option<Callback<CheckedState>>,
+
+    #[prop(into, optional)] disabled: MaybeProp<bool>,
+    /// Label used for typeahead when the content is not plain text
+    #[prop(into, optional)]
+    text_value: MaybeProp<String>,
+    /// Close the menu after toggling (default: true)
+    #[prop(into, optional)]
+    close_on_select: MaybeProp<bool>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: Children,
+) -> impl IntoView {
+    let checkbox_state = use_checkbox_state(checked, default_checked, on_checked_change);
+    let aria_checked = checkbox_state.get_aria_checked;
+    let state_attr = checkbox_state.get_state_attr;
+
+    view! {
+        <Provider value=MenuItemIndicatorContextValue { checked: checkbox_state.checked }>
+            <MenuItemView
+                role="menuitemcheckbox"
+                disabled=Signal::derive(move || disabled.get().unwrap_or(false))
+                text_value=text_value
+                close_on_select=Signal::derive(move || close_on_select.get().unwrap_or(true))
+                on_select=checkbox_state.toggle
+                aria_checked=Signal::derive(move || aria_checked.get())
+                data_state=Signal::derive(move || state_attr.get())
+                inset=true
+                class=class
+            >
+                {children()}
+            </MenuItemView>
+        </Provider>
+    }
+}
+
+/// DropdownMenuRadioGroup - Groups radio items sharing one value
+#[component]
+pub fn DropdownMenuRadioGroup(
+    // Core state management (from our hook library)
+    #[prop(into, optional)] value: MaybeProp<String>,
+    #[prop(into, optional)] default_value: MaybeProp<String>,
+    #[prop(into, optional)] on_value_change: Option<Callback<String>>,
+
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: Children,
+) -> impl IntoView {
+    let state = use_controllable_state(value, default_value, on_value_change);
+
+    let context_value = MenuRadioGroupContextValue {
+        value: state.value,
+        set_value: state.set_value,
+    };
+
+    view! {
+        <Provider value=context_value>
+            <div role="group" class=move || class.get()>
+                {children()}
+            </div>
+        </Provider>
+    }
+}
+
+/// DropdownMenuRadioItem - One choice inside a DropdownMenuRadioGroup
+#[component]
+pub fn DropdownMenuRadioItem(
+    #[prop(into)] value: String,
+    #[prop(into, optional)] disabled: MaybeProp<bool>,
+    /// Label used for typeahead when the content is not plain text
+    #[prop(into, optional)]
+    text_value: MaybeProp<String>,
+    /// Close the menu after choosing (default: true)
+    #[prop(into, optional)]
+    close_on_select: MaybeProp<bool>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: Children,
+) -> impl IntoView {
+    let group = expect_context::<MenuRadioGroupContextValue>();
+    let item_value = StoredValue::new(value);
+
+    let is_checked =
+        Signal::derive(move || item_value.with_value(|value| *value == group.value.get()));
+    let checked = Signal::derive(move || {
+        if is_checked.get() {
+            CheckedState::True
+        } else {
+            CheckedState::False
+        }
+    });
+
+    view! {
+        <Provider value=MenuItemIndicatorContextValue { checked }>
+            <MenuItemView
+                role="menuitemradio"
+                disabled=Signal::derive(move || disabled.get().unwrap_or(false))
+                text_value=text_value
+                close_on_select=Signal::derive(move || close_on_select.get().unwrap_or(true))
+                on_select=Callback::new(move |_: ()| group.set_value.run(item_value.get_value()))
+                aria_checked=Signal::derive(move || if is_checked.get() { "true" } else { "false" })
+                data_state=Signal::derive(move || {
+                    if is_checked.get() { "checked" } else { "unchecked" }
+                })
+                inset=true
+                class=class
+            >
+                {children()}
+            </MenuItemView>
+        </Provider>
+    }
+}
+
+/// DropdownMenuItemIndicator - Rendered only while its checkbox / radio item is checked
+#[component]
+pub fn DropdownMenuItemIndicator(
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: ChildrenFn,
+) -> impl IntoView {
+    let context = expect_context::<MenuItemIndicatorContextValue>();
+    let children = StoredValue::new(children);
+
+    view! {
+        <Show when=move || context.checked.get() != CheckedState::False>
+            <span
+                data-state=move || match context.checked.get() {
+                    CheckedState::True => "checked",
+                    CheckedState::False => "unchecked",
+                    CheckedState::Indeterminate => "indeterminate",
+                }
+                class=move || {
+                    format!(
+                        "absolute left-2 inline-flex h-4 w-4 items-center justify-center {}",
+                        class.get().unwrap_or_default(),
+                    )
+                }
+            >
+                {children.read_value()()}
+            </span>
+        </Show>
+    }
+}
+
+/// DropdownMenuLabel - Non-interactive heading for a group of items
+#[component]
+pub fn DropdownMenuLabel(
+    #[prop(optional)] inset: bool,
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: Children,
+) -> impl IntoView {
+    view! {
+        <div class=move || {
+            format!(
+                "px-2 py-1.5 text-xs font-semibold text-gray-500 {} {}",
+                if inset { "pl-8" } else { "" },
+                class.get().unwrap_or_default(),
+            )
+        }>{children()}</div>
+    }
+}
+
+/// DropdownMenuGroup - Groups related items (`role="group"`)
+#[component]
+pub fn DropdownMenuGroup(
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: Children,
+) -> impl IntoView {
+    view! {
+        <div role="group" class=move || class.get()>
+            {children()}
+        </div>
+    }
+}
+
+/// DropdownMenuSeparator - Divider between items (reuses Separator)
+#[component]
+pub fn DropdownMenuSeparator(#[prop(into, optional)] class: MaybeProp<String>) -> impl IntoView {
+    view! {
+        <Separator class=Signal::derive(move || {
+            format!("-mx-1 my-1 w-auto {}", class.get().unwrap_or_default())
+        }) />
+    }
+}
+
+/// DropdownMenuSub - Owns the open state of a nested submenu
+#[component]
+pub fn DropdownMenuSub(
+    // Core state management (from our hook library)
+    #[prop(into, optional)] open: MaybeProp<bool>,
+    #[prop(into, optional)] default_open: MaybeProp<bool>,
+    #[prop(into, optional)] on_open_change: Option<Callback<bool>>,
+
+    children: Children,
+) -> impl IntoView {
+    let parent = expect_context::<MenuLevelContextValue>();
+    let state = use_controllable_state(
+        open,
+        default_open.get().unwrap_or(false).into(),
+        on_open_change,
+    );
+    let ids = use_related_ids("dropdown-menu-sub");
+
+    let is_open = state.value;
+    let set_open = state.set_value;
+    let content_id = ids.content_id;
+
+    // Only one submenu per level is open: register when opening, close when
+    // a sibling (or a plain item) takes over
+    Effect::new(move |_| {
+        if is_open.get() {
+            parent.open_sub.set(Some(content_id.get_untracked()));
+        }
+    });
+    Effect::new(move |_| {
+        let active = parent.open_sub.get();
+        if is_open.get_untracked() && active != Some(content_id.get_untracked()) {
+            set_open.run(false);
+        }
+    });
+
+    let context_value = MenuSubContextValue {
+        open: is_open,
+        set_open,
+        get_state_attr: Memo::new(move |_| if is_open.get() { "open" } else { "closed" }),
+        initial_focus: RwSignal::new(None),
+        trigger_id: ids.trigger_id,
+        content_id,
+        trigger_ref: NodeRef::new(),
+        content_ref: NodeRef::new(),
+    };
+
+    view! {
+        <Provider value=context_value>
+            {children()}
+        </Provider>
+    }
+}
+
+/// DropdownMenuSubTrigger - Item that opens a submenu
+#[component]
+pub fn DropdownMenuSubTrigger(
+    #[prop(into, optional)] disabled: MaybeProp<bool>,
+    /// Label used for typeahead when the content is not plain text
+    #[prop(into, optional)]
+    text_value: MaybeProp<String>,
+    #[prop(optional)] inset: bool,
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: Children,
+) -> impl IntoView {
+    let DropdownMenuContextValue { menu } = expect_context::<DropdownMenuContextValue>();
+    let level = expect_context::<MenuLevelContextValue>();
+    let sub = expect_context::<MenuSubContextValue>();
+    let is_disabled = Signal::derive(move || disabled.get().unwrap_or(false));
+    let highlighted = RwSignal::new(false);
+
+    let open_sub = move |focus: Option<Movement>| {
+        if is_disabled.get_untracked() {
+            return;
+        }
+        sub.initial_focus.set(focus);
+        if !sub.open.get_untracked() {
+            sub.set_open.run(true);
+        } else if let Some(movement) = focus {
+            // Already open from hovering: just move focus in
+            if let Some(content) = sub.content_ref.get_untracked() {
+                focus_menu_item(&content, movement);
+            }
+        }
+    };
+
+    view! {
+        <div
+            node_ref=sub.trigger_ref
+            id=move || sub.trigger_id.get()
+            role="menuitem"
+            tabindex="-1"
+            aria-haspopup="menu"
+            aria-expanded=move || if sub.open.get() { "true" } else { "false" }
+            aria-controls=move || sub.open.get().then(|| sub.content_id.get())
+            aria-disabled=move || is_disabled.get().then_some("true")
+            data-state=move || sub.get_state_attr.get()
+            data-disabled=move || is_disabled.get().then_some("")
+            data-highlighted=move || highlighted.get().then_some("")
+            data-text-value=move || text_value.get()
+            class=move || get_menu_item_classes(inset, class.get().unwrap_or_default())
+            on:focus=move |_| highlighted.set(true)
+            on:blur=move |_| highlighted.set(false)
+            on:pointermove=move |event: PointerEvent| {
+                if event.pointer_type() != "mouse"
+                    || menu
+                        .is_pointer_in_grace
+                        .run((f64::from(event.client_x()), f64::from(event.client_y())))
+                {
+                    return;
+                }
+                if is_disabled.get_untracked() {
+                    focus_content(level.content_ref);
+                    return;
+                }
+                if !highlighted.get_untracked() {
+                    if let Some(trigger) = sub.trigger_ref.get_untracked() {
+                        let _ = trigger.focus();
+                    }
+                }
+                open_sub(None);
+            }
+            on:pointerleave=move |event: PointerEvent| {
+                if event.pointer_type() != "mouse" {
+                    return;
+                }
+                let exit = (f64::from(event.client_x()), f64::from(event.client_y()));
+                match sub.content_ref.get_untracked() {
+                    Some(content) if sub.open.get_untracked() => {
+                        menu.set_pointer_grace.run(Some(get_pointer_grace_area(exit, &content)));
+                    }
+                    _ => focus_content(level.content_ref),
+                }
+            }
+            on:click=move |_| open_sub(None)
+            on:keydown=move |event: KeyboardEvent| {
+                let key = event.key();
+                if key == "ArrowRight" || key == "Enter"
+                    || (key == " " && level.search.get_untracked().is_empty())
+                {
+                    event.prevent_default();
+                    event.stop_propagation();
+                    open_sub(Some(Movement::First));
+                }
+            }
+        >
+            {children()}
+            <span aria-hidden="true" class="ml-auto pl-4 text-xs">"›"</span>
+        </div>
+    }
+}
+
+/// DropdownMenuSubContent - The floating submenu (`role="menu"`)
+#[component]
+pub fn DropdownMenuSubContent(
+    /// Distance in px from the sub trigger
+    #[prop(into, optional)]
+    side_offset: MaybeProp<f64>,
+    /// Offset in px from the sub trigger's top edge
+    #[prop(into, optional)]
+    align_offset: MaybeProp<f64>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: ChildrenFn,
+) -> impl IntoView {
+    let sub = expect_context::<MenuSubContextValue>();
+
+    let options = Signal::derive(move || FloatingOptions {
+        side: Side::Right,
+        align: Align::Start,
+        side_offset: side_offset.get().unwrap_or(0.0),
+        align_offset: align_offset.get().unwrap_or(-5.0),
+        ..Default::default()
+    });
+
+    let position = use_floating_position(
+        sub.open,
+        move || {
+            sub.trigger_ref
+                .get()
+                .map(|trigger| trigger.unchecked_into())
+        },
+        sub.content_ref,
+        options,
+    );
+
+    let close_sub = Callback::new(move |_: ()| {
+        sub.set_open.run(false);
+        if let Some(trigger) = sub.trigger_ref.get_untracked() {
+            let _ = trigger.focus();
+        }
+    });
+
+    view! {
+        <MenuContentView
+            open=sub.open
+            get_state_attr=sub.get_state_attr
+            initial_focus=sub.initial_focus
+            content_id=sub.content_id
+            labelled_by=sub.trigger_id
+            content_ref=sub.content_ref
+            position=position
+            on_close_sub=Some(close_sub)
+            class=class
+            children=children
+        />
+    }
+}
@@ -0,0 +1,672 @@
+//! Form Component - Native form validation with accessible error messages
+//!
+//! Features:
+//! - ✅ Reads the native ValidityState of each control (required, type, pattern, min/max...)
+//! - ✅ FormMessage matches value_missing, type_mismatch, ... or a custom predicate
+//! - ✅ Server errors via FormField server_invalid and FormMessage force_match
+//! - ✅ Label, description and error ids wired through use_form_ids
+//! - ✅ No native error bubbles: the first invalid control is focused instead
+//! - ✅ data-valid / data-invalid on field, label and control for styling
+
+use crate::hooks::{use_form_ids, UseFormIdsReturn};
+use leptos::context::Provider;
+use leptos::ev;
+use leptos::html::{Form as FormElement, Input};
+use leptos::prelude::*;
+use std::collections::HashMap;
+use wasm_bindgen::JsCast;
+use web_sys::{HtmlElement, ValidityState};
+
+/// Custom validity message set on controls failing a custom matcher
+const DEFAULT_INVALID_MESSAGE: &str = "This value is not valid";
+
+/// Helper function to generate form CSS classes
+fn get_form_classes(user_class: String) -> String {
+    let base = "flex flex-col gap-4";
+
+    format!("{base} {user_class}")
+}
+
+/// Helper function to generate form field CSS classes
+fn get_form_field_classes(user_class: String) -> String {
+    let base = "grid gap-2";
+
+    format!("{base} {user_class}")
+}
+
+/// Helper function to generate form label CSS classes
+fn get_form_label_classes(user_class: String) -> String {
+    let base = "text-sm font-medium leading-none text-white";
+    let states = "data-[invalid]:text-red-400";
+
+    format!("{base} {states} {user_class}")
+}
+
+/// Helper function to generate form control CSS classes
+fn get_form_control_classes(user_class: String) -> String {
+    let base = "h-9 w-full rounded-md border border-white/20 bg-transparent px-3 text-sm text-white placeholder:text-gray-400";
+    let focus = "focus:outline-none focus-visible:ring-2 focus-visible:ring-black";
+    let states = "data-[invalid]:border-red-400";
+    let disabled = "disabled:cursor-not-allowed disabled:opacity-50";
+
+    format!("{base} {focus} {states} {disabled} {user_class}")
+}
+
+/// Helper function to generate form message CSS classes
+fn get_form_message_classes(user_class: String) -> String {
+    let base = "text-sm text-gray-400";
+
+    format!("{base} {user_class}")
+}
+
+/// Helper function to generate form submit CSS classes
+fn get_form_submit_classes(user_class: String) -> String {
+    let base = "inline-flex h-9 items-center justify-center rounded-md bg-black px-4 text-sm font-medium text-white";
+    let focus = "focus:outline-none focus-visible:ring-2 focus-visible:ring-white";
+    let disabled = "disabled:cursor-not-allowed disabled:opacity-50";
+
+    format!("{base} {focus} {disabled} {user_class}")
+}
+
+/// Id of the `index`th message of a kind within a field
+///
+/// The first description uses `description_id` and the first error
+/// `error_id` as is; further ones get a numeric suffix.
+pub(crate) fn get_message_id(base: &str, index: usize) -> String {
+    if index == 0 {
+        base.to_string()
+    } else {
+        format!("{base}-{}", index + 1)
+    }
+}
+
+/// Snapshot of a control's native ValidityState
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct FormValidity {
+    pub value_missing: bool,
+    pub type_mismatch: bool,
+    pub pattern_mismatch: bool,
+    pub too_long: bool,
+    pub too_short: bool,
+    pub range_underflow: bool,
+    pub range_overflow: bool,
+    pub step_mismatch: bool,
+    pub bad_input: bool,
+    /// A custom matcher (or set_custom_validity) flagged the value
+    pub custom_error: bool,
+    pub valid: bool,
+}
+
+impl From<&ValidityState> for FormValidity {
+    fn from(state: &ValidityState) -> Self {
+        Self {
+            value_missing: state.value_missing(),
+            type_mismatch: state.type_mismatch(),
+            pattern_mismatch: state.pattern_mismatch(),
+            too_long: state.too_long(),
+            too_short: state.too_short(),
+            range_underflow: state.range_underflow(),
+            range_overflow: state.range_overflow(),
+            step_mismatch: state.step_mismatch(),
+            bad_input: state.bad_input(),
+            custom_error: state.custom_error(),
+            valid: state.valid(),
+        }
+    }
+}
+
+impl FormValidity {
+    /// Whether a built-in matcher applies; custom matchers never do here
+    pub fn matches(&self, matcher: &FormMatch) -> bool {
+        match matcher {
+            FormMatch::ValueMissing => self.value_missing,
+            FormMatch::TypeMismatch => self.type_mismatch,
+            FormMatch::PatternMismatch => self.pattern_mismatch,
+            FormMatch::TooLong => self.too_long,
+            FormMatch::TooShort => self.too_short,
+            FormMatch::RangeUnderflow => self.range_underflow,
+            FormMatch::RangeOverflow => self.range_overflow,
+            FormMatch::StepMismatch => self.step_mismatch,
+            FormMatch::BadInput => self.bad_input,
+            FormMatch::Valid => self.valid,
+            FormMatch::Custom(_) => false,
+        }
+    }
+}
+
+/// When a FormMessage is shown
+#[derive(Clone, Copy)]
+pub enum FormMatch {
+    ValueMissing,
+    TypeMismatch,
+    PatternMismatch,
+    TooLong,
+    TooShort,
+    RangeUnderflow,
+    RangeOverflow,
+    StepMismatch,
+    BadInput,
+    Valid,
+    /// Shown when the predicate returns true for the control's value;
+    /// the control is then invalid and blocks submission
+    Custom(Callback<String, bool>),
+}
+
+impl FormMatch {
+    /// Message shown when a FormMessage has no children
+    pub fn default_message(&self) -> &'static str {
+        match self {
+            FormMatch::ValueMissing => "This value is missing",
+            FormMatch::TypeMismatch => "This value does not match the required type",
+            FormMatch::PatternMismatch => "This value does not match the required pattern",
+            FormMatch::TooLong => "This value is too long",
+            FormMatch::TooShort => "This value is too short",
+            FormMatch::RangeUnderflow => "This value is too small",
+            FormMatch::RangeOverflow => "This value is too large",
+            FormMatch::StepMismatch => "This value does not match the required step",
+            FormMatch::BadInput | FormMatch::Custom(_) => DEFAULT_INVALID_MESSAGE,
+            FormMatch::Valid => "",
+        }
+    }
+}
+
+/// A FormControl the Form validates on submit and resets
+#[derive(Clone)]
+struct RegisteredControl {
+    id: String,
+    validate: Callback<()>,
+    reset: Callback<()>,
+}
+
+/// Context value shared between Form and its fields
+#[derive(Clone, Copy)]
+pub struct FormContextValue {
+    /// Validity of each field by name, once checked
+    pub validity: RwSignal<HashMap<String, FormValidity>>,
+    controls: StoredValue<Vec<RegisteredControl>>,
+}
+
+/// Context value shared between FormField and its parts
+#[derive(Clone, Copy)]
+pub struct FormFieldContextValue {
+    pub name: Signal<String>,
+    pub ids: UseFormIdsReturn,
+    /// Native validity once checked (on change, invalid or submit)
+    pub validity: Signal<Option<FormValidity>>,
+    pub server_invalid: Signal<bool>,
+    custom_matchers: StoredValue<Vec<(String, Callback<String, bool>)>>,
+    custom_errors: RwSignal<Vec<String>>,
+    described_by: RwSignal<Vec<String>>,
+    descriptions: StoredValue<usize>,
+    errors: StoredValue<usize>,
+}
+
+impl FormFieldContextValue {
+    /// Valid, invalid or not checked yet
+    fn is_invalid(&self) -> Option<bool> {
+        if self.server_invalid.get() {
+            return Some(true);
+        }
+        self.validity.get().map(|validity| !validity.valid)
+    }
+}
+
+/// Attribute value for a present/absent data attribute
+fn data_flag(on: bool) -> Option<&'static str> {
+    on.then_some("")
+}
+
+/// Form component - Hook-first implementation
+///
+/// Uses our proven hook library for state management and ARIA compliance.
+/// Styled with Tailwind CSS 4 ONLY - no custom CSS allowed.
+///
+/// # Example
+/// ```rust,ignore
+/// view! {
+///     <Form on_submit=move |ev: ev::SubmitEvent| ev.prevent_default()>
+///         <FormField name="email">
+///             <FormLabel>"Email"</FormLabel>
+///             <FormControl r#type="email" required=true />
+///             <FormMessage r#match=FormMatch::ValueMissing>"Please enter your email"</FormMessage>
+///             <FormMessage r#match=FormMatch::TypeMismatch>"Please provide a valid email"</FormMessage>
+///         </FormField>
+///         <FormSubmit>"Sign up"</FormSubmit>
+///     </Form>
+/// }
+/// ```
+#[component]
+pub fn Form(
+    /// Called when a valid form is submitted
+    #[prop(into, optional)]
+    on_submit: Option<Callback<ev::SubmitEvent>>,
+    /// Called on submit and reset so server errors can be cleared
+    #[prop(into, optional)]
+    on_clear_server_errors: Option<Callback<()>>,
+
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: Children,
+) -> impl IntoView {
+    let form_ref = NodeRef::<FormElement>::new();
+    let context_value = FormContextValue {
+        validity: RwSignal::new(HashMap::new()),
+        controls: StoredValue::new(Vec::new()),
+    };
+
+    // Custom matchers only run on change, so check everything once more
+    let handle_submit = move |ev: ev::SubmitEvent| {
+        context_value
+            .controls
+            .with_value(|controls| controls.iter().for_each(|control| control.validate.run(())));
+        let valid = form_ref
+            .get_untracked()
+            .is_none_or(|form| form.check_validity());
+        if !valid {
+            ev.prevent_default();
+            return;
+        }
+        if let Some(callback) = on_clear_server_errors {
+            callback.run(());
+        }
+        if let Some(callback) = on_submit {
+            callback.run(ev);
+        }
+    };
+
+    let handle_reset = move |_: ev::Event| {
+        context_value
+            .controls
+            .with_value(|controls| controls.iter().for_each(|control| control.reset.run(())));
+        context_value.validity.set(HashMap::new());
+        if let Some(callback) = on_clear_server_errors {
+            callback.run(());
+        }
+    };
+
+    // Replace the native error bubbles by focusing the first invalid control
+    let handle_invalid = move |ev: ev::Event| {
+        ev.prevent_default();
+        let Some(form) = form_ref.get_untracked() else {
+            return;
+        };
+        let first_invalid = form
+            .query_selector("input:invalid, select:invalid, textarea:invalid")
+            .ok()
+            .flatten()
+            .and_then(|control| control.dyn_into::<HtmlElement>().ok());
+        if let Some(control) = first_invalid {
+            let is_target = ev
+                .target()
+                .is_some_and(|target| target == control.clone().into());
+            if is_target {
+                let _ = control.focus();
+            }
+        }
+    };
+
+    view! {
+        <Provider value=context_value>
+            <form
+                node_ref=form_ref
+                // 🚨 TAILWIND CSS 4 ONLY - Stacked fields
+                class=move || get_form_classes(class.get().unwrap_or_default())
+                on:submit=handle_submit
+                on:reset=handle_reset
+                on:invalid:capture=handle_invalid
+            >
+                {children()}
+            </form>
+        </Provider>
+    }
+}
+
+/// FormField - Groups the label, control and messages of one named value
+#[component]
+pub fn FormField(
+    /// Name of the control (also its form data key)
+    #[prop(into)]
+    name: String,
+    /// Marks the field invalid, e.g. after the server rejected the value
+    #[prop(into, optional)]
+    server_invalid: MaybeProp<bool>,
+
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: Children,
+) -> impl IntoView {
+    let form = expect_context::<FormContextValue>();
+    let ids = use_form_ids(name.clone());
+    let validity = {
+        let name = name.clone();
+        Signal::derive(move || form.validity.with(|validity| validity.get(&name).copied()))
+    };
+
+    let field = FormFieldContextValue {
+        name: Signal::stored(name),
+        ids,
+        validity,
+        server_invalid: Signal::derive(move || server_invalid.get().unwrap_or(false)),
+        custom_matchers: StoredValue::new(Vec::new()),
+        custom_errors: RwSignal::new(Vec::new()),
+        described_by: RwSignal::new(Vec::new()),
+        descriptions: StoredValue::new(0),
+        errors: StoredValue::new(0),
+    };
+
+    view! {
+        <Provider value=field>
+            <div
+                // Data attributes for Tailwind CSS 4 styling
+                data-valid=move || data_flag(field.is_invalid() == Some(false))
+                data-invalid=move || data_flag(field.is_invalid() == Some(true))
+                // 🚨 TAILWIND CSS 4 ONLY - Label above control and messages
+                class=move || get_form_field_classes(class.get().unwrap_or_default())
+            >
+                {children()}
+            </div>
+        </Provider>
+    }
+}
+
+/// FormLabel - Label of the field's control
+#[component]
+pub fn FormLabel(
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: Children,
+) -> impl IntoView {
+    let field = expect_context::<FormFieldContextValue>();
+
+    view! {
+        <label
+            id=move || field.ids.label_id.get()
+            for=move || field.ids.input_id.get()
+            data-valid=move || data_flag(field.is_invalid() == Some(false))
+            data-invalid=move || data_flag(field.is_invalid() == Some(true))
+            // 🚨 TAILWIND CSS 4 ONLY - Turns red when invalid
+            class=move || get_form_label_classes(class.get().unwrap_or_default())
+        >
+            {children()}
+        </label>
+    }
+}
+
+/// FormControl - The native input whose validity drives the messages
+///
+/// Messages hide while the user types and are checked again on change
+/// (blur), on submit, and whenever the browser reports the input invalid.
+#[component]
+pub fn FormControl(
+    #[prop(into, optional)] r#type: MaybeProp<String>,
+    /// Initial value
+    #[prop(into, optional)]
+    value: MaybeProp<String>,
+    #[prop(into, optional)] placeholder: MaybeProp<String>,
+    #[prop(into, optional)] autocomplete: MaybeProp<String>,
+
+    // Native constraints
+    #[prop(into, optional)] required: MaybeProp<bool>,
+    #[prop(into, optional)] pattern: MaybeProp<String>,
+    #[prop(into, optional)] min: MaybeProp<String>,
+    #[prop(into, optional)] max: MaybeProp<String>,
+    #[prop(into, optional)] step: MaybeProp<String>,
+    #[prop(into, optional)] min_length: MaybeProp<u32>,
+    #[prop(into, optional)] max_length: MaybeProp<u32>,
+    #[prop(into, optional)] disabled: MaybeProp<bool>,
+
+    #[prop(into, optional)] class: MaybeProp<String>,
+) -> impl IntoView {
+    let form = expect_context::<FormContextValue>();
+    let field = expect_context::<FormFieldContextValue>();
+    let input_ref = NodeRef::<Input>::new();
+
+    // Native validity first; custom matchers only judge otherwise valid values
+    let validate = move || {
+        let Some(input) = input_ref.get_untracked() else {
+            return;
+        };
+        input.set_custom_validity("");
+        let mut matched = Vec::new();
+        if input.validity().valid() {
+            let value = input.value();
+            field.custom_matchers.with_value(|matchers| {
+                for (id, matcher) in matchers {
+                    if matcher.run(value.clone()) {
+                        matched.push(id.clone());
+                    }
+                }
+            });
+            if !matched.is_empty() {
+                input.set_custom_validity(DEFAULT_INVALID_MESSAGE);
+            }
+        }
+        field.custom_errors.set(matched);
+        let validity = FormValidity::from(&input.validity());
+        form.validity.update(|fields| {
+            fields.insert(field.name.get_untracked(), validity);
+        });
+    };
+
+    let reset = move || {
+        if let Some(input) = input_ref.get_untracked() {
+            input.set_custom_validity("");
+        }
+        field.custom_errors.set(Vec::new());
+        form.validity.update(|fields| {
+            fields.remove(&field.name.get_untracked());
+        });
+    };
+
+    // Let the form validate this control on submit
+    let control_id = field.ids.input_id.get_untracked();
+    form.controls.update_value(|controls| {
+        controls.push(RegisteredControl {
+            id: control_id.clone(),
+            validate: Callback::new(move |_| validate()),
+            reset: Callback::new(move |_| reset()),
+        })
+    });
+    on_cleanup(move || {
+        form.controls
+            .try_update_value(|controls| controls.retain(|control| control.id != control_id));
+    });
+
+    let described_by = move || {
+        let ids = field.described_by.get();
+        (!ids.is_empty()).then(|| ids.join(" "))
+    };
+
+    view! {
+        <input
+            node_ref=input_ref
+            id=move || field.ids.input_id.get()
+            name=move || field.name.get()
+            type=move || r#type.get().unwrap_or_else(|| "text".to_string())
+            value=move || value.get()
+            placeholder=move || placeholder.get()
+            autocomplete=move || autocomplete.get()
+            required=move || required.get().unwrap_or(false)
+            pattern=move || pattern.get()
+            min=move || min.get()
+            max=move || max.get()
+            step=move || step.get()
+            minlength=move || min_length.get()
+            maxlength=move || max_length.get()
+            disabled=move || disabled.get().unwrap_or(false)
+            // Keep the native tooltip from repeating the messages
+            title=""
+            // ARIA attributes for accessibility
+            aria-labelledby=move || field.ids.label_id.get()
+            aria-describedby=described_by
+            aria-invalid=move || field.is_invalid().filter(|invalid| *invalid).map(|_| "true")
+            // Data attributes for Tailwind CSS 4 styling
+            data-valid=move || data_flag(field.is_invalid() == Some(false))
+            data-invalid=move || data_flag(field.is_invalid() == Some(true))
+            // 🚨 TAILWIND CSS 4 ONLY - Text input with invalid border
+            class=move || get_form_control_classes(class.get().unwrap_or_default())
+            on:input=move |_| reset()
+            on:change=move |_| validate()
+            on:invalid=move |_| validate()
+        />
+    }
+}
+
+/// FormMessage - A description, or an error shown when its match applies
+///
+/// Without `match` or `force_match` the message always shows and
+/// describes the control.
+#[component]
+pub fn FormMessage(
+    /// Validity condition that shows the message
+    #[prop(into, optional)]
+    r#match: Option<FormMatch>,
+    /// Show regardless of validity, e.g. for a server error
+    #[prop(into, optional)]
+    force_match: MaybeProp<bool>,
+
+    #[prop(into, optional)] class: MaybeProp<String>,
+    #[prop(optional)] children: Option<ChildrenFn>,
+) -> impl IntoView {
+    let field = expect_context::<FormFieldContextValue>();
+
+    // Descriptions and errors are numbered separately within the field
+    let is_error = r#match.is_some() || force_match.get_untracked().is_some();
+    let (counter, base_id) = if is_error {
+        (field.errors, field.ids.error_id)
+    } else {
+        (field.descriptions, field.ids.description_id)
+    };
+    let index = counter.get_value();
+    counter.set_value(index + 1);
+    let id = get_message_id(&base_id.get_untracked(), index);
+
+    if let Some(FormMatch::Custom(matcher)) = r#match {
+        let id = id.clone();
+        field
+            .custom_matchers
+            .update_value(|matchers| matchers.push((id, matcher)));
+    }
+
+    let is_shown = {
+        let id = id.clone();
+        move || {
+            if force_match.get().unwrap_or(false) {
+                return true;
+            }
+            let Some(matcher) = r#match else {
+                return true;
+            };
+            let Some(validity) = field.validity.get() else {
+                return false;
+            };
+            match matcher {
+                FormMatch::Custom(_) => field.custom_errors.with(|errors| errors.contains(&id)),
+                matcher => validity.matches(&matcher),
+            }
+        }
+    };
+
+    // Shown messages describe the control
+    Effect::new({
+        let id = id.clone();
+        let is_shown = is_shown.clone();
+        move |_| {
+            let shown = is_shown();
+            field.described_by.update(|ids| {
+                ids.retain(|described| described != &id);
+                if shown {
+                    ids.push(id.clone());
+                    ids.sort();
+                }
+            });
+        }
+    });
+    {
+        let id = id.clone();
+        on_cleanup(move || {
+            field
+                .described_by
+                .try_update(|ids| ids.retain(|described| described != &id));
+            field
+                .custom_matchers
+                .try_update_value(|matchers| matchers.retain(|(matcher_id, _)| matcher_id != &id));
+        });
+    }
+
+    let children = StoredValue::new(children);
+    let id = StoredValue::new(id);
+
+    view! {
+        <Show when=is_shown>
+            <span
+                id=move || id.get_value()
+                // 🚨 TAILWIND CSS 4 ONLY - Small helper/error text
+                class=move || get_form_message_classes(class.get().unwrap_or_default())
+            >
+                {move || match children.read_value().as_ref() {
+                    Some(children) => children().into_any(),
+                    None => r#match.map(|matcher| matcher.default_message()).into_any(),
+                }}
+            </span>
+        </Show>
+    }
+}
+
+/// FormValidityState - Renders its children with a field's validity
+///
+/// Uses the surrounding FormField unless `name` is given.
+///
+/// # Example
+/// ```rust,ignore
+/// view! {
+///     <FormValidityState let:validity>
+///         {validity.map(|validity| if validity.valid { "✓" } else { "✗" })}
+///     </FormValidityState>
+/// }
+/// ```
+#[component]
+pub fn FormValidityState<F, IV>(
+    #[prop(into, optional)] name: MaybeProp<String>,
+    children: F,
+) -> impl IntoView
+where
+    F: Fn(Option<FormValidity>) -> IV + Send + Sync + 'static,
+    IV: IntoView + 'static,
+{
+    let form = expect_context::<FormContextValue>();
+    let field = use_context::<FormFieldContextValue>();
+    let validity = move || {
+        let name = name.get().or_else(|| field.map(|field| field.name.get()))?;
+        form.validity.with(|validity| validity.get(&name).copied())
+    };
+
+    move || children(validity())
+}
+
+/// FormSubmit - Submit button of the form
+#[component]
+pub fn FormSubmit(
+    #[prop(into, optional)] disabled: MaybeProp<bool>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: Children,
+) -> impl IntoView {
+    view! {
+        <button
+            type="submit"
+            disabled=move || disabled.get().unwrap_or(false)
+            // 🚨 TAILWIND CSS 4 ONLY - Primary button
+            class=move || get_form_submit_classes(class.get().unwrap_or_default())
+        >
+            {children()}
+        </button>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_message_ids() {
+        assert_eq!(get_message_id("email-1-error", 0), "email-1-error");
+        assert_eq!(get_message_id("email-1-error", 1), "email-1-error-2");
+    }
+}
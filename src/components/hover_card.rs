@@ -0,0 +1,310 @@
+//! HoverCard Component - Rich preview of a link's target for sighted mouse users
+//!
+//! Features:
+//! - ✅ Controlled/uncontrolled open state (open, default_open, on_open_change)
+//! - ✅ Open/close delays (open_delay, close_delay) from use_tooltip_behavior
+//! - ✅ Stays open while the pointer (or focus) is inside the content
+//! - ✅ Ignores touch input: tapping the link just follows it
+//! - ✅ Escape closes; collision-aware positioning with data-side / data-align and optional arrow
+
+use crate::hooks::{
+    get_arrow_style, use_floating_position, use_tooltip_behavior, Align, FloatingOptions,
+    Placement, Side, TooltipDelayGroup, TooltipDelays, UseTooltipBehaviorReturn,
+};
+use leptos::context::Provider;
+use leptos::ev;
+use leptos::html::{Div, A};
+use leptos::prelude::*;
+use wasm_bindgen::JsCast;
+
+/// Card timing: no skip delay, so every hover waits for `open_delay`
+fn get_hover_card_delays(open_delay: Option<u64>, close_delay: Option<u64>) -> TooltipDelays {
+    TooltipDelays {
+        open_delay: open_delay.unwrap_or(700),
+        close_delay: close_delay.unwrap_or(300),
+        skip_delay: 0,
+    }
+}
+
+/// Helper function to generate hover card trigger CSS classes
+fn get_hover_card_trigger_classes(user_class: String) -> String {
+    let base = "inline-flex items-center text-sm font-medium underline-offset-4 hover:underline";
+    let focus = "focus:outline-none focus-visible:ring-2 focus-visible:ring-black";
+
+    format!("{base} {focus} {user_class}")
+}
+
+/// Helper function to generate hover card content CSS classes
+fn get_hover_card_content_classes(user_class: String) -> String {
+    let base =
+        "z-50 w-72 rounded-md border border-white/10 bg-gray-900 p-4 text-sm text-white shadow-md";
+    let animation = "data-[state=open]:animate-fade-in";
+
+    format!("{base} {animation} {user_class}")
+}
+
+/// Whether a pointer event comes from a mouse or pen rather than touch
+fn is_hover_pointer(event: &ev::PointerEvent) -> bool {
+    event.pointer_type() != "touch"
+}
+
+/// Context value shared between HoverCard and its parts
+#[derive(Clone, Copy)]
+pub struct HoverCardContextValue {
+    pub hover_card: UseTooltipBehaviorReturn,
+    pub trigger_ref: NodeRef<A>,
+    pub content_ref: NodeRef<Div>,
+}
+
+/// Context value shared between HoverCardContent and HoverCardArrow
+#[derive(Clone, Copy)]
+struct HoverCardContentContextValue {
+    placement: Signal<Option<Placement>>,
+}
+
+/// HoverCard component - Hook-first implementation
+///
+/// Uses our proven hook library for state management and ARIA compliance.
+/// Styled with Tailwind CSS 4 ONLY - no custom CSS allowed.
+///
+/// The card only previews what the link leads to: it is meant for sighted
+/// mouse and keyboard users, while touch users simply follow the link.
+///
+/// # Example
+/// ```rust,ignore
+/// view! {
+///     <HoverCard open_delay=500_u64>
+///         <HoverCardTrigger href="https://github.com/leptos-rs">"@leptos-rs"</HoverCardTrigger>
+///         <HoverCardContent side_offset=6.0>
+///             "Build fast web applications with Rust."
+///             <HoverCardArrow />
+///         </HoverCardContent>
+///     </HoverCard>
+/// }
+/// ```
+#[component]
+pub fn HoverCard(
+    // Core state management (from our hook library)
+    #[prop(into, optional)] open: MaybeProp<bool>,
+    #[prop(into, optional)] default_open: MaybeProp<bool>,
+    #[prop(into, optional)] on_open_change: Option<Callback<bool>>,
+
+    /// Hover time in ms before the card opens (default: 700)
+    #[prop(into, optional)]
+    open_delay: MaybeProp<u64>,
+    /// Time in ms after the pointer leaves before the card closes (default: 300)
+    #[prop(into, optional)]
+    close_delay: MaybeProp<u64>,
+
+    children: Children,
+) -> impl IntoView {
+    let delays = Signal::derive(move || get_hover_card_delays(open_delay.get(), close_delay.get()));
+    // A private group with the card's own delays: the default group's skip
+    // delay would let a quick re-hover open the card without `open_delay`
+    let group = TooltipDelayGroup::new(delays);
+    let hover_card = use_tooltip_behavior(
+        open,
+        default_open,
+        on_open_change,
+        Some(group),
+        Some(delays),
+    );
+
+    let context_value = HoverCardContextValue {
+        hover_card,
+        trigger_ref: NodeRef::new(),
+        content_ref: NodeRef::new(),
+    };
+
+    view! {
+        <Provider value=context_value>
+            {children()}
+        </Provider>
+    }
+}
+
+/// HoverCardTrigger - The link whose target the card previews
+#[component]
+pub fn HoverCardTrigger(
+    #[prop(into, optional)] href: MaybeProp<String>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: Children,
+) -> impl IntoView {
+    let HoverCardContextValue {
+        hover_card,
+        trigger_ref,
+        ..
+    } = expect_context::<HoverCardContextValue>();
+
+    view! {
+        <a
+            node_ref=trigger_ref
+            href=move || href.get()
+            // Data attributes for Tailwind CSS 4 styling
+            data-state=move || if hover_card.open.get() { "open" } else { "closed" }
+            // 🚨 TAILWIND CSS 4 ONLY - Inline link styling
+            class=move || get_hover_card_trigger_classes(class.get().unwrap_or_default())
+            on:pointerenter=move |event| {
+                if is_hover_pointer(&event) {
+                    hover_card.on_trigger_enter.run(());
+                }
+            }
+            on:pointerleave=move |event| {
+                if is_hover_pointer(&event) {
+                    hover_card.on_trigger_leave.run(());
+                }
+            }
+            // Keyboard users get the same delayed preview
+            on:focus=move |_| hover_card.on_trigger_enter.run(())
+            on:blur=move |_| hover_card.on_trigger_leave.run(())
+            // Following the link (or a tap) should not leave a card behind
+            on:click=move |_| hover_card.close.run(())
+        >
+            {children()}
+        </a>
+    }
+}
+
+/// HoverCardContent - The floating preview
+#[component]
+pub fn HoverCardContent(
+    /// Preferred side of the trigger (default: bottom)
+    #[prop(into, optional)]
+    side: MaybeProp<Side>,
+    /// Preferred alignment against the trigger (default: center)
+    #[prop(into, optional)]
+    align: MaybeProp<Align>,
+    /// Distance in px from the trigger
+    #[prop(into, optional)]
+    side_offset: MaybeProp<f64>,
+    /// Offset in px from the aligned edge
+    #[prop(into, optional)]
+    align_offset: MaybeProp<f64>,
+    /// Flip/shift to stay in the viewport (default: true)
+    #[prop(into, optional)]
+    avoid_collisions: MaybeProp<bool>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: ChildrenFn,
+) -> impl IntoView {
+    let HoverCardContextValue {
+        hover_card,
+        trigger_ref,
+        content_ref,
+    } = expect_context::<HoverCardContextValue>();
+    let children = StoredValue::new(children);
+
+    let options = Signal::derive(move || FloatingOptions {
+        side: side.get().unwrap_or(Side::Bottom),
+        align: align.get().unwrap_or_default(),
+        side_offset: side_offset.get().unwrap_or(0.0),
+        align_offset: align_offset.get().unwrap_or(0.0),
+        avoid_collisions: avoid_collisions.get().unwrap_or(true),
+        ..Default::default()
+    });
+
+    let position = use_floating_position(
+        hover_card.open,
+        move || trigger_ref.get().map(|trigger| trigger.unchecked_into()),
+        content_ref,
+        options,
+    );
+
+    let content_context = HoverCardContentContextValue {
+        placement: position.placement,
+    };
+
+    view! {
+        <Show when=move || hover_card.open.get()>
+            <Provider value=content_context>
+                <div
+                    node_ref=content_ref
+                    // Data attributes for Tailwind CSS 4 styling
+                    data-state=move || if hover_card.open.get() { "open" } else { "closed" }
+                    data-side=move || position.side.get().as_str()
+                    data-align=move || position.align.get().as_str()
+                    style=move || position.style.get()
+                    // 🚨 TAILWIND CSS 4 ONLY - Floating card styling
+                    class=move || get_hover_card_content_classes(class.get().unwrap_or_default())
+                    on:pointerenter=move |event| {
+                        if is_hover_pointer(&event) {
+                            hover_card.on_content_enter.run(());
+                        }
+                    }
+                    on:pointerleave=move |event| {
+                        if is_hover_pointer(&event) {
+                            hover_card.on_content_leave.run(());
+                        }
+                    }
+                    // Tabbing from the trigger into links in the card keeps it open
+                    on:focusin=move |_| hover_card.on_content_enter.run(())
+                    on:focusout=move |_| hover_card.on_content_leave.run(())
+                >
+                    {children.read_value()()}
+                </div>
+            </Provider>
+        </Show>
+    }
+}
+
+/// HoverCardArrow - Small triangle on the content edge pointing at the trigger
+#[component]
+pub fn HoverCardArrow(
+    #[prop(into, optional)] width: MaybeProp<f64>,
+    #[prop(into, optional)] height: MaybeProp<f64>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+) -> impl IntoView {
+    let content_context = expect_context::<HoverCardContentContextValue>();
+    let width = Signal::derive(move || width.get().unwrap_or(10.0));
+    let height = Signal::derive(move || height.get().unwrap_or(5.0));
+
+    view! {
+        <svg
+            width=move || width.get()
+            height=move || height.get()
+            viewBox="0 0 30 10"
+            preserveAspectRatio="none"
+            aria-hidden="true"
+            style=move || {
+                get_arrow_style(content_context.placement.get(), width.get(), height.get())
+            }
+            class=move || format!("fill-gray-900 {}", class.get().unwrap_or_default())
+        >
+            <polygon points="0,0 30,0 15,10" />
+        </svg>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hooks::get_close_delay;
+
+    fn group(delays: TooltipDelays) -> TooltipDelayGroup {
+        TooltipDelayGroup::new(Signal::derive(move || delays))
+    }
+
+    #[test]
+    fn test_card_waits_for_its_open_and_close_delays() {
+        let delays = get_hover_card_delays(None, None);
+        assert_eq!(group(delays).get_open_delay(delays), Some(700));
+        assert_eq!(get_close_delay(delays), Some(300));
+    }
+
+    #[test]
+    fn test_custom_delays_apply() {
+        let delays = get_hover_card_delays(Some(200), Some(0));
+        assert_eq!(group(delays).get_open_delay(delays), Some(200));
+        assert_eq!(get_close_delay(delays), None);
+    }
+
+    #[test]
+    fn test_open_tooltip_does_not_skip_the_card_delay() {
+        // Each card has a private group, apart from the tooltip provider's
+        let tooltips = group(TooltipDelays::default());
+        let delays = get_hover_card_delays(None, None);
+        let card = group(delays);
+
+        tooltips.on_open();
+        assert_eq!(card.get_open_delay(delays), Some(700));
+    }
+}
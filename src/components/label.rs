@@ -0,0 +1,241 @@
+//! Label Component - Accessible label for form controls
+//!
+//! Features:
+//! - ✅ Clicking the label activates the button-based control it points to
+//!   (`r#for` or a wrapped Checkbox/Switch/...) instead of its hidden input
+//! - ✅ Double-clicking the text does not select it
+//! - ✅ Checkbox and Switch pick up aria-labelledby from a wrapping or referencing Label
+//! - ✅ Native `<label>`: plain inputs keep the browser behavior
+
+use crate::hooks::use_id_with_prefix;
+use leptos::context::Provider;
+use leptos::ev;
+use leptos::html::Label as LabelElement;
+use leptos::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement};
+
+/// Controls a label can point to; hidden form inputs are mirrors, not controls
+const CONTROL_SELECTOR: &str = "button, input:not([aria-hidden='true']), select, textarea";
+
+/// Helper function to generate label CSS classes
+fn get_label_classes(user_class: String) -> String {
+    let base = "text-sm font-medium leading-none text-white cursor-pointer";
+
+    format!("{base} {user_class}")
+}
+
+/// Context value provided to controls wrapped in a Label
+#[derive(Clone, Copy)]
+pub struct LabelContextValue {
+    /// Id of the wrapping label element
+    pub id: Signal<String>,
+}
+
+/// aria-labelledby from the wrapping label and the labels whose `for` is the control
+fn get_labelled_by(wrapping: Option<String>, referencing: &[String]) -> Option<String> {
+    wrapping.or_else(|| (!referencing.is_empty()).then(|| referencing.join(" ")))
+}
+
+/// What a click on the label does to its button-based control
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum LabelClick {
+    /// The click is on another control inside the label: leave it alone
+    Ignore,
+    /// The click is on the control itself: only keep the browser from also
+    /// clicking the hidden input
+    Absorb,
+    /// The click is on the label text: activate the control
+    Activate,
+}
+
+fn get_label_click(on_control: bool, on_other_control: bool) -> LabelClick {
+    match (on_control, on_other_control) {
+        (true, _) => LabelClick::Absorb,
+        (false, true) => LabelClick::Ignore,
+        (false, false) => LabelClick::Activate,
+    }
+}
+
+/// aria-labelledby for a button-based control with the given id
+///
+/// A wrapping Label wins; otherwise every Label whose `for` is `id` is found
+/// once mounted.
+pub(crate) fn use_labelled_by(id: Signal<String>) -> Signal<Option<String>> {
+    let wrapping = use_context::<LabelContextValue>();
+    let referencing = RwSignal::new(Vec::<String>::new());
+
+    Effect::new(move |_| {
+        let id = id.get();
+        let selector = format!("label[for=\"{id}\"][id]");
+        let Ok(labels) = document().query_selector_all(&selector) else {
+            return;
+        };
+        referencing.set(
+            (0..labels.length())
+                .filter_map(|index| labels.item(index))
+                .filter_map(|label| label.dyn_into::<Element>().ok())
+                .map(|label| label.id())
+                .collect(),
+        );
+    });
+
+    Signal::derive(move || {
+        get_labelled_by(wrapping.map(|label| label.id.get()), &referencing.read())
+    })
+}
+
+/// Label component - Hook-first implementation
+///
+/// Uses our proven hook library for state management and ARIA compliance.
+/// Styled with Tailwind CSS 4 ONLY - no custom CSS allowed.
+///
+/// # Example
+/// ```rust,ignore
+/// view! {
+///     <Checkbox id="terms"><CheckboxIndicator>"✓"</CheckboxIndicator></Checkbox>
+///     <Label r#for="terms">"Accept terms"</Label>
+///
+///     <Label class="flex items-center gap-2">
+///         <Switch><SwitchThumb /></Switch>
+///         "Enable notifications"
+///     </Label>
+/// }
+/// ```
+#[component]
+pub fn Label(
+    /// Id of the control this label describes (default: the wrapped control)
+    #[prop(into, optional)]
+    r#for: MaybeProp<String>,
+
+    // Accessibility & DOM
+    #[prop(into, optional)] id: MaybeProp<String>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+
+    children: Children,
+) -> impl IntoView {
+    let label_ref = NodeRef::<LabelElement>::new();
+    let generated_id = use_id_with_prefix("label");
+    let final_id = Signal::derive(move || id.get().unwrap_or_else(|| generated_id.get()));
+
+    // Context for wrapped controls
+    let context_value = LabelContextValue { id: final_id };
+
+    // The control this label activates
+    let find_control = move || -> Option<HtmlElement> {
+        match r#for.get_untracked() {
+            Some(for_id) => document().get_element_by_id(&for_id),
+            None => label_ref
+                .get_untracked()?
+                .query_selector(CONTROL_SELECTOR)
+                .ok()
+                .flatten(),
+        }
+        .and_then(|control| control.dyn_into::<HtmlElement>().ok())
+    };
+
+    // Button controls are activated here: the browser would otherwise click
+    // their first labelable sibling, the hidden form input
+    let on_click = move |ev: ev::MouseEvent| {
+        let Some(control) = find_control().filter(|control| control.tag_name() == "BUTTON") else {
+            return;
+        };
+        let target = ev
+            .target()
+            .and_then(|target| target.dyn_into::<Element>().ok());
+        let on_control = target
+            .as_ref()
+            .is_some_and(|target| control.contains(Some(target)));
+        let on_other_control = target
+            .as_ref()
+            .and_then(|target| {
+                target
+                    .closest("a, button, input, select, textarea")
+                    .ok()
+                    .flatten()
+            })
+            .is_some();
+        match get_label_click(on_control, on_other_control) {
+            LabelClick::Ignore => {}
+            LabelClick::Absorb => ev.prevent_default(),
+            LabelClick::Activate => {
+                ev.prevent_default();
+                control.click();
+            }
+        }
+    };
+
+    // Double-clicking the label text should not select it
+    let on_mousedown = move |ev: ev::MouseEvent| {
+        let on_control = ev
+            .target()
+            .and_then(|target| target.dyn_into::<Element>().ok())
+            .and_then(|target| {
+                target
+                    .closest("button, input, select, textarea")
+                    .ok()
+                    .flatten()
+            })
+            .is_some();
+        if !on_control && !ev.default_prevented() && ev.detail() > 1 {
+            ev.prevent_default();
+        }
+    };
+
+    view! {
+        <Provider value=context_value>
+            <label
+                node_ref=label_ref
+                id=move || final_id.get()
+                for=move || r#for.get()
+                // 🚨 TAILWIND CSS 4 ONLY - Text styling for control labels
+                class=move || get_label_classes(class.get().unwrap_or_default())
+                on:click=on_click
+                on:mousedown=on_mousedown
+            >
+                {children()}
+            </label>
+        </Provider>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ids(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn test_wrapping_label_wins() {
+        assert_eq!(
+            get_labelled_by(Some("label-1".to_string()), &ids(&["label-2"])),
+            Some("label-1".to_string())
+        );
+    }
+
+    #[test]
+    fn test_every_referencing_label_is_listed() {
+        assert_eq!(
+            get_labelled_by(None, &ids(&["label-2", "label-3"])),
+            Some("label-2 label-3".to_string())
+        );
+        assert_eq!(get_labelled_by(None, &[]), None);
+    }
+
+    #[test]
+    fn test_clicking_the_text_activates_the_control() {
+        assert_eq!(get_label_click(false, false), LabelClick::Activate);
+    }
+
+    #[test]
+    fn test_clicking_the_control_is_not_doubled() {
+        assert_eq!(get_label_click(true, true), LabelClick::Absorb);
+    }
+
+    #[test]
+    fn test_clicking_another_control_is_left_alone() {
+        assert_eq!(get_label_click(false, true), LabelClick::Ignore);
+    }
+}
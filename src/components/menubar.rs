@@ -0,0 +1,396 @@
+//! Menubar Component - Desktop-style horizontal bar of menus (File / Edit / View)
+//!
+//! Features:
+//! - ✅ Controlled/uncontrolled open menu (value, default_value, on_value_change)
+//! - ✅ Roving focus between triggers with a single tab stop
+//! - ✅ WAI-ARIA menubar keyboard model: Left/Right between menus, Down/Up/Enter/Space to open
+//! - ✅ Left/Right inside an open menu switch to the neighbouring menu
+//! - ✅ Hovering a sibling trigger switches menus once one is open
+//! - ✅ Shares item semantics with DropdownMenu (checkbox / radio items, submenus, data-highlighted)
+
+use super::dropdown_menu::{DropdownMenuContextValue, MenuContentView};
+use crate::hooks::{
+    use_controllable_state, use_dropdown_behavior, use_floating_position, use_id_with_prefix,
+    Align, FloatingOptions, Side,
+};
+use crate::utils::{move_index, query_elements, Movement};
+use leptos::context::Provider;
+use leptos::html::Div;
+use leptos::prelude::*;
+use std::collections::HashMap;
+use wasm_bindgen::JsCast;
+use web_sys::{HtmlElement, KeyboardEvent, PointerEvent};
+
+// Item parts are shared with DropdownMenu
+pub use super::dropdown_menu::{
+    DropdownMenuCheckboxItem as MenubarCheckboxItem,
+    DropdownMenuCheckboxItemProps as MenubarCheckboxItemProps, DropdownMenuGroup as MenubarGroup,
+    DropdownMenuGroupProps as MenubarGroupProps, DropdownMenuItem as MenubarItem,
+    DropdownMenuItemIndicator as MenubarItemIndicator,
+    DropdownMenuItemIndicatorProps as MenubarItemIndicatorProps,
+    DropdownMenuItemProps as MenubarItemProps, DropdownMenuLabel as MenubarLabel,
+    DropdownMenuLabelProps as MenubarLabelProps, DropdownMenuRadioGroup as MenubarRadioGroup,
+    DropdownMenuRadioGroupProps as MenubarRadioGroupProps,
+    DropdownMenuRadioItem as MenubarRadioItem, DropdownMenuRadioItemProps as MenubarRadioItemProps,
+    DropdownMenuSeparator as MenubarSeparator, DropdownMenuSeparatorProps as MenubarSeparatorProps,
+    DropdownMenuSub as MenubarSub, DropdownMenuSubContent as MenubarSubContent,
+    DropdownMenuSubContentProps as MenubarSubContentProps, DropdownMenuSubProps as MenubarSubProps,
+    DropdownMenuSubTrigger as MenubarSubTrigger,
+    DropdownMenuSubTriggerProps as MenubarSubTriggerProps,
+};
+
+/// Selector for the triggers of one menubar
+const TRIGGER_SELECTOR: &str = "[data-menubar-trigger]";
+
+/// Helper function to generate menubar CSS classes
+fn get_menubar_classes(user_class: String) -> String {
+    let base = "flex h-9 items-center gap-1 rounded-md border border-gray-200 bg-white p-1";
+
+    format!("{base} {user_class}")
+}
+
+/// Helper function to generate menubar trigger CSS classes
+fn get_menubar_trigger_classes(user_class: String) -> String {
+    let base =
+        "flex cursor-default select-none items-center rounded px-3 py-1 text-sm font-medium text-gray-900";
+    let focus = "outline-none focus:bg-gray-100";
+    let states = "data-[state=open]:bg-gray-100 data-[disabled]:pointer-events-none data-[disabled]:opacity-50";
+
+    format!("{base} {focus} {states} {user_class}")
+}
+
+/// Context value shared between Menubar and its menus
+#[derive(Clone, Copy)]
+pub struct MenubarContextValue {
+    /// Value of the open menu ("" when all menus are closed)
+    pub value: Signal<String>,
+    pub set_value: Callback<String>,
+    /// Value of the trigger that currently holds the tab stop
+    pub tab_stop: RwSignal<Option<String>>,
+    pub root_ref: NodeRef<Div>,
+    /// Keyboard openers of every menu, keyed by menu value
+    openers: StoredValue<HashMap<String, Callback<Option<Movement>>>>,
+}
+
+impl MenubarContextValue {
+    /// Trigger elements in DOM order
+    fn triggers(&self) -> Vec<HtmlElement> {
+        self.root_ref
+            .get_untracked()
+            .map(|root| query_elements(&root, TRIGGER_SELECTOR))
+            .unwrap_or_default()
+    }
+
+    /// Trigger `movement` away from the trigger of menu `from` (wrapping around)
+    fn neighbour(&self, from: &str, movement: Movement) -> Option<HtmlElement> {
+        let triggers = self.triggers();
+        let current = triggers
+            .iter()
+            .position(|trigger| trigger.get_attribute("data-value").as_deref() == Some(from));
+        move_index(current, triggers.len(), movement, true)
+            .and_then(|index| triggers.get(index).cloned())
+    }
+
+    /// Open the menu `movement` away from `from`, focusing its first item
+    fn open_neighbour(&self, from: &str, movement: Movement) {
+        let Some(value) = self
+            .neighbour(from, movement)
+            .and_then(|trigger| trigger.get_attribute("data-value"))
+        else {
+            return;
+        };
+        let opener = self
+            .openers
+            .with_value(|openers| openers.get(&value).copied());
+        if let Some(opener) = opener {
+            opener.run(Some(Movement::First));
+        }
+    }
+}
+
+/// Context value shared between MenubarMenu and its trigger / content
+#[derive(Clone, Copy)]
+struct MenubarMenuContextValue {
+    value: Signal<String>,
+}
+
+/// Menubar component - Hook-first implementation
+///
+/// # Example
+/// ```rust,ignore
+/// view! {
+///     <Menubar>
+///         <MenubarMenu>
+///             <MenubarTrigger>"File"</MenubarTrigger>
+///             <MenubarContent>
+///                 <MenubarItem on_select=new_tab>"New Tab"</MenubarItem>
+///                 <MenubarSeparator />
+///                 <MenubarItem on_select=print>"Print…"</MenubarItem>
+///             </MenubarContent>
+///         </MenubarMenu>
+///         <MenubarMenu>
+///             <MenubarTrigger>"Edit"</MenubarTrigger>
+///             <MenubarContent>
+///                 <MenubarItem on_select=undo>"Undo"</MenubarItem>
+///             </MenubarContent>
+///         </MenubarMenu>
+///     </Menubar>
+/// }
+/// ```
+#[component]
+pub fn Menubar(
+    // Core state management (from our hook library)
+    #[prop(into, optional)] value: MaybeProp<String>,
+    #[prop(into, optional)] default_value: MaybeProp<String>,
+    #[prop(into, optional)] on_value_change: Option<Callback<String>>,
+
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: Children,
+) -> impl IntoView {
+    let state = use_controllable_state(value, default_value, on_value_change);
+    let root_ref = NodeRef::<Div>::new();
+
+    let context_value = MenubarContextValue {
+        value: state.value,
+        set_value: state.set_value,
+        tab_stop: RwSignal::new(None),
+        root_ref,
+        openers: StoredValue::new(HashMap::new()),
+    };
+
+    // Hand the tab stop to the first trigger once the bar is mounted
+    Effect::new(move |_| {
+        if root_ref.get().is_none() || context_value.tab_stop.get_untracked().is_some() {
+            return;
+        }
+        let first = context_value
+            .triggers()
+            .first()
+            .and_then(|trigger| trigger.get_attribute("data-value"));
+        context_value.tab_stop.set(first);
+    });
+
+    view! {
+        <Provider value=context_value>
+            <div
+                node_ref=root_ref
+                role="menubar"
+                aria-orientation="horizontal"
+                class=move || get_menubar_classes(class.get().unwrap_or_default())
+            >
+                {children()}
+            </div>
+        </Provider>
+    }
+}
+
+/// MenubarMenu - One top-level menu of the bar
+#[component]
+pub fn MenubarMenu(
+    /// Value identifying this menu in the Menubar value (generated when omitted)
+    #[prop(into, optional)]
+    value: MaybeProp<String>,
+
+    children: Children,
+) -> impl IntoView {
+    let menubar = expect_context::<MenubarContextValue>();
+    let generated = use_id_with_prefix("menubar-menu");
+    let menu_value = Signal::derive(move || value.get().unwrap_or_else(|| generated.get()));
+
+    let is_open = Signal::derive(move || menubar.value.get() == menu_value.get());
+    let menu = use_dropdown_behavior(
+        MaybeProp::from(is_open),
+        MaybeProp::default(),
+        Some(Callback::new(move |open: bool| {
+            let value = menu_value.get_untracked();
+            if open {
+                menubar.set_value.run(value);
+            } else if menubar.value.get_untracked() == value {
+                // Ignore stale closes from a menu another trigger already replaced
+                menubar.set_value.run(String::new());
+            }
+        })),
+        "menubar-menu",
+    );
+
+    let opener_key = menu_value.get_untracked();
+    menubar.openers.update_value(|openers| {
+        openers.insert(opener_key.clone(), menu.open_with_focus);
+    });
+    on_cleanup(move || {
+        menubar.openers.update_value(|openers| {
+            openers.remove(&opener_key);
+        });
+    });
+
+    view! {
+        <Provider value=DropdownMenuContextValue { menu }>
+            <Provider value=MenubarMenuContextValue { value: menu_value }>
+                {children()}
+            </Provider>
+        </Provider>
+    }
+}
+
+/// MenubarTrigger - Top-level button that opens its menu
+#[component]
+pub fn MenubarTrigger(
+    #[prop(into, optional)] disabled: MaybeProp<bool>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: Children,
+) -> impl IntoView {
+    let menubar = expect_context::<MenubarContextValue>();
+    let DropdownMenuContextValue { menu } = expect_context::<DropdownMenuContextValue>();
+    let MenubarMenuContextValue { value } = expect_context::<MenubarMenuContextValue>();
+    let is_disabled = Signal::derive(move || disabled.get().unwrap_or(false));
+
+    let tabindex = move || match menubar.tab_stop.get() {
+        Some(tab_stop) if tab_stop != value.get() => "-1",
+        _ => "0",
+    };
+
+    view! {
+        <button
+            node_ref=menu.trigger_ref
+            id=move || menu.trigger_id.get()
+            type="button"
+            role="menuitem"
+            tabindex=tabindex
+            disabled=move || is_disabled.get()
+            aria-haspopup="menu"
+            aria-expanded=move || if menu.open.get() { "true" } else { "false" }
+            aria-controls=move || menu.open.get().then(|| menu.content_id.get())
+            data-menubar-trigger=""
+            data-value=move || value.get()
+            data-state=move || menu.get_state_attr.get()
+            data-disabled=move || is_disabled.get().then_some("")
+            class=move || get_menubar_trigger_classes(class.get().unwrap_or_default())
+            on:focus=move |_| menubar.tab_stop.set(Some(value.get_untracked()))
+            on:pointerdown=move |event: PointerEvent| {
+                if is_disabled.get_untracked() || event.button() != 0 || event.ctrl_key() {
+                    return;
+                }
+                if !menu.open.get_untracked() {
+                    event.prevent_default();
+                }
+                menu.toggle.run(());
+            }
+            on:pointerenter=move |_| {
+                let open_value = menubar.value.get_untracked();
+                if is_disabled.get_untracked() || open_value.is_empty()
+                    || open_value == value.get_untracked()
+                {
+                    return;
+                }
+                menu.open_with_focus.run(None);
+            }
+            on:keydown=move |event: KeyboardEvent| {
+                if is_disabled.get_untracked() {
+                    return;
+                }
+                match event.key().as_str() {
+                    "Enter" | " " => {
+                        event.prevent_default();
+                        if menu.open.get_untracked() {
+                            menu.close.run(());
+                        } else {
+                            menu.open_with_focus.run(Some(Movement::First));
+                        }
+                    }
+                    "ArrowDown" => {
+                        event.prevent_default();
+                        menu.open_with_focus.run(Some(Movement::First));
+                    }
+                    "ArrowUp" => {
+                        event.prevent_default();
+                        menu.open_with_focus.run(Some(Movement::Last));
+                    }
+                    key @ ("ArrowRight" | "ArrowLeft" | "Home" | "End") => {
+                        event.prevent_default();
+                        let movement = match key {
+                            "ArrowRight" => Movement::Next,
+                            "ArrowLeft" => Movement::Previous,
+                            "Home" => Movement::First,
+                            _ => Movement::Last,
+                        };
+                        if let Some(trigger) = menubar.neighbour(&value.get_untracked(), movement) {
+                            let _ = trigger.focus();
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        >
+            {children()}
+        </button>
+    }
+}
+
+/// MenubarContent - The floating menu of one MenubarMenu (`role="menu"`)
+#[component]
+pub fn MenubarContent(
+    /// Preferred side of the trigger (default: bottom)
+    #[prop(into, optional)]
+    side: MaybeProp<Side>,
+    /// Preferred alignment against the trigger (default: start)
+    #[prop(into, optional)]
+    align: MaybeProp<Align>,
+    /// Distance in px from the trigger (default: 8)
+    #[prop(into, optional)]
+    side_offset: MaybeProp<f64>,
+    /// Offset in px from the aligned edge (default: -4)
+    #[prop(into, optional)]
+    align_offset: MaybeProp<f64>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: ChildrenFn,
+) -> impl IntoView {
+    let menubar = expect_context::<MenubarContextValue>();
+    let DropdownMenuContextValue { menu } = expect_context::<DropdownMenuContextValue>();
+    let MenubarMenuContextValue { value } = expect_context::<MenubarMenuContextValue>();
+
+    let options = Signal::derive(move || FloatingOptions {
+        side: side.get().unwrap_or_default(),
+        align: align.get().unwrap_or(Align::Start),
+        side_offset: side_offset.get().unwrap_or(8.0),
+        align_offset: align_offset.get().unwrap_or(-4.0),
+        ..Default::default()
+    });
+
+    let position = use_floating_position(
+        menu.open,
+        move || {
+            menu.trigger_ref
+                .get()
+                .map(|trigger| trigger.unchecked_into())
+        },
+        menu.content_ref,
+        options,
+    );
+
+    // Left / Right that no item (or submenu) handled move to the neighbouring menu
+    let on_unhandled_keydown = Callback::new(move |event: KeyboardEvent| {
+        let movement = match event.key().as_str() {
+            "ArrowRight" => Movement::Next,
+            "ArrowLeft" => Movement::Previous,
+            _ => return,
+        };
+        event.prevent_default();
+        menubar.open_neighbour(&value.get_untracked(), movement);
+    });
+
+    view! {
+        <MenuContentView
+            open=menu.open
+            get_state_attr=menu.get_state_attr
+            initial_focus=menu.initial_focus
+            content_id=menu.content_id
+            labelled_by=menu.trigger_id
+            content_ref=menu.content_ref
+            position=position
+            on_close_sub=None
+            on_unhandled_keydown=on_unhandled_keydown
+            class=class
+            children=children
+        />
+    }
+}
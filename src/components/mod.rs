@@ -1,9 +1,61 @@
+pub mod accordion;
+pub mod alert_dialog;
+pub mod avatar;
 pub mod checkbox;
+pub mod collapsible;
+pub mod combobox;
+pub mod context_menu;
+pub mod dialog;
+pub mod dropdown_menu;
+pub mod form;
+pub mod hover_card;
+pub mod label;
+pub mod menubar;
+pub mod navigation_menu;
+pub mod one_time_password_field;
+pub mod password_toggle_field;
+pub mod popover;
 pub mod progress;
+pub mod radio_group;
+pub mod scroll_area;
+pub mod select;
 pub mod separator;
+pub mod slider;
 pub mod switch;
+pub mod tabs;
+pub mod toast;
+pub mod toggle;
+pub mod toggle_group;
+pub mod toolbar;
+pub mod tooltip;
 
+pub use accordion::*;
+pub use alert_dialog::*;
+pub use avatar::*;
 pub use checkbox::*;
+pub use collapsible::*;
+pub use combobox::*;
+pub use context_menu::*;
+pub use dialog::*;
+pub use dropdown_menu::*;
+pub use form::*;
+pub use hover_card::*;
+pub use label::*;
+pub use menubar::*;
+pub use navigation_menu::*;
+pub use one_time_password_field::*;
+pub use password_toggle_field::*;
+pub use popover::*;
 pub use progress::*;
+pub use radio_group::*;
+pub use scroll_area::*;
+pub use select::*;
 pub use separator::*;
+pub use slider::*;
 pub use switch::*;
+pub use tabs::*;
+pub use toast::*;
+pub use toggle::*;
+pub use toggle_group::*;
+pub use toolbar::*;
+pub use tooltip::*;
@@ -0,0 +1,752 @@
+//! NavigationMenu Component - Site header navigation with flyout panels
+//!
+//! Features:
+//! - ✅ Controlled/uncontrolled open item (value, default_value, on_value_change)
+//! - ✅ Hover open/close delays, skipped while moving between triggers
+//! - ✅ data-motion="from-start|from-end|to-start|to-end" on content when switching items
+//! - ✅ Shared viewport sized through --navigation-menu-viewport-width / -height
+//! - ✅ Indicator that tracks the active trigger
+//! - ✅ Plain `<a href>` links: server-rendered and working before (or without) hydration
+//! - ✅ Left/Right between top-level items, Down into the open panel, Escape to close
+
+use crate::hooks::{
+    use_controllable_state, use_escape_key_when, use_id_with_prefix,
+    use_outside_click_multiple_when, use_presence, use_related_ids,
+};
+use crate::utils::{focus_first, move_index, query_elements, Movement, FOCUSABLE_SELECTOR};
+use leptos::context::Provider;
+use leptos::html::{Button, Div, Nav, Ul};
+use leptos::portal::Portal;
+use leptos::prelude::*;
+use std::time::Duration;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement, KeyboardEvent, PointerEvent};
+
+/// Selector for top-level triggers and links that take part in arrow-key navigation
+const FOCUSABLE_ITEM_SELECTOR: &str = "[data-navigation-menu-item]";
+
+/// Helper function to generate navigation menu list CSS classes
+fn get_navigation_menu_list_classes(user_class: String) -> String {
+    let base = "relative flex list-none items-center justify-center gap-1 p-1";
+
+    format!("{base} {user_class}")
+}
+
+/// Helper function to generate navigation menu trigger / top-level link CSS classes
+fn get_navigation_menu_trigger_classes(user_class: String) -> String {
+    let base = "group inline-flex select-none items-center justify-center gap-1 rounded px-3 py-2 text-sm font-medium transition-colors";
+    let focus = "focus:outline-none focus:ring-2 focus:ring-black focus:ring-offset-0";
+    let states = "data-[state=open]:bg-gray-100 data-[active]:underline";
+
+    format!("{base} {focus} {states} {user_class}")
+}
+
+/// Content slide animations per data-motion (keyframes in style/main.css)
+///
+/// Leaving content stays mounted until its animation ends and is laid over
+/// the entering content.
+pub(crate) const NAVIGATION_MENU_MOTION_CLASSES: &str = "data-[motion=from-start]:animate-enter-from-left data-[motion=from-end]:animate-enter-from-right data-[motion=to-start]:animate-exit-to-left data-[motion=to-end]:animate-exit-to-right data-[motion^=to-]:absolute";
+
+/// Helper function to generate navigation menu content CSS classes
+fn get_navigation_menu_content_classes(user_class: String) -> String {
+    let base = "left-0 top-0 w-full p-4";
+
+    format!("{base} {NAVIGATION_MENU_MOTION_CLASSES} {user_class}")
+}
+
+/// Helper function to generate navigation menu viewport CSS classes
+fn get_navigation_menu_viewport_classes(user_class: String) -> String {
+    let base = "relative mt-2 overflow-hidden rounded-md bg-white text-gray-900 shadow-lg transition-[width,height] duration-300 h-[var(--navigation-menu-viewport-height)] w-[var(--navigation-menu-viewport-width)]";
+    let animation =
+        "data-[state=open]:animate-in data-[state=open]:zoom-in-90 data-[state=closed]:hidden";
+
+    format!("{base} {animation} {user_class}")
+}
+
+/// `data-motion` for the content of `item` when the open item changes
+///
+/// `items` are the item values in DOM order. The entering content slides in
+/// from the side of the new item, the leaving content towards the opposite
+/// side. Opening from (or closing to) nothing has no motion.
+pub(crate) fn get_motion_attribute(
+    items: &[String],
+    previous: &str,
+    current: &str,
+    item: &str,
+) -> Option<&'static str> {
+    if previous.is_empty() || current.is_empty() || previous == current {
+        return None;
+    }
+    let previous_index = items.iter().position(|value| value == previous)?;
+    let current_index = items.iter().position(|value| value == current)?;
+    let moving_forward = current_index > previous_index;
+
+    if item == current {
+        Some(if moving_forward {
+            "from-end"
+        } else {
+            "from-start"
+        })
+    } else if item == previous {
+        Some(if moving_forward { "to-start" } else { "to-end" })
+    } else {
+        None
+    }
+}
+
+/// Context value shared between NavigationMenu and its parts
+#[derive(Clone, Copy)]
+pub struct NavigationMenuContextValue {
+    /// Value of the open item ("" when closed)
+    pub value: Signal<String>,
+    pub set_value: Callback<String>,
+    /// Value of the item that was open before the current one
+    pub previous_value: RwSignal<String>,
+    pub root_ref: NodeRef<Nav>,
+    pub list_ref: NodeRef<Ul>,
+    pub viewport_ref: NodeRef<Div>,
+    /// Whether a NavigationMenuViewport is rendered (content is moved into it)
+    pub has_viewport: RwSignal<bool>,
+    /// Pointer entered a trigger: open it after the delay (or at once when switching)
+    pub on_trigger_enter: Callback<String>,
+    /// Pointer left a trigger or the content: close after the close delay
+    pub on_pointer_leave: Callback<()>,
+    /// Pointer entered the open content: keep it open
+    pub on_content_enter: Callback<()>,
+}
+
+impl NavigationMenuContextValue {
+    /// Item values in DOM order
+    fn item_values(&self) -> Vec<String> {
+        self.list_ref
+            .get()
+            .map(|list| query_elements(&list, "[data-navigation-menu-trigger]"))
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|trigger| trigger.get_attribute("data-value"))
+            .collect()
+    }
+}
+
+/// Context value shared between NavigationMenuItem and its trigger / content
+#[derive(Clone, Copy)]
+struct NavigationMenuItemContextValue {
+    value: Signal<String>,
+    trigger_id: Signal<String>,
+    content_id: Signal<String>,
+    trigger_ref: NodeRef<Button>,
+}
+
+/// Marks links rendered inside a content panel (they are not top-level items)
+#[derive(Clone, Copy)]
+struct NavigationMenuInContentContextValue;
+
+/// NavigationMenu component - Hook-first implementation
+///
+/// # Example
+/// ```rust,ignore
+/// view! {
+///     <NavigationMenu>
+///         <NavigationMenuList>
+///             <NavigationMenuItem value="learn">
+///                 <NavigationMenuTrigger>"Learn"</NavigationMenuTrigger>
+///                 <NavigationMenuContent>
+///                     <NavigationMenuLink href="/docs">"Documentation"</NavigationMenuLink>
+///                 </NavigationMenuContent>
+///             </NavigationMenuItem>
+///             <NavigationMenuItem>
+///                 <NavigationMenuLink href="/blog">"Blog"</NavigationMenuLink>
+///             </NavigationMenuItem>
+///             <NavigationMenuIndicator />
+///         </NavigationMenuList>
+///         <NavigationMenuViewport />
+///     </NavigationMenu>
+/// }
+/// ```
+#[component]
+pub fn NavigationMenu(
+    // Core state management (from our hook library)
+    #[prop(into, optional)] value: MaybeProp<String>,
+    #[prop(into, optional)] default_value: MaybeProp<String>,
+    #[prop(into, optional)] on_value_change: Option<Callback<String>>,
+
+    /// Hover time in ms before a panel opens (default: 200)
+    #[prop(into, optional)]
+    delay_duration: MaybeProp<u64>,
+    /// Time in ms after the pointer leaves before the panel closes (default: 150)
+    #[prop(into, optional)]
+    close_delay_duration: MaybeProp<u64>,
+    /// Time in ms after closing during which hovering opens instantly (default: 300)
+    #[prop(into, optional)]
+    skip_delay_duration: MaybeProp<u64>,
+
+    /// Accessible name of the navigation landmark (default: "Main")
+    #[prop(into, optional)]
+    aria_label: MaybeProp<String>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: Children,
+) -> impl IntoView {
+    let state = use_controllable_state(value, default_value, on_value_change);
+    let root_ref = NodeRef::<Nav>::new();
+    let list_ref = NodeRef::<Ul>::new();
+    let viewport_ref = NodeRef::<Div>::new();
+    let previous_value = RwSignal::new(String::new());
+
+    let current = state.value;
+    let set_value = state.set_value;
+    let is_open = Signal::derive(move || !current.get().is_empty());
+
+    // Remember the previously open item for data-motion
+    Effect::new(move |previous: Option<String>| {
+        let value = current.get();
+        if let Some(previous) = previous {
+            if previous != value {
+                previous_value.set(previous);
+            }
+        }
+        value
+    });
+
+    // Delay timers
+    let open_timer = StoredValue::new(None::<TimeoutHandle>);
+    let close_timer = StoredValue::new(None::<TimeoutHandle>);
+    let skip_timer = StoredValue::new(None::<TimeoutHandle>);
+    let is_open_delayed = RwSignal::new(true);
+    let clear_timer = move |timer: StoredValue<Option<TimeoutHandle>>| {
+        if let Some(handle) = timer.try_update_value(Option::take).flatten() {
+            handle.clear();
+        }
+    };
+
+    // After everything closed, wait skip_delay before delaying opens again
+    Effect::new(move |_| {
+        if is_open.get() {
+            clear_timer(skip_timer);
+            is_open_delayed.set(false);
+        } else if !is_open_delayed.get_untracked() {
+            let handle = set_timeout_with_handle(
+                move || is_open_delayed.set(true),
+                Duration::from_millis(skip_delay_duration.get_untracked().unwrap_or(300)),
+            )
+            .ok();
+            skip_timer.set_value(handle);
+        }
+    });
+
+    let on_trigger_enter = Callback::new(move |item: String| {
+        clear_timer(open_timer);
+        clear_timer(close_timer);
+        if current.get_untracked() == item {
+            return;
+        }
+        if !is_open_delayed.get_untracked() {
+            set_value.run(item);
+            return;
+        }
+        let handle = set_timeout_with_handle(
+            move || set_value.run(item),
+            Duration::from_millis(delay_duration.get_untracked().unwrap_or(200)),
+        )
+        .ok();
+        open_timer.set_value(handle);
+    });
+    let on_pointer_leave = Callback::new(move |_: ()| {
+        clear_timer(open_timer);
+        clear_timer(close_timer);
+        let handle = set_timeout_with_handle(
+            move || set_value.run(String::new()),
+            Duration::from_millis(close_delay_duration.get_untracked().unwrap_or(150)),
+        )
+        .ok();
+        close_timer.set_value(handle);
+    });
+    let on_content_enter = Callback::new(move |_: ()| clear_timer(close_timer));
+
+    on_cleanup(move || {
+        clear_timer(open_timer);
+        clear_timer(close_timer);
+        clear_timer(skip_timer);
+    });
+
+    let context_value = NavigationMenuContextValue {
+        value: current,
+        set_value,
+        previous_value,
+        root_ref,
+        list_ref,
+        viewport_ref,
+        has_viewport: RwSignal::new(false),
+        on_trigger_enter,
+        on_pointer_leave,
+        on_content_enter,
+    };
+
+    // Dismissal: Escape returns focus to the open trigger
+    use_escape_key_when(
+        is_open,
+        Callback::new(move |_| {
+            let open_value = current.get_untracked();
+            set_value.run(String::new());
+            let trigger = list_ref.get_untracked().and_then(|list| {
+                query_elements(&list, "[data-navigation-menu-trigger]")
+                    .into_iter()
+                    .find(|trigger| trigger.get_attribute("data-value") == Some(open_value.clone()))
+            });
+            if let Some(trigger) = trigger {
+                let _ = trigger.focus();
+            }
+        }),
+    );
+    use_outside_click_multiple_when(
+        is_open,
+        vec![Box::new(root_ref)],
+        Callback::new(move |_| set_value.run(String::new())),
+    );
+
+    view! {
+        <Provider value=context_value>
+            <nav
+                node_ref=root_ref
+                aria-label=move || aria_label.get().unwrap_or_else(|| "Main".to_string())
+                data-orientation="horizontal"
+                class=move || format!("relative z-10 flex flex-col items-center {}", class.get().unwrap_or_default())
+            >
+                {children()}
+            </nav>
+        </Provider>
+    }
+}
+
+/// NavigationMenuList - The top-level list of items (`<ul>`)
+#[component]
+pub fn NavigationMenuList(
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: Children,
+) -> impl IntoView {
+    let context = expect_context::<NavigationMenuContextValue>();
+
+    // Left / Right / Home / End move between top-level triggers and links
+    let on_keydown = move |event: KeyboardEvent| {
+        let movement = match event.key().as_str() {
+            "ArrowRight" => Movement::Next,
+            "ArrowLeft" => Movement::Previous,
+            "Home" => Movement::First,
+            "End" => Movement::Last,
+            _ => return,
+        };
+        let Some(list) = context.list_ref.get_untracked() else {
+            return;
+        };
+        let items = query_elements(&list, FOCUSABLE_ITEM_SELECTOR);
+        let active = event
+            .target()
+            .and_then(|target| target.dyn_into::<HtmlElement>().ok());
+        let Some(current) = items.iter().position(|item| Some(item) == active.as_ref()) else {
+            return;
+        };
+        event.prevent_default();
+        if let Some(next) = move_index(Some(current), items.len(), movement, false)
+            .and_then(|index| items.get(index))
+        {
+            let _ = next.focus();
+        }
+    };
+
+    view! {
+        <ul
+            node_ref=context.list_ref
+            data-orientation="horizontal"
+            class=move || get_navigation_menu_list_classes(class.get().unwrap_or_default())
+            on:keydown=on_keydown
+        >
+            {children()}
+        </ul>
+    }
+}
+
+/// NavigationMenuItem - One top-level entry (a trigger with content, or a link)
+#[component]
+pub fn NavigationMenuItem(
+    /// Value identifying this item in the NavigationMenu value (generated when omitted)
+    #[prop(into, optional)]
+    value: MaybeProp<String>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: Children,
+) -> impl IntoView {
+    let generated = use_id_with_prefix("navigation-menu-item");
+    let ids = use_related_ids("navigation-menu");
+
+    let item_context = NavigationMenuItemContextValue {
+        value: Signal::derive(move || value.get().unwrap_or_else(|| generated.get())),
+        trigger_id: ids.trigger_id,
+        content_id: ids.content_id,
+        trigger_ref: NodeRef::new(),
+    };
+
+    view! {
+        <Provider value=item_context>
+            <li class=move || class.get()>{children()}</li>
+        </Provider>
+    }
+}
+
+/// NavigationMenuTrigger - Button that opens its item's content
+#[component]
+pub fn NavigationMenuTrigger(
+    #[prop(into, optional)] disabled: MaybeProp<bool>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: Children,
+) -> impl IntoView {
+    let context = expect_context::<NavigationMenuContextValue>();
+    let item = expect_context::<NavigationMenuItemContextValue>();
+    let is_disabled = Signal::derive(move || disabled.get().unwrap_or(false));
+    let is_open = Signal::derive(move || context.value.get() == item.value.get());
+
+    view! {
+        <button
+            node_ref=item.trigger_ref
+            id=move || item.trigger_id.get()
+            type="button"
+            disabled=move || is_disabled.get()
+            aria-expanded=move || if is_open.get() { "true" } else { "false" }
+            aria-controls=move || item.content_id.get()
+            data-navigation-menu-item=""
+            data-navigation-menu-trigger=""
+            data-value=move || item.value.get()
+            data-state=move || if is_open.get() { "open" } else { "closed" }
+            data-disabled=move || is_disabled.get().then_some("")
+            class=move || get_navigation_menu_trigger_classes(class.get().unwrap_or_default())
+            on:pointerenter=move |event: PointerEvent| {
+                if event.pointer_type() == "mouse" && !is_disabled.get_untracked() {
+                    context.on_trigger_enter.run(item.value.get_untracked());
+                }
+            }
+            on:pointerleave=move |event: PointerEvent| {
+                if event.pointer_type() == "mouse" {
+                    context.on_pointer_leave.run(());
+                }
+            }
+            on:click=move |_| {
+                let next = if is_open.get_untracked() {
+                    String::new()
+                } else {
+                    item.value.get_untracked()
+                };
+                context.set_value.run(next);
+            }
+            on:keydown=move |event: KeyboardEvent| {
+                if event.key() != "ArrowDown" || !is_open.get_untracked() {
+                    return;
+                }
+                let content = web_sys::window()
+                    .and_then(|window| window.document())
+                    .and_then(|document| document.get_element_by_id(&item.content_id.get_untracked()));
+                if let Some(content) = content {
+                    event.prevent_default();
+                    focus_first(&content, FOCUSABLE_SELECTOR);
+                }
+            }
+        >
+            {children()}
+            <svg
+                aria-hidden="true"
+                width="10"
+                height="10"
+                viewBox="0 0 15 15"
+                class="transition-transform duration-200 group-data-[state=open]:rotate-180"
+            >
+                <path d="M4 6H11L7.5 10.5L4 6Z" fill="currentColor" />
+            </svg>
+        </button>
+    }
+}
+
+/// NavigationMenuContent - Panel shown while its item is open
+///
+/// Rendered inside the NavigationMenuViewport when there is one, in place otherwise.
+#[component]
+pub fn NavigationMenuContent(
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: ChildrenFn,
+) -> impl IntoView {
+    let context = expect_context::<NavigationMenuContextValue>();
+    let item = expect_context::<NavigationMenuItemContextValue>();
+    let children = StoredValue::new(children);
+    let content_ref = NodeRef::<Div>::new();
+    let is_open = Signal::derive(move || context.value.get() == item.value.get());
+    // The leaving content stays until its data-motion exit animation ends
+    let presence = use_presence(is_open, content_ref);
+
+    let motion = Signal::derive(move || {
+        get_motion_attribute(
+            &context.item_values(),
+            &context.previous_value.get(),
+            &context.value.get(),
+            &item.value.get(),
+        )
+    });
+
+    let content = move || {
+        view! {
+            <Provider value=NavigationMenuInContentContextValue>
+                <div
+                    node_ref=content_ref
+                    id=move || item.content_id.get()
+                    aria-labelledby=move || item.trigger_id.get()
+                    data-state=move || if is_open.get() { "open" } else { "closed" }
+                    data-motion=move || motion.get()
+                    class=move || {
+                        let position = if context.has_viewport.get() { "" } else { "absolute mt-2 rounded-md bg-white shadow-lg" };
+                        format!("{position} {}", get_navigation_menu_content_classes(class.get().unwrap_or_default()))
+                    }
+                    on:pointerenter=move |_| context.on_content_enter.run(())
+                    on:pointerleave=move |event: PointerEvent| {
+                        if event.pointer_type() == "mouse" {
+                            context.on_pointer_leave.run(());
+                        }
+                    }
+                    on:animationend=move |ev| presence.on_animation_end.run(ev)
+                    // Switching again mid-exit drops data-motion and cancels the animation
+                    on:animationcancel=move |ev| presence.on_animation_cancel.run(ev)
+                >
+                    {children.read_value()()}
+                </div>
+            </Provider>
+        }
+    };
+
+    view! {
+        <Show when=move || presence.is_present.get()>
+            {move || {
+                let viewport: Option<Element> = context
+                    .viewport_ref
+                    .get_untracked()
+                    .filter(|_| context.has_viewport.get_untracked())
+                    .map(|viewport| viewport.unchecked_into());
+                match viewport {
+                    Some(viewport) => view! { <Portal mount=viewport>{content()}</Portal> }.into_any(),
+                    None => content().into_any(),
+                }
+            }}
+        </Show>
+    }
+}
+
+/// NavigationMenuLink - Plain `<a href>` that closes the menu when followed
+///
+/// Works as a normal link before hydration; `active` marks the current page.
+#[component]
+pub fn NavigationMenuLink(
+    #[prop(into)] href: String,
+    /// Marks the link for the current page (`aria-current="page"`, `data-active`)
+    #[prop(into, optional)]
+    active: MaybeProp<bool>,
+    #[prop(into, optional)] on_select: Option<Callback<()>>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: Children,
+) -> impl IntoView {
+    let context = expect_context::<NavigationMenuContextValue>();
+    let is_top_level = use_context::<NavigationMenuInContentContextValue>().is_none();
+    let is_active = Signal::derive(move || active.get().unwrap_or(false));
+
+    view! {
+        <a
+            href=href
+            aria-current=move || is_active.get().then_some("page")
+            data-active=move || is_active.get().then_some("")
+            data-navigation-menu-item=is_top_level.then_some("")
+            class=move || {
+                if is_top_level {
+                    get_navigation_menu_trigger_classes(class.get().unwrap_or_default())
+                } else {
+                    class.get().unwrap_or_default()
+                }
+            }
+            on:click=move |_| {
+                if let Some(callback) = on_select {
+                    callback.run(());
+                }
+                context.set_value.run(String::new());
+            }
+        >
+            {children()}
+        </a>
+    }
+}
+
+/// NavigationMenuIndicator - Marker under the active trigger (place inside the list)
+#[component]
+pub fn NavigationMenuIndicator(#[prop(into, optional)] class: MaybeProp<String>) -> impl IntoView {
+    let context = expect_context::<NavigationMenuContextValue>();
+    let (position, set_position) = signal(None::<(f64, f64)>);
+
+    Effect::new(move |_| {
+        let open_value = context.value.get();
+        if open_value.is_empty() {
+            set_position.set(None);
+            return;
+        }
+        let Some(list) = context.list_ref.get() else {
+            return;
+        };
+
+        let update = move || {
+            let trigger = query_elements(&list, "[data-navigation-menu-trigger]")
+                .into_iter()
+                .find(|trigger| {
+                    trigger.get_attribute("data-value").as_deref() == Some(open_value.as_str())
+                });
+            set_position.set(trigger.map(|trigger| {
+                (
+                    f64::from(trigger.offset_left()),
+                    f64::from(trigger.offset_width()),
+                )
+            }));
+        };
+        update();
+
+        let handle = window_event_listener(leptos::ev::resize, move |_| update());
+        on_cleanup(move || handle.remove());
+    });
+
+    view! {
+        <li
+            role="none"
+            aria-hidden="true"
+            data-state=move || if position.get().is_some() { "visible" } else { "hidden" }
+            style=move || match position.get() {
+                Some((left, width)) => format!(
+                    "position: absolute; bottom: 0px; left: 0px; width: {width}px; transform: translateX({left}px);"
+                ),
+                None => "position: absolute; bottom: 0px; left: 0px; visibility: hidden;".to_string(),
+            }
+            class=move || {
+                format!(
+                    "z-10 flex h-2 items-end justify-center overflow-hidden transition-all duration-200 data-[state=visible]:animate-in data-[state=visible]:fade-in-0 {}",
+                    class.get().unwrap_or_default(),
+                )
+            }
+        >
+            <div class="relative top-[60%] h-2 w-2 rotate-45 rounded-tl-sm bg-white shadow-md" />
+        </li>
+    }
+}
+
+/// NavigationMenuViewport - Shared container the open content is rendered into
+///
+/// Sizes itself to the active content through `--navigation-menu-viewport-width`
+/// and `--navigation-menu-viewport-height` so size changes can be transitioned.
+#[component]
+pub fn NavigationMenuViewport(#[prop(into, optional)] class: MaybeProp<String>) -> impl IntoView {
+    let context = expect_context::<NavigationMenuContextValue>();
+    let (size, set_size) = signal(None::<(i32, i32)>);
+    let is_open = Signal::derive(move || !context.value.get().is_empty());
+
+    context.has_viewport.set(true);
+    on_cleanup(move || context.has_viewport.set(false));
+
+    // Measure the active content once it has been moved in
+    Effect::new(move |_| {
+        if !is_open.get() {
+            return;
+        }
+        let Some(viewport) = context.viewport_ref.get() else {
+            return;
+        };
+        request_animation_frame(move || {
+            let content = viewport
+                .last_element_child()
+                .and_then(|content| content.dyn_into::<HtmlElement>().ok());
+            if let Some(content) = content {
+                set_size.set(Some((content.offset_width(), content.offset_height())));
+            }
+        });
+    });
+
+    view! {
+        <div class="absolute left-0 top-full flex w-full justify-center">
+            <div
+                node_ref=context.viewport_ref
+                data-state=move || if is_open.get() { "open" } else { "closed" }
+                style=move || match size.get() {
+                    Some((width, height)) => format!(
+                        "--navigation-menu-viewport-width: {width}px; --navigation-menu-viewport-height: {height}px;"
+                    ),
+                    None => String::new(),
+                }
+                class=move || get_navigation_menu_viewport_classes(class.get().unwrap_or_default())
+                on:pointerenter=move |_| context.on_content_enter.run(())
+                on:pointerleave=move |event: PointerEvent| {
+                    if event.pointer_type() == "mouse" {
+                        context.on_pointer_leave.run(());
+                    }
+                }
+            />
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn items() -> Vec<String> {
+        vec![
+            "learn".to_string(),
+            "overview".to_string(),
+            "docs".to_string(),
+        ]
+    }
+
+    #[test]
+    fn test_motion_moving_forward() {
+        let items = items();
+        assert_eq!(
+            get_motion_attribute(&items, "learn", "docs", "docs"),
+            Some("from-end")
+        );
+        assert_eq!(
+            get_motion_attribute(&items, "learn", "docs", "learn"),
+            Some("to-start")
+        );
+    }
+
+    #[test]
+    fn test_motion_moving_backward() {
+        let items = items();
+        assert_eq!(
+            get_motion_attribute(&items, "docs", "overview", "overview"),
+            Some("from-start")
+        );
+        assert_eq!(
+            get_motion_attribute(&items, "docs", "overview", "docs"),
+            Some("to-end")
+        );
+    }
+
+    #[test]
+    fn test_no_motion_when_opening_or_closing() {
+        let items = items();
+        assert_eq!(get_motion_attribute(&items, "", "learn", "learn"), None);
+        assert_eq!(get_motion_attribute(&items, "learn", "", "learn"), None);
+        assert_eq!(
+            get_motion_attribute(&items, "learn", "docs", "overview"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_every_motion_has_a_defined_animation() {
+        // Without a real animation the leaving content unmounts at once
+        let css = include_str!("../../style/main.css");
+        for motion in ["from-start", "from-end", "to-start", "to-end"] {
+            let prefix = format!("data-[motion={motion}]:animate-");
+            let animation = NAVIGATION_MENU_MOTION_CLASSES
+                .split_whitespace()
+                .find_map(|class| class.strip_prefix(prefix.as_str()))
+                .unwrap_or_else(|| panic!("no animation for {motion}"));
+            assert!(css.contains(&format!("--animate-{animation}:")));
+            assert!(css.contains(&format!("@keyframes {animation} ")));
+        }
+    }
+}
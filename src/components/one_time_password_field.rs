@@ -0,0 +1,479 @@
+//! OneTimePasswordField Component - Segmented code input that behaves as one field
+//!
+//! Features:
+//! - ✅ Controlled/uncontrolled value (value, default_value, on_value_change)
+//! - ✅ One character per cell; typing advances, Backspace goes back
+//! - ✅ Pasting (or SMS autofill via autocomplete="one-time-code") spreads the code across cells
+//! - ✅ Numeric, alphanumeric or unrestricted validation_type
+//! - ✅ Hidden input carrying the combined value for form submission
+//! - ✅ Optional auto-submit of the surrounding form once every cell is filled
+
+use crate::hooks::use_controllable_state;
+use leptos::context::Provider;
+use leptos::ev;
+use leptos::html::{Div, Input};
+use leptos::prelude::*;
+use std::collections::BTreeSet;
+use std::fmt::{Display, Formatter};
+use wasm_bindgen::JsCast;
+use web_sys::{HtmlFormElement, HtmlInputElement};
+
+/// Helper function to generate one-time password field CSS classes
+fn get_one_time_password_field_classes(user_class: String) -> String {
+    let base = "flex items-center gap-2";
+
+    format!("{base} {user_class}")
+}
+
+/// Helper function to generate one-time password cell CSS classes
+fn get_one_time_password_input_classes(user_class: String) -> String {
+    let base = "h-12 w-10 rounded-md border border-white/20 bg-transparent text-center text-lg font-medium text-white caret-transparent";
+    let focus = "focus:outline-none focus:ring-2 focus:ring-black";
+    let states = "data-[filled]:border-white/60";
+    let disabled = "disabled:cursor-not-allowed disabled:opacity-50";
+
+    format!("{base} {focus} {states} {disabled} {user_class}")
+}
+
+/// Which characters a cell accepts
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum OneTimePasswordValidationType {
+    /// Digits only
+    #[default]
+    Numeric,
+    /// ASCII letters and digits
+    Alphanumeric,
+    /// Anything except whitespace
+    None,
+}
+
+impl OneTimePasswordValidationType {
+    /// Whether a single character is accepted
+    pub fn is_valid_char(&self, c: char) -> bool {
+        match self {
+            Self::Numeric => c.is_ascii_digit(),
+            Self::Alphanumeric => c.is_ascii_alphanumeric(),
+            Self::None => !c.is_whitespace(),
+        }
+    }
+
+    /// `inputmode` for the on-screen keyboard
+    pub fn input_mode(&self) -> &'static str {
+        match self {
+            Self::Numeric => "numeric",
+            Self::Alphanumeric | Self::None => "text",
+        }
+    }
+}
+
+impl Display for OneTimePasswordValidationType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Numeric => "numeric",
+                Self::Alphanumeric => "alphanumeric",
+                Self::None => "none",
+            }
+        )
+    }
+}
+
+/// Keep only the characters the validation type accepts
+///
+/// Separators in pasted codes such as `123-456` or `123 456` are dropped.
+pub(crate) fn sanitize_one_time_password(
+    text: &str,
+    validation_type: OneTimePasswordValidationType,
+) -> String {
+    text.chars()
+        .filter(|c| validation_type.is_valid_char(*c))
+        .collect()
+}
+
+/// Write `text` into the cells starting at `index`, keeping `length` cells
+///
+/// Characters from `index` on are replaced; the value never has gaps, so
+/// `index` is clamped to the current value length.
+pub(crate) fn insert_one_time_password(
+    value: &str,
+    index: usize,
+    text: &str,
+    length: usize,
+) -> String {
+    let mut chars: Vec<char> = value.chars().collect();
+    let index = index.min(chars.len());
+    for (offset, c) in text.chars().enumerate() {
+        let position = index + offset;
+        if position >= length {
+            break;
+        }
+        if position < chars.len() {
+            chars[position] = c;
+        } else {
+            chars.push(c);
+        }
+    }
+    chars.truncate(length);
+    chars.into_iter().collect()
+}
+
+/// Remove the character at `index`; later characters move back one cell
+pub(crate) fn remove_one_time_password(value: &str, index: usize) -> String {
+    value
+        .chars()
+        .enumerate()
+        .filter(|(position, _)| *position != index)
+        .map(|(_, c)| c)
+        .collect()
+}
+
+/// Context value shared between OneTimePasswordField and its cells
+#[derive(Clone, Copy)]
+pub struct OneTimePasswordFieldContextValue {
+    pub value: Signal<String>,
+    /// Number of cells rendered
+    pub length: Signal<usize>,
+    pub validation_type: Signal<OneTimePasswordValidationType>,
+    pub disabled: Signal<bool>,
+    set_value: Callback<String>,
+    /// Indices of the mounted cells
+    cells: RwSignal<BTreeSet<usize>>,
+    root_ref: NodeRef<Div>,
+    hidden_ref: NodeRef<Input>,
+    r#type: Signal<String>,
+    autocomplete: Signal<String>,
+    auto_submit: Signal<bool>,
+    on_auto_submit: Option<Callback<String>>,
+}
+
+impl OneTimePasswordFieldContextValue {
+    /// Cell input at `index`
+    fn cell(&self, index: usize) -> Option<HtmlInputElement> {
+        self.root_ref
+            .get_untracked()?
+            .query_selector(&format!("input[data-index=\"{index}\"]"))
+            .ok()
+            .flatten()?
+            .dyn_into::<HtmlInputElement>()
+            .ok()
+    }
+
+    fn focus_cell(&self, index: usize) {
+        let last = self.length.get_untracked().saturating_sub(1);
+        if let Some(cell) = self.cell(index.min(last)) {
+            let _ = cell.focus();
+            cell.select();
+        }
+    }
+
+    /// Write typed or pasted text from cell `index` on and focus the next cell
+    fn fill(&self, index: usize, text: &str) {
+        let text = sanitize_one_time_password(text, self.validation_type.get_untracked());
+        if text.is_empty() {
+            return;
+        }
+        let length = self.length.get_untracked();
+        let count = text.chars().count();
+        // A whole code (paste or autofill) fills the field from the start
+        let start = if count >= length { 0 } else { index };
+        let value = insert_one_time_password(&self.value.get_untracked(), start, &text, length);
+        let next = value.chars().count().min(start + count);
+        self.commit(value);
+        self.focus_cell(next);
+    }
+
+    /// Update the value and submit once every cell is filled
+    fn commit(&self, value: String) {
+        let complete = value.chars().count() == self.length.get_untracked();
+        let changed = value != self.value.get_untracked();
+        self.set_value.run(value.clone());
+        if !(complete && changed) {
+            return;
+        }
+        if let Some(callback) = self.on_auto_submit {
+            callback.run(value.clone());
+        }
+        if self.auto_submit.get_untracked() {
+            self.submit(&value);
+        }
+    }
+
+    /// Submit the surrounding form with the combined value
+    fn submit(&self, value: &str) {
+        // The hidden input updates with the next render; the form needs it now
+        if let Some(hidden) = self.hidden_ref.get_untracked() {
+            hidden.set_value(value);
+        }
+        let form = self
+            .root_ref
+            .get_untracked()
+            .and_then(|root| root.closest("form").ok().flatten())
+            .and_then(|form| form.dyn_into::<HtmlFormElement>().ok());
+        if let Some(form) = form {
+            let _ = form.request_submit();
+        }
+    }
+}
+
+/// OneTimePasswordField component - Hook-first implementation
+///
+/// Uses our proven hook library for state management and ARIA compliance.
+/// Styled with Tailwind CSS 4 ONLY - no custom CSS allowed.
+///
+/// Render one OneTimePasswordFieldInput per character of the code, each with
+/// its position as `index`.
+///
+/// # Example
+/// ```rust,ignore
+/// view! {
+///     <OneTimePasswordField name="code" auto_submit=true>
+///         {(0..6).map(|index| view! { <OneTimePasswordFieldInput index=index /> }).collect_view()}
+///     </OneTimePasswordField>
+/// }
+/// ```
+#[component]
+pub fn OneTimePasswordField(
+    // Core state management (from our hook library)
+    #[prop(into, optional)] value: MaybeProp<String>,
+    #[prop(into, optional)] default_value: MaybeProp<String>,
+    #[prop(into, optional)] on_value_change: Option<Callback<String>>,
+
+    /// Characters the cells accept (default: numeric)
+    #[prop(into, optional)]
+    validation_type: MaybeProp<OneTimePasswordValidationType>,
+    /// Submit the surrounding form once every cell is filled
+    #[prop(into, optional)]
+    auto_submit: MaybeProp<bool>,
+    /// Called with the code once every cell is filled
+    #[prop(into, optional)]
+    on_auto_submit: Option<Callback<String>>,
+    /// `text` or `password` (default: text)
+    #[prop(into, optional)]
+    r#type: MaybeProp<String>,
+    /// Autocomplete hint of the first cell (default: one-time-code)
+    #[prop(into, optional)]
+    autocomplete: MaybeProp<String>,
+
+    // Form integration
+    #[prop(into, optional)] name: MaybeProp<String>,
+    #[prop(into, optional)] disabled: MaybeProp<bool>,
+
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: Children,
+) -> impl IntoView {
+    let state = use_controllable_state(value, default_value, on_value_change);
+    let cells = RwSignal::new(BTreeSet::new());
+    let hidden_ref = NodeRef::<Input>::new();
+
+    let context_value = OneTimePasswordFieldContextValue {
+        value: state.value,
+        length: Signal::derive(move || cells.with(BTreeSet::len)),
+        validation_type: Signal::derive(move || validation_type.get().unwrap_or_default()),
+        disabled: Signal::derive(move || disabled.get().unwrap_or(false)),
+        set_value: state.set_value,
+        cells,
+        root_ref: NodeRef::new(),
+        hidden_ref,
+        r#type: Signal::derive(move || r#type.get().unwrap_or_else(|| "text".to_string())),
+        autocomplete: Signal::derive(move || {
+            autocomplete
+                .get()
+                .unwrap_or_else(|| "one-time-code".to_string())
+        }),
+        auto_submit: Signal::derive(move || auto_submit.get().unwrap_or(false)),
+        on_auto_submit,
+    };
+
+    view! {
+        <Provider value=context_value>
+            <div
+                node_ref=context_value.root_ref
+                // Data attributes for Tailwind CSS 4 styling
+                data-disabled=move || context_value.disabled.get().then_some("")
+                // 🚨 TAILWIND CSS 4 ONLY - Row of cells
+                class=move || get_one_time_password_field_classes(class.get().unwrap_or_default())
+            >
+                {children()}
+                // Hidden input for form integration
+                <input
+                    node_ref=hidden_ref
+                    type="hidden"
+                    name=move || name.get()
+                    value=move || state.value.get()
+                    disabled=move || context_value.disabled.get()
+                />
+            </div>
+        </Provider>
+    }
+}
+
+/// OneTimePasswordFieldInput - One cell of the code
+#[component]
+pub fn OneTimePasswordFieldInput(
+    /// Position of the cell in the code (0 for the first)
+    index: usize,
+    #[prop(into, optional)] class: MaybeProp<String>,
+) -> impl IntoView {
+    let context = expect_context::<OneTimePasswordFieldContextValue>();
+
+    // Counted during render so the server HTML knows the length; a cell
+    // leaves with the owner of its effect (effects never run on the server)
+    if !context.cells.with_untracked(|cells| cells.contains(&index)) {
+        context.cells.update(|cells| {
+            cells.insert(index);
+        });
+    }
+    Effect::new(move |_| {
+        on_cleanup(move || {
+            context.cells.try_update(|cells| cells.remove(&index));
+        });
+    });
+
+    let char_at = move || {
+        context
+            .value
+            .with(|value| value.chars().nth(index).map(String::from))
+    };
+
+    // Typing, autofill and IME input all land here
+    let on_input = move |ev: ev::Event| {
+        let Some(input) = ev
+            .target()
+            .and_then(|target| target.dyn_into::<HtmlInputElement>().ok())
+        else {
+            return;
+        };
+        let text = input.value();
+        // Keep the cell in sync even when the input was rejected
+        input.set_value(&char_at().unwrap_or_default());
+        context.fill(index, &text);
+    };
+
+    let on_paste = move |ev: ev::ClipboardEvent| {
+        ev.prevent_default();
+        let Some(pasted) = ev
+            .clipboard_data()
+            .and_then(|data| data.get_data("text/plain").ok())
+        else {
+            return;
+        };
+        context.fill(index, &pasted);
+    };
+
+    let on_keydown = move |ev: ev::KeyboardEvent| {
+        let value = context.value.get_untracked();
+        let filled = value.chars().count();
+        match ev.key().as_str() {
+            "Backspace" => {
+                ev.prevent_default();
+                if index < filled {
+                    context.commit(remove_one_time_password(&value, index));
+                    context.focus_cell(index);
+                } else if index > 0 {
+                    context.commit(remove_one_time_password(&value, index - 1));
+                    context.focus_cell(index - 1);
+                }
+            }
+            "Delete" => {
+                ev.prevent_default();
+                context.commit(remove_one_time_password(&value, index));
+            }
+            "ArrowLeft" | "ArrowUp" => {
+                ev.prevent_default();
+                context.focus_cell(index.saturating_sub(1));
+            }
+            "ArrowRight" | "ArrowDown" => {
+                ev.prevent_default();
+                context.focus_cell((index + 1).min(filled));
+            }
+            "Home" => {
+                ev.prevent_default();
+                context.focus_cell(0);
+            }
+            "End" => {
+                ev.prevent_default();
+                context.focus_cell(filled);
+            }
+            "Enter" => {
+                ev.prevent_default();
+                if filled == context.length.get_untracked() {
+                    context.submit(&value);
+                }
+            }
+            _ => {}
+        }
+    };
+
+    // The value has no gaps: focusing past the first empty cell goes there
+    let on_focus = move |ev: ev::FocusEvent| {
+        let filled = context.value.with_untracked(|value| value.chars().count());
+        if index > filled {
+            context.focus_cell(filled);
+            return;
+        }
+        if let Some(input) = ev
+            .target()
+            .and_then(|target| target.dyn_into::<HtmlInputElement>().ok())
+        {
+            input.select();
+        }
+    };
+
+    view! {
+        <input
+            data-index=index
+            type=move || context.r#type.get()
+            inputmode=move || context.validation_type.get().input_mode()
+            autocomplete=move || if index == 0 { context.autocomplete.get() } else { "off".to_string() }
+            // Allows a whole autofilled code to reach the first cell
+            maxlength=move || if index == 0 { None } else { Some(1) }
+            // Attribute for the server render, property once typing starts
+            value=char_at
+            prop:value=move || char_at().unwrap_or_default()
+            disabled=move || context.disabled.get()
+            // ARIA attributes for accessibility
+            aria-label=move || format!("Character {} of {}", index + 1, context.length.get())
+            // Data attributes for Tailwind CSS 4 styling
+            data-filled=move || char_at().map(|_| "")
+            // 🚨 TAILWIND CSS 4 ONLY - Square cell
+            class=move || get_one_time_password_input_classes(class.get().unwrap_or_default())
+            on:input=on_input
+            on:paste=on_paste
+            on:keydown=on_keydown
+            on:focus=on_focus
+        />
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanitize_drops_separators_and_invalid_chars() {
+        let numeric = OneTimePasswordValidationType::Numeric;
+        assert_eq!(sanitize_one_time_password("123-456", numeric), "123456");
+        assert_eq!(sanitize_one_time_password("12a 3", numeric), "123");
+
+        let alphanumeric = OneTimePasswordValidationType::Alphanumeric;
+        assert_eq!(sanitize_one_time_password("AB1-2c", alphanumeric), "AB12c");
+    }
+
+    #[test]
+    fn test_insert_distributes_and_truncates() {
+        assert_eq!(insert_one_time_password("", 0, "123456", 6), "123456");
+        assert_eq!(insert_one_time_password("12", 2, "3", 6), "123");
+        assert_eq!(insert_one_time_password("123", 1, "9", 6), "193");
+        // No gaps: writing past the end appends
+        assert_eq!(insert_one_time_password("1", 4, "2", 6), "12");
+        assert_eq!(insert_one_time_password("1234", 3, "5678", 6), "123567");
+    }
+
+    #[test]
+    fn test_remove_shifts_later_chars() {
+        assert_eq!(remove_one_time_password("1234", 1), "134");
+        assert_eq!(remove_one_time_password("12", 5), "12");
+    }
+}
@@ -0,0 +1,379 @@
+//! PasswordToggleField Component - Password input with a show/hide toggle
+//!
+//! Features:
+//! - ✅ Controlled/uncontrolled visibility (visible, default_visible, on_visibility_change)
+//! - ✅ Toggling keeps focus and caret position in the input
+//! - ✅ Back to hidden when the surrounding form is submitted or reset
+//! - ✅ Toggle aria-label via show_label / hide_label, aria-controls the input
+//! - ✅ Icon part switching between eye and eye-off (or your own views)
+
+use crate::hooks::{use_id_with_prefix, use_switch_state, UseSwitchStateReturn};
+use leptos::context::Provider;
+use leptos::ev;
+use leptos::html::Input;
+use leptos::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::Node;
+
+/// Helper function to generate password input CSS classes
+fn get_password_toggle_field_input_classes(user_class: String) -> String {
+    let base = "h-9 w-full rounded-md border border-white/20 bg-transparent pl-3 pr-10 text-sm text-white placeholder:text-gray-400";
+    let focus = "focus:outline-none focus-visible:ring-2 focus-visible:ring-black";
+    let disabled = "disabled:cursor-not-allowed disabled:opacity-50";
+
+    format!("{base} {focus} {disabled} {user_class}")
+}
+
+/// Helper function to generate password toggle CSS classes
+fn get_password_toggle_field_toggle_classes(user_class: String) -> String {
+    let base = "absolute right-1 top-1/2 inline-flex h-7 w-7 -translate-y-1/2 items-center justify-center rounded text-gray-300 hover:text-white";
+    let focus = "focus:outline-none focus-visible:ring-2 focus-visible:ring-black";
+
+    format!("{base} {focus} {user_class}")
+}
+
+/// Input type for the current visibility
+fn get_password_input_type(visible: bool) -> &'static str {
+    if visible {
+        "text"
+    } else {
+        "password"
+    }
+}
+
+/// Selection to put back after toggling, only while the user was typing
+///
+/// A toggle reached by keyboard has moved focus out of the input, so
+/// there is no caret to keep.
+fn get_selection_to_restore(
+    typing: bool,
+    start: Option<u32>,
+    end: Option<u32>,
+) -> Option<(u32, u32)> {
+    typing.then_some(start.zip(end)).flatten()
+}
+
+/// Context value shared between PasswordToggleField and its parts
+#[derive(Clone, Copy)]
+pub struct PasswordToggleFieldContextValue {
+    /// Whether the password is shown as plain text
+    pub visible: Signal<bool>,
+    pub input_id: Signal<String>,
+    toggle: Callback<()>,
+    input_ref: NodeRef<Input>,
+}
+
+impl PasswordToggleFieldContextValue {
+    fn hide(&self) {
+        if self.visible.get_untracked() {
+            self.toggle.run(());
+        }
+    }
+}
+
+/// PasswordToggleField component - Hook-first implementation
+///
+/// Uses our proven hook library for state management and ARIA compliance.
+/// Styled with Tailwind CSS 4 ONLY - no custom CSS allowed.
+///
+/// # Example
+/// ```rust,ignore
+/// view! {
+///     <PasswordToggleField>
+///         <div class="relative">
+///             <PasswordToggleFieldInput name="password" autocomplete="new-password" />
+///             <PasswordToggleFieldToggle>
+///                 <PasswordToggleFieldIcon />
+///             </PasswordToggleFieldToggle>
+///         </div>
+///     </PasswordToggleField>
+/// }
+/// ```
+#[component]
+pub fn PasswordToggleField(
+    // Core state management (from our hook library)
+    #[prop(into, optional)] visible: MaybeProp<bool>,
+    #[prop(into, optional)] default_visible: MaybeProp<bool>,
+    #[prop(into, optional)] on_visibility_change: Option<Callback<bool>>,
+
+    /// Id of the input (default: generated)
+    #[prop(into, optional)]
+    id: MaybeProp<String>,
+
+    children: Children,
+) -> impl IntoView {
+    let UseSwitchStateReturn {
+        checked, toggle, ..
+    } = use_switch_state(visible, default_visible, on_visibility_change);
+    let generated_id = use_id_with_prefix("password");
+
+    let context_value = PasswordToggleFieldContextValue {
+        visible: checked,
+        input_id: Signal::derive(move || id.get().unwrap_or_else(|| generated_id.get())),
+        toggle,
+        input_ref: NodeRef::new(),
+    };
+
+    view! {
+        <Provider value=context_value>
+            {children()}
+        </Provider>
+    }
+}
+
+/// PasswordToggleFieldInput - The password input itself
+#[component]
+pub fn PasswordToggleFieldInput(
+    #[prop(into, optional)] name: MaybeProp<String>,
+    /// Autocomplete hint (default: current-password)
+    #[prop(into, optional)]
+    autocomplete: MaybeProp<String>,
+    #[prop(into, optional)] placeholder: MaybeProp<String>,
+    #[prop(into, optional)] required: MaybeProp<bool>,
+    #[prop(into, optional)] disabled: MaybeProp<bool>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+) -> impl IntoView {
+    let context = expect_context::<PasswordToggleFieldContextValue>();
+
+    // Never leave a password readable after the form is sent or cleared
+    let is_own_form = move |ev: &ev::Event| {
+        let form = context
+            .input_ref
+            .get_untracked()
+            .and_then(|input| input.form());
+        let target = ev
+            .target()
+            .and_then(|target| target.dyn_into::<Node>().ok());
+        matches!((form, target), (Some(form), Some(target)) if form.is_same_node(Some(&target)))
+    };
+    Effect::new(move |_| {
+        let submit = window_event_listener(ev::submit, move |ev| {
+            if is_own_form(&ev) {
+                context.hide();
+            }
+        });
+        let reset = window_event_listener(ev::reset, move |ev| {
+            if is_own_form(&ev) {
+                context.hide();
+            }
+        });
+        on_cleanup(move || {
+            submit.remove();
+            reset.remove();
+        });
+    });
+
+    view! {
+        <input
+            node_ref=context.input_ref
+            id=move || context.input_id.get()
+            type=move || get_password_input_type(context.visible.get())
+            name=move || name.get()
+            autocomplete=move || autocomplete.get().unwrap_or_else(|| "current-password".to_string())
+            placeholder=move || placeholder.get()
+            required=move || required.get().unwrap_or(false)
+            disabled=move || disabled.get().unwrap_or(false)
+            // Plain text mode must not autocorrect the password
+            autocapitalize="off"
+            spellcheck="false"
+            // Data attributes for Tailwind CSS 4 styling
+            data-state=move || if context.visible.get() { "visible" } else { "hidden" }
+            // 🚨 TAILWIND CSS 4 ONLY - Room on the right for the toggle
+            class=move || get_password_toggle_field_input_classes(class.get().unwrap_or_default())
+        />
+    }
+}
+
+/// PasswordToggleFieldToggle - Button showing or hiding the password
+///
+/// Clicking it while typing leaves focus and the caret in the input.
+#[component]
+pub fn PasswordToggleFieldToggle(
+    /// Accessible label while the password is hidden (default: "Show password")
+    #[prop(into, optional)]
+    show_label: MaybeProp<String>,
+    /// Accessible label while the password is shown (default: "Hide password")
+    #[prop(into, optional)]
+    hide_label: MaybeProp<String>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+    #[prop(optional)] children: Option<Children>,
+) -> impl IntoView {
+    let context = expect_context::<PasswordToggleFieldContextValue>();
+
+    let aria_label = move || {
+        if context.visible.get() {
+            hide_label
+                .get()
+                .unwrap_or_else(|| "Hide password".to_string())
+        } else {
+            show_label
+                .get()
+                .unwrap_or_else(|| "Show password".to_string())
+        }
+    };
+
+    let on_click = move |_: ev::MouseEvent| {
+        let input = context.input_ref.get_untracked();
+        // Mouse clicks keep focus in the input (see mousedown below)
+        let typing = document()
+            .active_element()
+            .zip(input.as_ref())
+            .is_some_and(|(active, input)| active.is_same_node(Some(input)));
+        let selection = input.as_ref().and_then(|input| {
+            get_selection_to_restore(
+                typing,
+                input.selection_start().ok().flatten(),
+                input.selection_end().ok().flatten(),
+            )
+        });
+
+        context.toggle.run(());
+
+        // Switching the type resets the selection in some browsers
+        if let (Some(input), Some((start, end))) = (input, selection) {
+            request_animation_frame(move || {
+                let _ = input.focus();
+                let _ = input.set_selection_range(start, end);
+            });
+        }
+    };
+
+    view! {
+        <button
+            type="button"
+            // ARIA attributes for accessibility
+            aria-label=aria_label
+            aria-controls=move || context.input_id.get()
+            // Data attributes for Tailwind CSS 4 styling
+            data-state=move || if context.visible.get() { "visible" } else { "hidden" }
+            // 🚨 TAILWIND CSS 4 ONLY - Icon button inside the input
+            class=move || get_password_toggle_field_toggle_classes(class.get().unwrap_or_default())
+            on:mousedown=|ev: ev::MouseEvent| ev.prevent_default()
+            on:click=on_click
+        >
+            {match children {
+                Some(children) => children().into_any(),
+                None => view! { <PasswordToggleFieldIcon /> }.into_any(),
+            }}
+        </button>
+    }
+}
+
+/// PasswordToggleFieldIcon - Eye while hidden, eye-off while shown
+#[component]
+pub fn PasswordToggleFieldIcon(
+    /// Shown while the password is visible (default: eye-off)
+    #[prop(into, optional)]
+    visible: Option<ViewFn>,
+    /// Shown while the password is hidden (default: eye)
+    #[prop(into, optional)]
+    hidden: Option<ViewFn>,
+) -> impl IntoView {
+    let context = expect_context::<PasswordToggleFieldContextValue>();
+
+    move || {
+        if context.visible.get() {
+            match &visible {
+                Some(view) => view.run(),
+                None => view! {
+                    <svg
+                        width="16"
+                        height="16"
+                        viewBox="0 0 24 24"
+                        fill="none"
+                        stroke="currentColor"
+                        stroke-width="2"
+                        stroke-linecap="round"
+                        stroke-linejoin="round"
+                        aria-hidden="true"
+                    >
+                        <path d="M17.94 17.94A10.07 10.07 0 0 1 12 20c-7 0-11-8-11-8a18.45 18.45 0 0 1 5.06-5.94" />
+                        <path d="M9.9 4.24A9.12 9.12 0 0 1 12 4c7 0 11 8 11 8a18.5 18.5 0 0 1-2.16 3.19" />
+                        <path d="M14.12 14.12a3 3 0 1 1-4.24-4.24" />
+                        <line x1="1" y1="1" x2="23" y2="23" />
+                    </svg>
+                }
+                .into_any(),
+            }
+        } else {
+            match &hidden {
+                Some(view) => view.run(),
+                None => view! {
+                    <svg
+                        width="16"
+                        height="16"
+                        viewBox="0 0 24 24"
+                        fill="none"
+                        stroke="currentColor"
+                        stroke-width="2"
+                        stroke-linecap="round"
+                        stroke-linejoin="round"
+                        aria-hidden="true"
+                    >
+                        <path d="M1 12s4-8 11-8 11 8 11 8-4 8-11 8-11-8-11-8z" />
+                        <circle cx="12" cy="12" r="3" />
+                    </svg>
+                }
+                .into_any(),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn context(visible: RwSignal<bool>) -> PasswordToggleFieldContextValue {
+        PasswordToggleFieldContextValue {
+            visible: visible.into(),
+            input_id: Signal::derive(|| "password-1".to_string()),
+            toggle: Callback::new(move |_| visible.update(|visible| *visible = !*visible)),
+            input_ref: NodeRef::new(),
+        }
+    }
+
+    #[test]
+    fn test_toggle_switches_the_input_type() {
+        let visible = RwSignal::new(false);
+        let context = context(visible);
+        assert_eq!(get_password_input_type(context.visible.get()), "password");
+
+        context.toggle.run(());
+        assert_eq!(get_password_input_type(context.visible.get()), "text");
+
+        context.toggle.run(());
+        assert_eq!(get_password_input_type(context.visible.get()), "password");
+    }
+
+    #[test]
+    fn test_hide_only_hides_a_visible_password() {
+        let visible = RwSignal::new(true);
+        let context = context(visible);
+
+        context.hide();
+        assert!(!visible.get());
+        // Submitting again must not reveal the password
+        context.hide();
+        assert!(!visible.get());
+    }
+
+    #[test]
+    fn test_caret_is_restored_while_typing() {
+        assert_eq!(
+            get_selection_to_restore(true, Some(3), Some(3)),
+            Some((3, 3))
+        );
+        assert_eq!(
+            get_selection_to_restore(true, Some(1), Some(4)),
+            Some((1, 4))
+        );
+    }
+
+    #[test]
+    fn test_caret_is_left_alone_without_a_selection_or_focus() {
+        assert_eq!(get_selection_to_restore(false, Some(3), Some(3)), None);
+        assert_eq!(get_selection_to_restore(true, None, Some(3)), None);
+        assert_eq!(get_selection_to_restore(true, None, None), None);
+    }
+}
@@ -0,0 +1,308 @@
+//! Popover Component - Non-modal floating panel anchored to a trigger
+//!
+//! Features:
+//! - ✅ Controlled/uncontrolled open state (open, default_open, on_open_change)
+//! - ✅ Positioned against the trigger or an explicit PopoverAnchor
+//! - ✅ Collision-aware side flipping exposed as data-side / data-align
+//! - ✅ Escape key and outside click dismissal (trigger + content count as inside)
+//! - ✅ Optional arrow pointing at the anchor
+
+use crate::hooks::{
+    get_arrow_style, use_controllable_state, use_escape_key_when, use_floating_position,
+    use_outside_click_multiple_when, use_related_ids, Align, FloatingOptions, Placement, Side,
+};
+use crate::utils::{focus_first, FOCUSABLE_SELECTOR};
+use leptos::context::Provider;
+use leptos::html::{Button, Div};
+use leptos::prelude::*;
+use wasm_bindgen::JsCast;
+
+/// Helper function to generate popover content CSS classes
+fn get_popover_content_classes(user_class: String) -> String {
+    let base = "z-50 w-72 rounded-md bg-white p-4 text-gray-900 shadow-lg";
+    let focus = "focus:outline-none";
+    let animation = "data-[state=open]:animate-in data-[state=open]:fade-in-0 data-[state=open]:zoom-in-95 data-[side=bottom]:slide-in-from-top-2 data-[side=top]:slide-in-from-bottom-2 data-[side=left]:slide-in-from-right-2 data-[side=right]:slide-in-from-left-2";
+
+    format!("{base} {focus} {animation} {user_class}")
+}
+
+/// Helper function to generate popover trigger CSS classes
+fn get_popover_trigger_classes(user_class: String) -> String {
+    let base = "inline-flex items-center justify-center rounded px-3 py-1.5 text-sm font-medium transition-colors";
+    let focus = "focus:outline-none focus:ring-2 focus:ring-black focus:ring-offset-0";
+
+    format!("{base} {focus} {user_class}")
+}
+
+/// Context value shared between Popover and its parts
+#[derive(Clone, Copy)]
+pub struct PopoverContextValue {
+    pub open: Signal<bool>,
+    pub set_open: Callback<bool>,
+    pub get_state_attr: Memo<&'static str>,
+    pub trigger_id: Signal<String>,
+    pub content_id: Signal<String>,
+    pub trigger_ref: NodeRef<Button>,
+    pub anchor_ref: NodeRef<Div>,
+    pub content_ref: NodeRef<Div>,
+}
+
+/// Context value shared between PopoverContent and PopoverArrow
+#[derive(Clone, Copy)]
+struct PopoverContentContextValue {
+    placement: Signal<Option<Placement>>,
+}
+
+/// Popover component - Hook-first implementation
+///
+/// # Example
+/// ```rust,ignore
+/// view! {
+///     <Popover>
+///         <PopoverTrigger>"Filters"</PopoverTrigger>
+///         <PopoverContent side=Side::Bottom side_offset=8.0>
+///             "Filter options"
+///             <PopoverClose aria_label="Close">"×"</PopoverClose>
+///             <PopoverArrow />
+///         </PopoverContent>
+///     </Popover>
+/// }
+/// ```
+#[component]
+pub fn Popover(
+    // Core state management (from our hook library)
+    #[prop(into, optional)] open: MaybeProp<bool>,
+    #[prop(into, optional)] default_open: MaybeProp<bool>,
+    #[prop(into, optional)] on_open_change: Option<Callback<bool>>,
+
+    children: Children,
+) -> impl IntoView {
+    let state = use_controllable_state(
+        open,
+        default_open.get().unwrap_or(false).into(),
+        on_open_change,
+    );
+    let ids = use_related_ids("popover");
+    let trigger_ref = NodeRef::<Button>::new();
+    let anchor_ref = NodeRef::<Div>::new();
+    let content_ref = NodeRef::<Div>::new();
+
+    let is_open = state.value;
+    let set_open = state.set_value;
+    let get_state_attr = Memo::new(move |_| if is_open.get() { "open" } else { "closed" });
+
+    // Dismissal: Escape returns focus to the trigger, outside clicks just close
+    use_escape_key_when(
+        is_open,
+        Callback::new(move |_| {
+            set_open.run(false);
+            if let Some(trigger) = trigger_ref.get_untracked() {
+                let _ = trigger.focus();
+            }
+        }),
+    );
+    use_outside_click_multiple_when(
+        is_open,
+        vec![Box::new(trigger_ref), Box::new(content_ref)],
+        Callback::new(move |_| set_open.run(false)),
+    );
+
+    let context_value = PopoverContextValue {
+        open: is_open,
+        set_open,
+        get_state_attr,
+        trigger_id: ids.trigger_id,
+        content_id: ids.content_id,
+        trigger_ref,
+        anchor_ref,
+        content_ref,
+    };
+
+    view! {
+        <Provider value=context_value>
+            {children()}
+        </Provider>
+    }
+}
+
+/// PopoverTrigger - Button that toggles the popover (and anchors it by default)
+#[component]
+pub fn PopoverTrigger(
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: Children,
+) -> impl IntoView {
+    let context = expect_context::<PopoverContextValue>();
+
+    view! {
+        <button
+            node_ref=context.trigger_ref
+            id=move || context.trigger_id.get()
+            type="button"
+            aria-haspopup="dialog"
+            aria-expanded=move || if context.open.get() { "true" } else { "false" }
+            aria-controls=move || context.content_id.get()
+            data-state=move || context.get_state_attr.get()
+            class=move || get_popover_trigger_classes(class.get().unwrap_or_default())
+            on:click=move |_| context.set_open.run(!context.open.get_untracked())
+        >
+            {children()}
+        </button>
+    }
+}
+
+/// PopoverAnchor - Positions the content against something other than the trigger
+#[component]
+pub fn PopoverAnchor(
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: Children,
+) -> impl IntoView {
+    let context = expect_context::<PopoverContextValue>();
+
+    view! {
+        <div node_ref=context.anchor_ref class=move || class.get()>
+            {children()}
+        </div>
+    }
+}
+
+/// PopoverContent - The floating panel
+#[component]
+pub fn PopoverContent(
+    /// Preferred side of the anchor (default: bottom)
+    #[prop(into, optional)]
+    side: MaybeProp<Side>,
+    /// Preferred alignment against the anchor (default: center)
+    #[prop(into, optional)]
+    align: MaybeProp<Align>,
+    /// Distance in px from the anchor
+    #[prop(into, optional)]
+    side_offset: MaybeProp<f64>,
+    /// Offset in px from the aligned edge
+    #[prop(into, optional)]
+    align_offset: MaybeProp<f64>,
+    /// Flip/shift to stay in the viewport (default: true)
+    #[prop(into, optional)]
+    avoid_collisions: MaybeProp<bool>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: ChildrenFn,
+) -> impl IntoView {
+    let context = expect_context::<PopoverContextValue>();
+    let children = StoredValue::new(children);
+
+    let options = Signal::derive(move || FloatingOptions {
+        side: side.get().unwrap_or_default(),
+        align: align.get().unwrap_or_default(),
+        side_offset: side_offset.get().unwrap_or(0.0),
+        align_offset: align_offset.get().unwrap_or(0.0),
+        avoid_collisions: avoid_collisions.get().unwrap_or(true),
+        ..Default::default()
+    });
+
+    let position = use_floating_position(
+        context.open,
+        move || {
+            context
+                .anchor_ref
+                .get()
+                .map(|anchor| anchor.unchecked_into())
+                .or_else(|| {
+                    context
+                        .trigger_ref
+                        .get()
+                        .map(|trigger| trigger.unchecked_into())
+                })
+        },
+        context.content_ref,
+        options,
+    );
+
+    // Move focus into the panel when it opens (non-modal: focus is not trapped)
+    Effect::new(move |_| {
+        if !context.open.get() {
+            return;
+        }
+        if let Some(content) = context.content_ref.get() {
+            if content.is_connected() {
+                focus_first(&content, FOCUSABLE_SELECTOR);
+            }
+        }
+    });
+
+    let content_context = PopoverContentContextValue {
+        placement: position.placement,
+    };
+
+    view! {
+        <Show when=move || context.open.get()>
+            <Provider value=content_context>
+                <div
+                    node_ref=context.content_ref
+                    id=move || context.content_id.get()
+                    role="dialog"
+                    tabindex="-1"
+                    data-state=move || context.get_state_attr.get()
+                    data-side=move || position.side.get().as_str()
+                    data-align=move || position.align.get().as_str()
+                    style=move || position.style.get()
+                    class=move || get_popover_content_classes(class.get().unwrap_or_default())
+                >
+                    {children.read_value()()}
+                </div>
+            </Provider>
+        </Show>
+    }
+}
+
+/// PopoverClose - Button that closes the popover
+#[component]
+pub fn PopoverClose(
+    #[prop(into, optional)] class: MaybeProp<String>,
+    #[prop(into, optional)] aria_label: MaybeProp<String>,
+    children: Children,
+) -> impl IntoView {
+    let context = expect_context::<PopoverContextValue>();
+
+    view! {
+        <button
+            type="button"
+            aria-label=move || aria_label.get()
+            class=move || class.get()
+            on:click=move |_| {
+                context.set_open.run(false);
+                if let Some(trigger) = context.trigger_ref.get_untracked() {
+                    let _ = trigger.focus();
+                }
+            }
+        >
+            {children()}
+        </button>
+    }
+}
+
+/// PopoverArrow - Small triangle on the content edge pointing at the anchor
+#[component]
+pub fn PopoverArrow(
+    #[prop(into, optional)] width: MaybeProp<f64>,
+    #[prop(into, optional)] height: MaybeProp<f64>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+) -> impl IntoView {
+    let content_context = expect_context::<PopoverContentContextValue>();
+    let width = Signal::derive(move || width.get().unwrap_or(10.0));
+    let height = Signal::derive(move || height.get().unwrap_or(5.0));
+
+    view! {
+        <svg
+            width=move || width.get()
+            height=move || height.get()
+            viewBox="0 0 30 10"
+            preserveAspectRatio="none"
+            aria-hidden="true"
+            style=move || {
+                get_arrow_style(content_context.placement.get(), width.get(), height.get())
+            }
+            class=move || format!("fill-white {}", class.get().unwrap_or_default())
+        >
+            <polygon points="0,0 30,0 15,10" />
+        </svg>
+    }
+}
@@ -0,0 +1,357 @@
+use crate::hooks::{
+    use_id_with_prefix, use_presence, use_radio_group_state, UseRadioGroupStateReturn,
+};
+use crate::utils::{get_roving_focus_movement, move_index, query_elements, JumpKeys, Movement};
+use leptos::context::Provider;
+use leptos::ev;
+use leptos::html::{Div, Span};
+use leptos::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::HtmlElement;
+
+/// Selector for the items that can receive focus
+const RADIO_ITEM_SELECTOR: &str = "[role='radio']:not([disabled])";
+
+/// Helper function to generate radio group CSS classes
+fn get_radio_group_classes(orientation: Option<&str>, user_class: String) -> String {
+    let base = match orientation {
+        Some("horizontal") => "flex flex-row gap-4",
+        _ => "flex flex-col gap-2",
+    };
+
+    format!("{base} {user_class}")
+}
+
+/// Helper function to generate radio item CSS classes
+fn get_radio_item_classes(user_class: String) -> String {
+    let base = "relative inline-flex h-6 w-6 shrink-0 items-center justify-center rounded-full border-0 bg-white transition-all duration-200 ease-in-out shadow-sm";
+    let focus = "focus:outline-none focus:ring-2 focus:ring-black focus:ring-offset-0";
+    let hover = "hover:bg-hover-purple data-[state=checked]:hover:bg-white";
+    let disabled = "data-[disabled]:opacity-50 data-[disabled]:cursor-not-allowed data-[disabled]:hover:bg-white";
+
+    format!("{base} {focus} {hover} {disabled} {user_class}")
+}
+
+/// Movement for an arrow key, honoring orientation and reading direction
+///
+/// Without an orientation every arrow key moves; `rtl` swaps Left and Right.
+pub fn get_radio_movement(key: &str, orientation: Option<&str>, rtl: bool) -> Option<Movement> {
+    get_roving_focus_movement(key, orientation, rtl, JumpKeys::None)
+}
+
+/// Context value shared between RadioGroup and its items
+#[derive(Clone, Copy)]
+pub struct RadioGroupContextValue {
+    pub state: UseRadioGroupStateReturn,
+    pub disabled: Signal<bool>,
+    pub required: Signal<bool>,
+    pub name: Signal<Option<String>>,
+    pub form: Signal<Option<String>>,
+    /// Item that last had focus (the tab stop when nothing is selected)
+    focused: RwSignal<Option<String>>,
+    /// Enabled item values in render order
+    items: RwSignal<Vec<String>>,
+}
+
+impl RadioGroupContextValue {
+    /// The single item reachable with Tab: the selected one, else the last
+    /// focused one, else the first enabled item
+    fn tab_stop(&self) -> Option<String> {
+        let value = self.state.value.get();
+        self.items.with(|items| {
+            if items.contains(&value) {
+                return Some(value);
+            }
+            self.focused
+                .get()
+                .filter(|focused| items.contains(focused))
+                .or_else(|| items.first().cloned())
+        })
+    }
+}
+
+/// Context value shared between RadioGroupItem and RadioGroupIndicator
+#[derive(Clone, Debug)]
+pub struct RadioGroupItemContextValue {
+    pub checked: Signal<bool>,
+    pub disabled: Signal<bool>,
+}
+
+/// RadioGroup component - Hook-first implementation
+///
+/// Uses our proven hook library for state management and ARIA compliance.
+/// Styled with Tailwind CSS 4 ONLY - no custom CSS allowed.
+///
+/// # Example
+/// ```rust,ignore
+/// view! {
+///     <RadioGroup name="density" default_value="comfortable">
+///         <div class="flex items-center gap-2">
+///             <RadioGroupItem value="compact" id="density-compact">
+///                 <RadioGroupIndicator />
+///             </RadioGroupItem>
+///             <label for="density-compact">"Compact"</label>
+///         </div>
+///     </RadioGroup>
+/// }
+/// ```
+#[component]
+pub fn RadioGroup(
+    // Core state management (from our hook library)
+    #[prop(into, optional)] value: MaybeProp<String>,
+    #[prop(into, optional)] default_value: MaybeProp<String>,
+    #[prop(into, optional)] on_value_change: Option<Callback<String>>,
+
+    // Form integration
+    #[prop(into, optional)] disabled: MaybeProp<bool>,
+    #[prop(into, optional)] required: MaybeProp<bool>,
+    #[prop(into, optional)] name: MaybeProp<String>,
+    #[prop(into, optional)] form: MaybeProp<String>,
+
+    // Keyboard navigation
+    /// "horizontal" or "vertical" limits the arrow keys (default: all arrows)
+    #[prop(into, optional)]
+    orientation: MaybeProp<String>,
+    /// Reading direction ("ltr" or "rtl") for Left/Right
+    #[prop(into, optional)]
+    dir: MaybeProp<String>,
+    /// Wrap around at the ends (default: true)
+    #[prop(into, optional)]
+    loop_focus: MaybeProp<bool>,
+
+    // Accessibility & DOM
+    #[prop(into, optional)] aria_label: MaybeProp<String>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+
+    children: Children,
+) -> impl IntoView {
+    // Phase 0: Compose hooks - no manual state management!
+    let radio_state = use_radio_group_state(value, default_value, on_value_change);
+    let root_ref = NodeRef::<Div>::new();
+
+    // Pre-compute common values to reduce complexity
+    let is_disabled = Signal::derive(move || disabled.get().unwrap_or(false));
+    let is_required = Signal::derive(move || required.get().unwrap_or(false));
+
+    // Context for child components
+    let context_value = RadioGroupContextValue {
+        state: radio_state,
+        disabled: is_disabled,
+        required: is_required,
+        name: Signal::derive(move || name.get()),
+        form: Signal::derive(move || form.get()),
+        focused: RwSignal::new(None),
+        items: RwSignal::new(Vec::new()),
+    };
+
+    // Arrow keys move focus and select the focused item
+    let on_keydown = move |ev: ev::KeyboardEvent| {
+        let rtl = dir.get_untracked().as_deref() == Some("rtl");
+        let Some(movement) =
+            get_radio_movement(&ev.key(), orientation.get_untracked().as_deref(), rtl)
+        else {
+            return;
+        };
+        let Some(root) = root_ref.get_untracked() else {
+            return;
+        };
+        let items: Vec<HtmlElement> = query_elements(&root, RADIO_ITEM_SELECTOR)
+            .into_iter()
+            .filter(|item| {
+                item.closest("[role='radiogroup']")
+                    .ok()
+                    .flatten()
+                    .is_some_and(|group| group == **root)
+            })
+            .collect();
+        let current = ev
+            .target()
+            .and_then(|target| target.dyn_into::<HtmlElement>().ok())
+            .and_then(|target| items.iter().position(|item| *item == target));
+
+        ev.prevent_default();
+        let looping = loop_focus.get_untracked().unwrap_or(true);
+        if let Some(next) =
+            move_index(current, items.len(), movement, looping).and_then(|index| items.get(index))
+        {
+            let _ = next.focus();
+            if let Some(value) = next.get_attribute("value") {
+                radio_state.select.run(value);
+            }
+        }
+    };
+
+    view! {
+        <Provider value=context_value>
+            <div
+                node_ref=root_ref
+                role="radiogroup"
+                aria-label=move || aria_label.get()
+                aria-required=move || if is_required.get() { Some("true") } else { None }
+                aria-orientation=move || orientation.get()
+                dir=move || dir.get()
+                data-disabled=move || if is_disabled.get() { Some("") } else { None }
+                // 🚨 TAILWIND CSS 4 ONLY - Layout follows the orientation
+                class=move || get_radio_group_classes(orientation.get().as_deref(), class.get().unwrap_or_default())
+                on:keydown=on_keydown
+            >
+                {children()}
+            </div>
+        </Provider>
+    }
+}
+
+/// RadioGroupItem - One radio button with its hidden native radio input
+#[component]
+pub fn RadioGroupItem(
+    #[prop(into)] value: String,
+    #[prop(into, optional)] disabled: MaybeProp<bool>,
+
+    // Accessibility & DOM
+    #[prop(into, optional)] id: MaybeProp<String>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+
+    children: ChildrenFn,
+) -> impl IntoView {
+    let context = expect_context::<RadioGroupContextValue>();
+    let radio_state = context.state;
+    let item_value = StoredValue::new(value);
+    let generated_id = use_id_with_prefix("radio");
+
+    // Pre-compute common values to reduce complexity
+    let is_disabled =
+        Signal::derive(move || context.disabled.get() || disabled.get().unwrap_or(false));
+    let is_checked =
+        Signal::derive(move || item_value.with_value(|value| radio_state.is_checked(value)));
+    let final_id = Signal::derive(move || id.get().unwrap_or_else(|| generated_id.get()));
+    let is_tab_stop = Signal::derive(move || {
+        item_value.with_value(|value| context.tab_stop().as_ref() == Some(value))
+    });
+
+    // Register enabled items for the roving tab stop (during render too, so
+    // the server-rendered HTML already has one tab stop)
+    if !is_disabled.get_untracked() {
+        context
+            .items
+            .update(|items| items.push(item_value.get_value()));
+    }
+    Effect::new(move |_| {
+        let enabled = !is_disabled.get();
+        let value = item_value.get_value();
+        context.items.update(|items| {
+            let registered = items.contains(&value);
+            if enabled && !registered {
+                items.push(value);
+            } else if !enabled && registered {
+                items.retain(|item| *item != value);
+            }
+        });
+    });
+    on_cleanup(move || {
+        context
+            .items
+            .try_update(|items| item_value.with_value(|value| items.retain(|item| item != value)));
+    });
+
+    let select = move || {
+        if !is_disabled.get_untracked() {
+            radio_state.select.run(item_value.get_value());
+        }
+    };
+
+    // Context for the indicator
+    let item_context = RadioGroupItemContextValue {
+        checked: is_checked,
+        disabled: is_disabled,
+    };
+
+    view! {
+        <Provider value=item_context>
+            <div class="relative inline-flex">
+                // Hidden input for form integration (bubble input pattern)
+                <input
+                    type="radio"
+                    aria-hidden="true"
+                    name=move || context.name.get()
+                    value=move || item_value.get_value()
+                    form=move || context.form.get()
+                    checked=move || is_checked.get()
+                    required=move || context.required.get()
+                    disabled=move || is_disabled.get()
+                    // 🚨 TAILWIND CSS 4 ONLY - Hidden input styling
+                    class="absolute opacity-0 pointer-events-none"
+                    style="position: absolute; opacity: 0; pointer-events: none; margin: 0; width: 1px; height: 1px;"
+                    tabindex="-1"
+                />
+
+                <button
+                    id=move || final_id.get()
+                    type="button"
+                    role="radio"
+                    value=move || item_value.get_value()
+                    // ARIA attributes from our hook
+                    aria-checked=move || item_value.with_value(|value| radio_state.get_aria_checked(value))
+                    aria-disabled=move || if is_disabled.get() { Some("true") } else { None }
+                    // Roving tabindex: one tab stop for the whole group
+                    tabindex=move || if is_tab_stop.get() { "0" } else { "-1" }
+                    // Data attributes for Tailwind CSS 4 styling
+                    data-state=move || item_value.with_value(|value| radio_state.get_state_attr(value))
+                    data-disabled=move || if is_disabled.get() { Some("") } else { None }
+                    disabled=move || is_disabled.get()
+                    // 🚨 TAILWIND CSS 4 ONLY - Professional data-driven styling
+                    class=move || get_radio_item_classes(class.get().unwrap_or_default())
+                    on:focus=move |_| context.focused.set(Some(item_value.get_value()))
+                    on:click=move |_| select()
+                    on:keydown=move |ev: ev::KeyboardEvent| {
+                        // Radios are checked with Space only (Enter submits forms)
+                        if ev.key() == "Enter" {
+                            ev.prevent_default();
+                        }
+                    }
+                >
+                    {children()}
+                </button>
+            </div>
+        </Provider>
+    }
+}
+
+/// RadioGroupIndicator - Shows when its item is checked
+#[component]
+pub fn RadioGroupIndicator(
+    #[prop(into, optional)] force_mount: MaybeProp<bool>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+    #[prop(optional)] children: Option<ChildrenFn>,
+) -> impl IntoView {
+    let context = expect_context::<RadioGroupItemContextValue>();
+    let indicator_ref = NodeRef::<Span>::new();
+
+    // Stay mounted while the animate-out classes run
+    let presence = use_presence(context.checked, indicator_ref);
+    let is_present =
+        Signal::derive(move || force_mount.get().unwrap_or(false) || presence.is_present.get());
+
+    view! {
+        <Show when=move || is_present.get()>
+            <span
+                node_ref=indicator_ref
+                data-state=move || if context.checked.get() { "checked" } else { "unchecked" }
+                data-disabled=move || if context.disabled.get() { Some("") } else { None }
+                // 🚨 TAILWIND CSS 4 ONLY - Professional indicator styling
+                class=move || {
+                    let base = "absolute inset-0 flex items-center justify-center pointer-events-none";
+                    let animation = "data-[state=checked]:animate-in data-[state=checked]:fade-in-0 data-[state=checked]:zoom-in-95 data-[state=unchecked]:animate-out data-[state=unchecked]:fade-out-0 data-[state=unchecked]:zoom-out-95";
+                    let user = class.get().unwrap_or_default();
+                    format!("{base} {animation} {user}")
+                }
+                on:animationend=move |ev| presence.on_animation_end.run(ev)
+            >
+                {match children.clone() {
+                    Some(children) => children().into_any(),
+                    // Default dot, matching the checkbox's black-on-white look
+                    None => view! { <span class="block h-2.5 w-2.5 rounded-full bg-black" /> }.into_any(),
+                }}
+            </span>
+        </Show>
+    }
+}
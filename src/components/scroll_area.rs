@@ -0,0 +1,777 @@
+//! ScrollArea Component - Native scrolling with custom, styled scrollbars
+//!
+//! Features:
+//! - ✅ Native scrolling: wheel, touch, keyboard and screen readers work as usual
+//! - ✅ type="hover" (default), "auto", "always" or "scroll" scrollbar visibility
+//! - ✅ scroll_hide_delay before "hover" and "scroll" scrollbars hide
+//! - ✅ Draggable thumbs, track clicks page by one viewport
+//! - ✅ Vertical and horizontal scrollbars with a corner, RTL aware
+//! - ✅ Thumbs follow content size changes (ResizeObserver on viewport and content)
+//! - ✅ Scrollbars are decorative (aria-hidden); the viewport stays the scroll container
+
+use crate::hooks::use_presence;
+use leptos::context::Provider;
+use leptos::ev;
+use leptos::html::Div;
+use leptos::prelude::*;
+use std::time::Duration;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+use web_sys::{HtmlElement, Node, ResizeObserver};
+
+/// Smallest thumb length in pixels, so it stays grabbable on long content
+const MIN_THUMB_SIZE: f64 = 18.0;
+
+/// Helper function to generate scroll area root CSS classes
+fn get_scroll_area_classes(user_class: String) -> String {
+    let base = "relative overflow-hidden";
+
+    format!("{base} {user_class}")
+}
+
+/// Helper function to generate scroll area viewport CSS classes
+fn get_scroll_area_viewport_classes(user_class: String) -> String {
+    let base = "h-full w-full rounded-[inherit]";
+    // Native scrollbars are replaced by ScrollAreaScrollbar
+    let native = "[scrollbar-width:none] [&::-webkit-scrollbar]:hidden";
+    let focus = "focus:outline-none focus-visible:ring-2 focus-visible:ring-black";
+
+    format!("{base} {native} {focus} {user_class}")
+}
+
+/// Helper function to generate scrollbar CSS classes
+fn get_scroll_area_scrollbar_classes(user_class: String) -> String {
+    let base = "absolute flex touch-none select-none p-px transition-colors duration-150";
+    let orientation = "data-[orientation=vertical]:w-2.5 data-[orientation=horizontal]:h-2.5 data-[orientation=horizontal]:flex-col";
+    let states = "bg-white/5 hover:bg-white/10 data-[state=visible]:animate-fade-in data-[state=hidden]:animate-fade-out data-[state=hidden]:opacity-0 data-[state=hidden]:pointer-events-none";
+
+    format!("{base} {orientation} {states} {user_class}")
+}
+
+/// Helper function to generate scrollbar thumb CSS classes
+fn get_scroll_area_thumb_classes(user_class: String) -> String {
+    let base =
+        "relative flex-1 rounded-full bg-white/40 transition-colors duration-150 hover:bg-white/60";
+
+    format!("{base} {user_class}")
+}
+
+/// Helper function to generate scroll area corner CSS classes
+fn get_scroll_area_corner_classes(user_class: String) -> String {
+    let base = "absolute bottom-0 bg-white/5";
+
+    format!("{base} {user_class}")
+}
+
+/// Thumb length on a `track` px long track, for `viewport` px of `content` px
+///
+/// Never shorter than 18px (or the track), zero when there is nothing to show.
+pub(crate) fn get_thumb_size(viewport: f64, content: f64, track: f64) -> f64 {
+    if content <= 0.0 || track <= 0.0 {
+        return 0.0;
+    }
+    let ratio = (viewport / content).min(1.0);
+    (track * ratio).max(MIN_THUMB_SIZE).min(track)
+}
+
+/// Thumb offset from the start of the track for a scroll position
+pub(crate) fn get_thumb_offset(
+    scroll: f64,
+    viewport: f64,
+    content: f64,
+    track: f64,
+    thumb: f64,
+) -> f64 {
+    let max_scroll = content - viewport;
+    if max_scroll <= 0.0 {
+        return 0.0;
+    }
+    (scroll / max_scroll).clamp(0.0, 1.0) * (track - thumb).max(0.0)
+}
+
+/// Scroll position that puts the thumb at `thumb_offset` (inverse of get_thumb_offset)
+pub(crate) fn get_scroll_from_thumb_offset(
+    thumb_offset: f64,
+    viewport: f64,
+    content: f64,
+    track: f64,
+    thumb: f64,
+) -> f64 {
+    let max_offset = track - thumb;
+    if max_offset <= 0.0 {
+        return 0.0;
+    }
+    (thumb_offset / max_offset).clamp(0.0, 1.0) * (content - viewport).max(0.0)
+}
+
+/// Scroll position after a track click at `pointer` (offset along the track)
+///
+/// Pages one viewport towards the pointer; clicks on the thumb keep the position.
+pub(crate) fn get_paged_scroll(
+    scroll: f64,
+    viewport: f64,
+    content: f64,
+    thumb_offset: f64,
+    thumb: f64,
+    pointer: f64,
+) -> f64 {
+    let next = if pointer < thumb_offset {
+        scroll - viewport
+    } else if pointer > thumb_offset + thumb {
+        scroll + viewport
+    } else {
+        scroll
+    };
+    next.clamp(0.0, (content - viewport).max(0.0))
+}
+
+/// Viewport scroll position and sizes, in pixels
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ScrollAreaMetrics {
+    pub scroll_top: f64,
+    /// Negative while scrolled in RTL, like the DOM property
+    pub scroll_left: f64,
+    pub viewport_width: f64,
+    pub viewport_height: f64,
+    pub content_width: f64,
+    pub content_height: f64,
+}
+
+impl ScrollAreaMetrics {
+    /// Viewport and content length along one axis
+    fn sizes(&self, horizontal: bool) -> (f64, f64) {
+        if horizontal {
+            (self.viewport_width, self.content_width)
+        } else {
+            (self.viewport_height, self.content_height)
+        }
+    }
+
+    /// Scroll position measured from the left/top edge, RTL included
+    fn scroll_offset(&self, horizontal: bool, rtl: bool) -> f64 {
+        match (horizontal, rtl) {
+            (false, _) => self.scroll_top,
+            (true, false) => self.scroll_left,
+            (true, true) => self.content_width - self.viewport_width + self.scroll_left,
+        }
+    }
+}
+
+/// Context value shared between ScrollArea and its parts
+#[derive(Clone, Copy)]
+pub struct ScrollAreaContextValue {
+    /// "hover", "auto", "always" or "scroll"
+    pub scroll_type: Signal<String>,
+    pub rtl: Signal<bool>,
+    /// Latest viewport measurements
+    pub metrics: Signal<ScrollAreaMetrics>,
+    viewport_ref: NodeRef<Div>,
+    set_metrics: RwSignal<ScrollAreaMetrics>,
+    /// Pointer over the area (stays true for scroll_hide_delay after leaving)
+    hovered: RwSignal<bool>,
+    /// Viewport scrolled within the last scroll_hide_delay
+    scrolling: RwSignal<bool>,
+    hide_delay: Signal<u32>,
+    hover_timer: StoredValue<Option<TimeoutHandle>>,
+    scroll_timer: StoredValue<Option<TimeoutHandle>>,
+    /// Mounted scrollbars per axis: the viewport only scrolls on those
+    scrollbar_x_enabled: RwSignal<bool>,
+    scrollbar_y_enabled: RwSignal<bool>,
+    /// Thickness of the visible scrollbars, for the corner and the other scrollbar
+    scrollbar_x_height: RwSignal<f64>,
+    scrollbar_y_width: RwSignal<f64>,
+}
+
+impl ScrollAreaContextValue {
+    /// Read the viewport's scroll position and sizes
+    fn measure(&self) {
+        let Some(viewport) = self.viewport_ref.get_untracked() else {
+            return;
+        };
+        let next = ScrollAreaMetrics {
+            scroll_top: viewport.scroll_top().into(),
+            scroll_left: viewport.scroll_left().into(),
+            viewport_width: viewport.client_width().into(),
+            viewport_height: viewport.client_height().into(),
+            content_width: viewport.scroll_width().into(),
+            content_height: viewport.scroll_height().into(),
+        };
+        if self.set_metrics.get_untracked() != next {
+            self.set_metrics.set(next);
+        }
+    }
+
+    /// Scroll the viewport to a position measured from the left/top edge
+    fn scroll_to(&self, horizontal: bool, offset: f64) {
+        let Some(viewport) = self.viewport_ref.get_untracked() else {
+            return;
+        };
+        if !horizontal {
+            viewport.set_scroll_top(offset.round() as i32);
+            return;
+        }
+        let offset = if self.rtl.get_untracked() {
+            let metrics = self.set_metrics.get_untracked();
+            offset - (metrics.content_width - metrics.viewport_width)
+        } else {
+            offset
+        };
+        viewport.set_scroll_left(offset.round() as i32);
+    }
+
+    fn restart_timer(
+        &self,
+        timer: StoredValue<Option<TimeoutHandle>>,
+        on_timeout: impl FnOnce() + 'static,
+    ) {
+        clear_timer(timer);
+        let delay = Duration::from_millis(self.hide_delay.get_untracked().into());
+        timer.set_value(set_timeout_with_handle(on_timeout, delay).ok());
+    }
+
+    fn on_viewport_scroll(&self) {
+        self.measure();
+        if !self.scrolling.get_untracked() {
+            self.scrolling.set(true);
+        }
+        let scrolling = self.scrolling;
+        self.restart_timer(self.scroll_timer, move || scrolling.set(false));
+    }
+}
+
+fn clear_timer(timer: StoredValue<Option<TimeoutHandle>>) {
+    if let Some(handle) = timer.try_update_value(Option::take).flatten() {
+        handle.clear();
+    }
+}
+
+/// ScrollArea component - Hook-first implementation
+///
+/// Uses our proven hook library for state management and ARIA compliance.
+/// Styled with Tailwind CSS 4 ONLY - no custom CSS allowed.
+///
+/// The viewport keeps native scrolling and hides the OS scrollbars; each
+/// ScrollAreaScrollbar enables scrolling on its axis.
+///
+/// # Example
+/// ```rust,ignore
+/// view! {
+///     <ScrollArea class="h-72 w-48">
+///         <ScrollAreaViewport>
+///             <p>"Long content"</p>
+///         </ScrollAreaViewport>
+///         <ScrollAreaScrollbar orientation="vertical">
+///             <ScrollAreaThumb />
+///         </ScrollAreaScrollbar>
+///         <ScrollAreaCorner />
+///     </ScrollArea>
+/// }
+/// ```
+#[component]
+pub fn ScrollArea(
+    /// Scrollbar visibility: "hover" (default), "auto", "always" or "scroll"
+    #[prop(into, optional)]
+    r#type: MaybeProp<String>,
+    /// Milliseconds before "hover" and "scroll" scrollbars hide (default: 600)
+    #[prop(into, optional)]
+    scroll_hide_delay: MaybeProp<u32>,
+    /// Reading direction ("ltr" or "rtl")
+    #[prop(into, optional)]
+    dir: MaybeProp<String>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+
+    children: Children,
+) -> impl IntoView {
+    let metrics = RwSignal::new(ScrollAreaMetrics::default());
+
+    // Context for child components
+    let context_value = ScrollAreaContextValue {
+        scroll_type: Signal::derive(move || r#type.get().unwrap_or_else(|| "hover".to_string())),
+        rtl: Signal::derive(move || dir.get().as_deref() == Some("rtl")),
+        metrics: metrics.into(),
+        viewport_ref: NodeRef::new(),
+        set_metrics: metrics,
+        hovered: RwSignal::new(false),
+        scrolling: RwSignal::new(false),
+        hide_delay: Signal::derive(move || scroll_hide_delay.get().unwrap_or(600)),
+        hover_timer: StoredValue::new(None),
+        scroll_timer: StoredValue::new(None),
+        scrollbar_x_enabled: RwSignal::new(false),
+        scrollbar_y_enabled: RwSignal::new(false),
+        scrollbar_x_height: RwSignal::new(0.0),
+        scrollbar_y_width: RwSignal::new(0.0),
+    };
+
+    on_cleanup(move || {
+        clear_timer(context_value.hover_timer);
+        clear_timer(context_value.scroll_timer);
+    });
+
+    let on_pointerenter = move |_: ev::PointerEvent| {
+        clear_timer(context_value.hover_timer);
+        // Content may have changed since the last scroll
+        context_value.measure();
+        context_value.hovered.set(true);
+    };
+    let on_pointerleave = move |_: ev::PointerEvent| {
+        let hovered = context_value.hovered;
+        context_value.restart_timer(context_value.hover_timer, move || hovered.set(false));
+    };
+
+    view! {
+        <Provider value=context_value>
+            <div
+                dir=move || dir.get()
+                // 🚨 TAILWIND CSS 4 ONLY - Clips the viewport, positions the scrollbars
+                class=move || get_scroll_area_classes(class.get().unwrap_or_default())
+                on:pointerenter=on_pointerenter
+                on:pointerleave=on_pointerleave
+            >
+                {children()}
+            </div>
+        </Provider>
+    }
+}
+
+/// ScrollAreaViewport - The natively scrolling container
+#[component]
+pub fn ScrollAreaViewport(
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: Children,
+) -> impl IntoView {
+    let context = expect_context::<ScrollAreaContextValue>();
+
+    let content_ref = NodeRef::<Div>::new();
+
+    // Measure once laid out, and whenever the viewport or its content resizes
+    // (window resize, async data, expanding rows...)
+    Effect::new(move |_| {
+        let (Some(viewport), Some(content)) = (context.viewport_ref.get(), content_ref.get())
+        else {
+            return;
+        };
+        request_animation_frame(move || context.measure());
+        let on_resize = Closure::<dyn Fn()>::new(move || context.measure());
+        let Ok(observer) = ResizeObserver::new(on_resize.as_ref().unchecked_ref()) else {
+            return;
+        };
+        observer.observe(&viewport);
+        observer.observe(&content);
+
+        let observing = StoredValue::new_local(Some((observer, on_resize)));
+        on_cleanup(move || {
+            if let Some((observer, _)) = observing.try_update_value(Option::take).flatten() {
+                observer.disconnect();
+            }
+        });
+    });
+
+    // Only axes with a scrollbar scroll
+    let overflow = |enabled: bool| if enabled { "scroll" } else { "hidden" };
+
+    view! {
+        <div
+            node_ref=context.viewport_ref
+            data-scroll-area-viewport=""
+            style=move || {
+                format!(
+                    "overflow-x: {}; overflow-y: {}",
+                    overflow(context.scrollbar_x_enabled.get()),
+                    overflow(context.scrollbar_y_enabled.get()),
+                )
+            }
+            // 🚨 TAILWIND CSS 4 ONLY - Native scrollbars hidden, scrolling kept
+            class=move || get_scroll_area_viewport_classes(class.get().unwrap_or_default())
+            on:scroll=move |_| context.on_viewport_scroll()
+        >
+            // Table layout lets horizontal content size the scroll width
+            <div node_ref=content_ref style="min-width: 100%; display: table">
+                {children()}
+            </div>
+        </div>
+    }
+}
+
+/// Scrollbar track length and leading padding, in pixels
+#[derive(Clone, Copy, Default, PartialEq)]
+struct ScrollbarTrack {
+    length: f64,
+    padding_start: f64,
+}
+
+impl ScrollbarTrack {
+    fn measure(scrollbar: &HtmlElement, horizontal: bool) -> Self {
+        let style = window().get_computed_style(scrollbar).ok().flatten();
+        let padding = |name: &str| {
+            style
+                .as_ref()
+                .and_then(|style| style.get_property_value(name).ok())
+                .and_then(|value| value.trim_end_matches("px").parse::<f64>().ok())
+                .unwrap_or(0.0)
+        };
+        let (size, start, end) = if horizontal {
+            (scrollbar.client_width(), "padding-left", "padding-right")
+        } else {
+            (scrollbar.client_height(), "padding-top", "padding-bottom")
+        };
+        let padding_start = padding(start);
+        Self {
+            length: f64::from(size) - padding_start - padding(end),
+            padding_start,
+        }
+    }
+}
+
+/// Context value shared between a scrollbar and its thumb
+#[derive(Clone, Copy)]
+pub struct ScrollAreaScrollbarContextValue {
+    pub horizontal: bool,
+    /// Thumb length along the track, in pixels
+    pub thumb_size: Signal<f64>,
+    /// Thumb offset from the start of the track, in pixels
+    pub thumb_offset: Signal<f64>,
+    thumb_ref: NodeRef<Div>,
+}
+
+/// ScrollAreaScrollbar - Custom scrollbar for one axis
+///
+/// Mounting it enables scrolling on its axis. Hidden from assistive
+/// technology: the viewport itself remains the accessible scroll container.
+#[component]
+pub fn ScrollAreaScrollbar(
+    /// "vertical" (default) or "horizontal"
+    #[prop(into, optional)]
+    orientation: MaybeProp<String>,
+    /// Keep the scrollbar in the DOM while hidden
+    #[prop(into, optional)]
+    force_mount: MaybeProp<bool>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+
+    children: ChildrenFn,
+) -> impl IntoView {
+    let context = expect_context::<ScrollAreaContextValue>();
+    let scrollbar_ref = NodeRef::<Div>::new();
+    let thumb_ref = NodeRef::<Div>::new();
+    let track = RwSignal::new(ScrollbarTrack::default());
+    // Pointer offset inside the thumb while dragging
+    let drag_offset = StoredValue::new(None::<f64>);
+
+    let horizontal = orientation.get_untracked().as_deref() == Some("horizontal");
+    let (enabled, thickness, other_thickness) = if horizontal {
+        (
+            context.scrollbar_x_enabled,
+            context.scrollbar_x_height,
+            context.scrollbar_y_width,
+        )
+    } else {
+        (
+            context.scrollbar_y_enabled,
+            context.scrollbar_y_width,
+            context.scrollbar_x_height,
+        )
+    };
+
+    // Enable native scrolling on this axis (during render, so SSR output matches)
+    enabled.set(true);
+    on_cleanup(move || {
+        enabled.try_set(false);
+        thickness.try_set(0.0);
+    });
+
+    let overflows = Signal::derive(move || {
+        let (viewport, content) = context.metrics.get().sizes(horizontal);
+        content > viewport
+    });
+    let is_visible = Signal::derive(move || match context.scroll_type.get().as_str() {
+        "always" => true,
+        "auto" => overflows.get(),
+        "scroll" => overflows.get() && context.scrolling.get(),
+        _ => overflows.get() && (context.hovered.get() || context.scrolling.get()),
+    });
+    let presence = use_presence(is_visible, scrollbar_ref);
+    let is_rendered =
+        Signal::derive(move || force_mount.get().unwrap_or(false) || presence.is_present.get());
+
+    // Re-measure the track when the viewport or the other scrollbar changes.
+    // Only write changes: both scrollbars track each other's thickness.
+    Effect::new(move |_| {
+        context.metrics.track();
+        other_thickness.track();
+        let bar = scrollbar_ref.get().filter(|_| presence.is_present.get());
+        let (next_track, next_thickness) = match bar {
+            Some(bar) => (
+                ScrollbarTrack::measure(&bar, horizontal),
+                if horizontal {
+                    bar.offset_height().into()
+                } else {
+                    bar.offset_width().into()
+                },
+            ),
+            None => (track.get_untracked(), 0.0),
+        };
+        if track.get_untracked() != next_track {
+            track.set(next_track);
+        }
+        if thickness.get_untracked() != next_thickness {
+            thickness.set(next_thickness);
+        }
+    });
+
+    let thumb_size = Signal::derive(move || {
+        let (viewport, content) = context.metrics.get().sizes(horizontal);
+        get_thumb_size(viewport, content, track.get().length)
+    });
+    let thumb_offset = Signal::derive(move || {
+        let metrics = context.metrics.get();
+        let (viewport, content) = metrics.sizes(horizontal);
+        let scroll = metrics.scroll_offset(horizontal, context.rtl.get());
+        get_thumb_offset(
+            scroll,
+            viewport,
+            content,
+            track.get().length,
+            thumb_size.get(),
+        )
+    });
+
+    // Pointer position along the track
+    let pointer_on_track = move |ev: &ev::PointerEvent| -> Option<f64> {
+        let rect = scrollbar_ref.get_untracked()?.get_bounding_client_rect();
+        let position = if horizontal {
+            f64::from(ev.client_x()) - rect.left()
+        } else {
+            f64::from(ev.client_y()) - rect.top()
+        };
+        Some(position - track.get_untracked().padding_start)
+    };
+
+    let on_pointerdown = move |ev: ev::PointerEvent| {
+        if ev.button() != 0 {
+            return;
+        }
+        let (Some(bar), Some(pointer)) = (scrollbar_ref.get_untracked(), pointer_on_track(&ev))
+        else {
+            return;
+        };
+        // Keep focus where it is and avoid selecting text
+        ev.prevent_default();
+        let on_thumb = thumb_ref.get_untracked().is_some_and(|thumb| {
+            let target = ev
+                .target()
+                .and_then(|target| target.dyn_into::<Node>().ok());
+            thumb.contains(target.as_ref())
+        });
+        if on_thumb {
+            let _ = bar.set_pointer_capture(ev.pointer_id());
+            drag_offset.set_value(Some(pointer - thumb_offset.get_untracked()));
+            return;
+        }
+        let metrics = context.metrics.get_untracked();
+        let (viewport, content) = metrics.sizes(horizontal);
+        let scroll = get_paged_scroll(
+            metrics.scroll_offset(horizontal, context.rtl.get_untracked()),
+            viewport,
+            content,
+            thumb_offset.get_untracked(),
+            thumb_size.get_untracked(),
+            pointer,
+        );
+        context.scroll_to(horizontal, scroll);
+    };
+
+    let on_pointermove = move |ev: ev::PointerEvent| {
+        let (Some(offset), Some(pointer)) = (drag_offset.get_value(), pointer_on_track(&ev)) else {
+            return;
+        };
+        let (viewport, content) = context.metrics.get_untracked().sizes(horizontal);
+        let scroll = get_scroll_from_thumb_offset(
+            pointer - offset,
+            viewport,
+            content,
+            track.get_untracked().length,
+            thumb_size.get_untracked(),
+        );
+        context.scroll_to(horizontal, scroll);
+    };
+
+    let on_pointerup = move |ev: ev::PointerEvent| {
+        if drag_offset
+            .try_update_value(Option::take)
+            .flatten()
+            .is_some()
+        {
+            if let Some(bar) = scrollbar_ref.get_untracked() {
+                let _ = bar.release_pointer_capture(ev.pointer_id());
+            }
+        }
+    };
+
+    // The scrollbar sits outside the viewport: forward the wheel to it
+    let on_wheel = move |ev: ev::WheelEvent| {
+        let Some(viewport) = context.viewport_ref.get_untracked() else {
+            return;
+        };
+        let before = (viewport.scroll_left(), viewport.scroll_top());
+        viewport.scroll_by_with_x_and_y(ev.delta_x(), ev.delta_y());
+        if (viewport.scroll_left(), viewport.scroll_top()) != before {
+            ev.prevent_default();
+        }
+    };
+
+    let position_style = move || {
+        let rtl = context.rtl.get();
+        let other = other_thickness.get();
+        match (horizontal, rtl) {
+            (false, false) => format!("top: 0; right: 0; bottom: {other}px"),
+            (false, true) => format!("top: 0; left: 0; bottom: {other}px"),
+            (true, false) => format!("bottom: 0; left: 0; right: {other}px"),
+            (true, true) => format!("bottom: 0; right: 0; left: {other}px"),
+        }
+    };
+
+    let scrollbar_context = ScrollAreaScrollbarContextValue {
+        horizontal,
+        thumb_size,
+        thumb_offset,
+        thumb_ref,
+    };
+
+    view! {
+        <Provider value=scrollbar_context>
+            <Show when=move || is_rendered.get()>
+                <div
+                    node_ref=scrollbar_ref
+                    aria-hidden="true"
+                    style=position_style
+                    // Data attributes for Tailwind CSS 4 styling
+                    data-orientation=if horizontal { "horizontal" } else { "vertical" }
+                    data-state=move || if is_visible.get() { "visible" } else { "hidden" }
+                    // 🚨 TAILWIND CSS 4 ONLY - Track styling that works on dark panels
+                    class=move || get_scroll_area_scrollbar_classes(class.get().unwrap_or_default())
+                    on:pointerdown=on_pointerdown
+                    on:pointermove=on_pointermove
+                    on:pointerup=on_pointerup
+                    on:pointercancel=on_pointerup
+                    on:wheel=on_wheel
+                    on:animationend=move |ev| presence.on_animation_end.run(ev)
+                >
+                    {children()}
+                </div>
+            </Show>
+        </Provider>
+    }
+}
+
+/// ScrollAreaThumb - Draggable handle sized to the visible portion
+#[component]
+pub fn ScrollAreaThumb(#[prop(into, optional)] class: MaybeProp<String>) -> impl IntoView {
+    let scrollbar = expect_context::<ScrollAreaScrollbarContextValue>();
+
+    let style = move || {
+        let size = scrollbar.thumb_size.get();
+        let offset = scrollbar.thumb_offset.get();
+        if scrollbar.horizontal {
+            format!("width: {size}px; transform: translate3d({offset}px, 0, 0)")
+        } else {
+            format!("height: {size}px; transform: translate3d(0, {offset}px, 0)")
+        }
+    };
+
+    view! {
+        <div
+            node_ref=scrollbar.thumb_ref
+            data-scroll-area-thumb=""
+            style=style
+            // 🚨 TAILWIND CSS 4 ONLY - Rounded thumb, brighter on hover
+            class=move || get_scroll_area_thumb_classes(class.get().unwrap_or_default())
+        />
+    }
+}
+
+/// ScrollAreaCorner - Fills the gap where both visible scrollbars meet
+#[component]
+pub fn ScrollAreaCorner(#[prop(into, optional)] class: MaybeProp<String>) -> impl IntoView {
+    let context = expect_context::<ScrollAreaContextValue>();
+
+    let size = move || {
+        let width = context.scrollbar_y_width.get();
+        let height = context.scrollbar_x_height.get();
+        (width > 0.0 && height > 0.0).then_some((width, height))
+    };
+
+    view! {
+        <Show when=move || size().is_some()>
+            <div
+                aria-hidden="true"
+                style=move || {
+                    let (width, height) = size().unwrap_or_default();
+                    let side = if context.rtl.get() { "left" } else { "right" };
+                    format!("{side}: 0; width: {width}px; height: {height}px")
+                }
+                // 🚨 TAILWIND CSS 4 ONLY - Matches the scrollbar track
+                class=move || get_scroll_area_corner_classes(class.get().unwrap_or_default())
+            />
+        </Show>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_thumb_size_follows_visible_ratio() {
+        assert_eq!(get_thumb_size(100.0, 400.0, 200.0), 50.0);
+        assert_eq!(get_thumb_size(100.0, 100.0, 200.0), 200.0);
+        // Never smaller than the minimum, never larger than the track
+        assert_eq!(get_thumb_size(10.0, 10_000.0, 200.0), 18.0);
+        assert_eq!(get_thumb_size(10.0, 10_000.0, 12.0), 12.0);
+        assert_eq!(get_thumb_size(100.0, 0.0, 200.0), 0.0);
+    }
+
+    #[test]
+    fn test_thumb_offset_round_trips() {
+        // 300px of scrollable content on a 150px free track
+        assert_eq!(get_thumb_offset(0.0, 100.0, 400.0, 200.0, 50.0), 0.0);
+        assert_eq!(get_thumb_offset(150.0, 100.0, 400.0, 200.0, 50.0), 75.0);
+        assert_eq!(get_thumb_offset(300.0, 100.0, 400.0, 200.0, 50.0), 150.0);
+        assert_eq!(get_thumb_offset(50.0, 100.0, 100.0, 200.0, 200.0), 0.0);
+
+        assert_eq!(
+            get_scroll_from_thumb_offset(75.0, 100.0, 400.0, 200.0, 50.0),
+            150.0
+        );
+        // Dragging past the ends clamps
+        assert_eq!(
+            get_scroll_from_thumb_offset(-20.0, 100.0, 400.0, 200.0, 50.0),
+            0.0
+        );
+        assert_eq!(
+            get_scroll_from_thumb_offset(500.0, 100.0, 400.0, 200.0, 50.0),
+            300.0
+        );
+    }
+
+    #[test]
+    fn test_track_click_pages_towards_pointer() {
+        // Thumb spans 75..125 on the track
+        assert_eq!(
+            get_paged_scroll(150.0, 100.0, 400.0, 75.0, 50.0, 10.0),
+            50.0
+        );
+        assert_eq!(
+            get_paged_scroll(150.0, 100.0, 400.0, 75.0, 50.0, 180.0),
+            250.0
+        );
+        assert_eq!(
+            get_paged_scroll(150.0, 100.0, 400.0, 75.0, 50.0, 100.0),
+            150.0
+        );
+        // Paging stops at the ends
+        assert_eq!(
+            get_paged_scroll(250.0, 100.0, 400.0, 125.0, 50.0, 190.0),
+            300.0
+        );
+        assert_eq!(get_paged_scroll(50.0, 100.0, 400.0, 25.0, 50.0, 5.0), 0.0);
+    }
+}
@@ -0,0 +1,1048 @@
+//! Select Component - Styled single-select backed by a native `<select>`
+//!
+//! Features:
+//! - ✅ Controlled/uncontrolled value (value, default_value, on_value_change) and open state
+//! - ✅ Item-aligned positioning (selected item over the trigger) or popper positioning
+//! - ✅ Keyboard: Up/Down, Home/End, Enter/Space, Escape, typeahead (also on the closed trigger)
+//! - ✅ Scroll up/down buttons for long lists
+//! - ✅ Hidden native `<select>` (bubble input pattern) so plain forms and server functions get the value
+//! - ✅ Renders the selected item's text under SSR
+
+use super::separator::Separator;
+use crate::hooks::{
+    use_controllable_state, use_dropdown_behavior, use_floating_position, use_id_with_prefix,
+    use_typeahead, Align, FloatingOptions, Rect, Side, UseDropdownBehaviorReturn,
+};
+use crate::utils::{active_element, move_index, query_elements, Movement};
+use leptos::context::Provider;
+use leptos::html::Div;
+use leptos::prelude::*;
+use std::time::Duration;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement, KeyboardEvent, PointerEvent};
+
+/// Selector for the enabled options of a select content
+const OPTION_SELECTOR: &str = "[role='option']:not([data-disabled])";
+
+/// Minimum distance in px kept between item-aligned content and the viewport edges
+const CONTENT_MARGIN: f64 = 10.0;
+
+/// How far (px) and how often (ms) the scroll buttons scroll while hovered
+const SCROLL_STEP: f64 = 24.0;
+const SCROLL_INTERVAL_MS: u64 = 50;
+
+/// Pointer travel in px below which a release still belongs to the opening press
+const TRIGGER_RELEASE_TOLERANCE: i32 = 10;
+
+/// How the content is positioned relative to the trigger
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum SelectPosition {
+    /// The selected item is placed over the trigger (native macOS style)
+    #[default]
+    ItemAligned,
+    /// The content floats below (or above) the trigger like a popover
+    Popper,
+}
+
+/// Helper function to generate select trigger CSS classes
+fn get_select_trigger_classes(user_class: String) -> String {
+    let base = "inline-flex h-9 min-w-[10rem] items-center justify-between gap-2 rounded bg-white px-3 text-sm text-gray-900 shadow-sm";
+    let focus = "focus:outline-none focus:ring-2 focus:ring-black focus:ring-offset-0";
+    let states = "data-[placeholder]:text-gray-500 data-[disabled]:cursor-not-allowed data-[disabled]:opacity-50";
+
+    format!("{base} {focus} {states} {user_class}")
+}
+
+/// Helper function to generate select content CSS classes
+fn get_select_content_classes(user_class: String) -> String {
+    let base = "z-50 flex flex-col overflow-hidden rounded-md border border-gray-200 bg-white text-gray-900 shadow-md";
+    let focus = "focus:outline-none";
+    let animation =
+        "data-[state=open]:animate-in data-[state=open]:fade-in-0 data-[state=open]:zoom-in-95";
+
+    format!("{base} {focus} {animation} {user_class}")
+}
+
+/// Helper function to generate select item CSS classes
+fn get_select_item_classes(user_class: String) -> String {
+    let base = "relative flex cursor-default select-none items-center rounded-sm py-1.5 pl-8 pr-2 text-sm outline-none";
+    let states = "data-[highlighted]:bg-hover-purple data-[highlighted]:text-white data-[disabled]:pointer-events-none data-[disabled]:opacity-50";
+
+    format!("{base} {states} {user_class}")
+}
+
+/// Where item-aligned content goes so the selected item sits over the trigger
+///
+/// `item_top` and `item_height` describe the selected item relative to the
+/// content's top edge. The result is clamped to the viewport with a small
+/// margin. Pure function so it can be unit-tested without a DOM.
+pub(crate) fn compute_item_aligned_position(
+    trigger: Rect,
+    content: (f64, f64),
+    item_top: f64,
+    item_height: f64,
+    viewport: (f64, f64),
+) -> (f64, f64) {
+    let (width, height) = content;
+    let (viewport_width, viewport_height) = viewport;
+
+    let x = trigger.x;
+    let y = trigger.y + trigger.height / 2.0 - (item_top + item_height / 2.0);
+
+    let clamp = |value: f64, size: f64, limit: f64| {
+        let max = (limit - size - CONTENT_MARGIN).max(CONTENT_MARGIN);
+        value.clamp(CONTENT_MARGIN, max)
+    };
+
+    (
+        clamp(x, width, viewport_width),
+        clamp(y, height, viewport_height),
+    )
+}
+
+/// An item known to the Select, used for the native `<select>` and SelectValue
+#[derive(Clone)]
+struct SelectItemData {
+    value: String,
+    label: String,
+    disabled: bool,
+    /// Content of the item's SelectItemText, rendered again by SelectValue
+    text: Option<ChildrenFn>,
+}
+
+/// Whether a pointer moved less than TRIGGER_RELEASE_TOLERANCE between two points
+pub(crate) fn is_within_release_tolerance(start: (i32, i32), end: (i32, i32)) -> bool {
+    (end.0 - start.0).abs() <= TRIGGER_RELEASE_TOLERANCE
+        && (end.1 - start.1).abs() <= TRIGGER_RELEASE_TOLERANCE
+}
+
+/// Context value shared between Select and its parts
+#[derive(Clone, Copy)]
+pub struct SelectContextValue {
+    /// Open state, ids and refs (the trigger ref and content ref live here)
+    pub select: UseDropdownBehaviorReturn,
+    /// Selected value ("" when nothing is selected)
+    pub value: Signal<String>,
+    pub set_value: Callback<String>,
+    pub disabled: Signal<bool>,
+    items: RwSignal<Vec<SelectItemData>>,
+    viewport_ref: NodeRef<Div>,
+    can_scroll_up: RwSignal<bool>,
+    can_scroll_down: RwSignal<bool>,
+    /// Where the mouse press that opened the content happened, until released
+    trigger_pointer_down: StoredValue<Option<(i32, i32)>>,
+}
+
+impl SelectContextValue {
+    /// Choose a value, close the content and return focus to the trigger
+    fn choose(&self, value: String) {
+        self.set_value.run(value);
+        self.select.dismiss.run(());
+    }
+
+    /// Whether a mouse release at (x, y) ends the press that opened the content
+    ///
+    /// Item-aligned content opens over the trigger, so that release lands on an item.
+    fn is_opening_release(&self, x: i32, y: i32) -> bool {
+        self.trigger_pointer_down
+            .try_update_value(Option::take)
+            .flatten()
+            .is_some_and(|start| is_within_release_tolerance(start, (x, y)))
+    }
+
+    /// Enabled option elements in DOM order (the content stays mounted while closed)
+    fn options(&self) -> Vec<HtmlElement> {
+        self.select
+            .content_ref
+            .get_untracked()
+            .map(|content| query_elements(&content, OPTION_SELECTOR))
+            .unwrap_or_default()
+    }
+
+    /// Recompute which scroll buttons are needed
+    fn update_scroll_buttons(&self) {
+        if let Some(viewport) = self.viewport_ref.get_untracked() {
+            let top = viewport.scroll_top();
+            let max = viewport.scroll_height() - viewport.client_height();
+            self.can_scroll_up.set(top > 0);
+            self.can_scroll_down.set(top < max - 1);
+        }
+    }
+}
+
+/// Context value shared between SelectItem and its text / indicator
+#[derive(Clone, Copy)]
+struct SelectItemContextValue {
+    value: StoredValue<String>,
+    is_selected: Signal<bool>,
+    text_id: Signal<String>,
+}
+
+/// Context value shared between SelectContent and its items
+#[derive(Clone, Copy)]
+struct SelectContentContextValue {
+    /// Typeahead search in progress (Space types instead of selecting)
+    search: Signal<String>,
+}
+
+/// Context value shared between SelectGroup and its label
+#[derive(Clone, Copy)]
+struct SelectGroupContextValue {
+    label_id: Signal<String>,
+}
+
+/// Label used for typeahead: `data-text-value` when set, otherwise the text content
+fn get_option_label(option: &HtmlElement) -> String {
+    option
+        .get_attribute("data-text-value")
+        .or_else(|| option.text_content())
+        .unwrap_or_default()
+}
+
+/// Select component - Hook-first implementation
+///
+/// # Example
+/// ```rust,ignore
+/// view! {
+///     <form action="/settings" method="post">
+///         <Select name="theme" default_value="system">
+///             <SelectTrigger>
+///                 <SelectValue placeholder="Pick a theme" />
+///             </SelectTrigger>
+///             <SelectContent>
+///                 <SelectViewport>
+///                     <SelectItem value="light"><SelectItemText>"Light"</SelectItemText></SelectItem>
+///                     <SelectItem value="dark"><SelectItemText>"Dark"</SelectItemText></SelectItem>
+///                     <SelectItem value="system"><SelectItemText>"System"</SelectItemText></SelectItem>
+///                 </SelectViewport>
+///             </SelectContent>
+///         </Select>
+///     </form>
+/// }
+/// ```
+#[component]
+pub fn Select(
+    // Core state management (from our hook library)
+    #[prop(into, optional)] value: MaybeProp<String>,
+    #[prop(into, optional)] default_value: MaybeProp<String>,
+    #[prop(into, optional)] on_value_change: Option<Callback<String>>,
+    #[prop(into, optional)] open: MaybeProp<bool>,
+    #[prop(into, optional)] default_open: MaybeProp<bool>,
+    #[prop(into, optional)] on_open_change: Option<Callback<bool>>,
+
+    // Form integration
+    #[prop(into, optional)] disabled: MaybeProp<bool>,
+    #[prop(into, optional)] required: MaybeProp<bool>,
+    #[prop(into, optional)] name: MaybeProp<String>,
+    #[prop(into, optional)] form: MaybeProp<String>,
+
+    children: Children,
+) -> impl IntoView {
+    let state = use_controllable_state(value, default_value, on_value_change);
+    let select = use_dropdown_behavior(open, default_open, on_open_change, "select");
+    let is_disabled = Signal::derive(move || disabled.get().unwrap_or(false));
+    let is_required = Signal::derive(move || required.get().unwrap_or(false));
+
+    let context_value = SelectContextValue {
+        select,
+        value: state.value,
+        set_value: state.set_value,
+        disabled: is_disabled,
+        items: RwSignal::new(Vec::new()),
+        viewport_ref: NodeRef::new(),
+        can_scroll_up: RwSignal::new(false),
+        can_scroll_down: RwSignal::new(false),
+        trigger_pointer_down: StoredValue::new(None),
+    };
+    let items = context_value.items;
+    let current = state.value;
+
+    view! {
+        <Provider value=context_value>
+            // Hidden native select for form integration (bubble input pattern)
+            <select
+                aria-hidden="true"
+                tabindex="-1"
+                name=move || name.get()
+                form=move || form.get()
+                required=move || is_required.get()
+                disabled=move || is_disabled.get()
+                // 🚨 TAILWIND CSS 4 ONLY - Hidden input styling
+                class="absolute opacity-0 pointer-events-none"
+                style="position: absolute; opacity: 0; pointer-events: none; margin: 0; width: 1px; height: 1px;"
+            >
+                <option value="" selected=move || current.get().is_empty()></option>
+                {move || {
+                    items
+                        .get()
+                        .into_iter()
+                        .map(|item| {
+                            let option_value = item.value.clone();
+                            view! {
+                                <option
+                                    value=item.value
+                                    disabled=item.disabled
+                                    selected=move || current.get() == option_value
+                                >
+                                    {item.label}
+                                </option>
+                            }
+                        })
+                        .collect_view()
+                }}
+            </select>
+            {children()}
+        </Provider>
+    }
+}
+
+/// SelectTrigger - Button showing the selected value (`role="combobox"`)
+#[component]
+pub fn SelectTrigger(
+    /// Accessible name when the trigger has no visible label
+    #[prop(into, optional)]
+    aria_label: MaybeProp<String>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: Children,
+) -> impl IntoView {
+    let context = expect_context::<SelectContextValue>();
+    let select = context.select;
+    let typeahead = use_typeahead();
+    let is_touch = StoredValue::new(false);
+
+    let open = move || {
+        if !context.disabled.get_untracked() && !select.open.get_untracked() {
+            select.open_with_focus.run(None);
+        }
+    };
+
+    view! {
+        <button
+            node_ref=select.trigger_ref
+            id=move || select.trigger_id.get()
+            type="button"
+            role="combobox"
+            aria-label=move || aria_label.get()
+            aria-haspopup="listbox"
+            aria-expanded=move || if select.open.get() { "true" } else { "false" }
+            aria-controls=move || select.content_id.get()
+            aria-autocomplete="none"
+            disabled=move || context.disabled.get()
+            data-state=move || select.get_state_attr.get()
+            data-disabled=move || context.disabled.get().then_some("")
+            data-placeholder=move || context.value.get().is_empty().then_some("")
+            class=move || get_select_trigger_classes(class.get().unwrap_or_default())
+            on:pointerdown=move |event: PointerEvent| {
+                is_touch.set_value(event.pointer_type() != "mouse");
+                if event.pointer_type() != "mouse" || event.button() != 0 || event.ctrl_key() {
+                    return;
+                }
+                // Keep focus off the trigger so the selected item can take it
+                event.prevent_default();
+                context
+                    .trigger_pointer_down
+                    .set_value(Some((event.client_x(), event.client_y())));
+                open();
+            }
+            on:click=move |_| {
+                if is_touch.get_value() {
+                    open();
+                }
+            }
+            on:keydown=move |event: KeyboardEvent| {
+                if context.disabled.get_untracked() {
+                    return;
+                }
+                let key = event.key();
+                match key.as_str() {
+                    "Enter" | " " if key != " " || typeahead.search.get_untracked().is_empty() => {
+                        event.prevent_default();
+                        open();
+                    }
+                    "ArrowDown" | "ArrowUp" => {
+                        event.prevent_default();
+                        open();
+                    }
+                    _ if key.chars().count() == 1
+                        && !event.ctrl_key()
+                        && !event.alt_key()
+                        && !event.meta_key() =>
+                    {
+                        // Typeahead on the closed trigger selects directly
+                        let options = context.options();
+                        let selected = context.value.get_untracked();
+                        let current = options
+                            .iter()
+                            .position(|option| option.get_attribute("data-value").as_deref() == Some(selected.as_str()));
+                        let labels = options.iter().map(get_option_label).collect();
+                        if let Some(index) = typeahead.on_key.run((key, labels, current)) {
+                            if let Some(value) = options[index].get_attribute("data-value") {
+                                context.set_value.run(value);
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        >
+            {children()}
+            <SelectIcon />
+        </button>
+    }
+}
+
+/// Chevron shown at the end of the trigger
+#[component]
+fn SelectIcon() -> impl IntoView {
+    view! {
+        <svg aria-hidden="true" width="12" height="12" viewBox="0 0 15 15" class="shrink-0 opacity-60">
+            <path
+                d="M4.93179 5.43179C4.75605 5.60753 4.75605 5.89245 4.93179 6.06819C5.10753 6.24392 5.39245 6.24392 5.56819 6.06819L7.49999 4.13638L9.43179 6.06819C9.60753 6.24392 9.89245 6.24392 10.0682 6.06819C10.2439 5.89245 10.2439 5.60753 10.0682 5.43179L7.81819 3.18179C7.73379 3.0974 7.61933 3.04999 7.49999 3.04999C7.38064 3.04999 7.26618 3.0974 7.18179 3.18179L4.93179 5.43179ZM10.0682 9.56819C10.2439 9.39245 10.2439 9.10753 10.0682 8.93179C9.89245 8.75606 9.60753 8.75606 9.43179 8.93179L7.49999 10.8636L5.56819 8.93179C5.39245 8.75606 5.10753 8.75606 4.93179 8.93179C4.75605 9.10753 4.75605 9.39245 4.93179 9.56819L7.18179 11.8182C7.26618 11.9026 7.38064 11.95 7.49999 11.95C7.61933 11.95 7.73379 11.9026 7.81819 11.8182L10.0682 9.56819Z"
+                fill="currentColor"
+            />
+        </svg>
+    }
+}
+
+/// SelectValue - The selected item's text, or the placeholder
+#[component]
+pub fn SelectValue(
+    /// Shown while nothing is selected
+    #[prop(into, optional)]
+    placeholder: MaybeProp<String>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+) -> impl IntoView {
+    let context = expect_context::<SelectContextValue>();
+
+    let selected_text = move || {
+        let value = context.value.get();
+        context.items.with(|items| {
+            items
+                .iter()
+                .find(|item| item.value == value)
+                .map(|item| (item.text.clone(), item.label.clone()))
+        })
+    };
+
+    view! {
+        <span style="pointer-events: none;" class=move || class.get()>
+            {move || match selected_text() {
+                Some((Some(text), _)) => text().into_any(),
+                Some((None, label)) => label.into_any(),
+                None => placeholder.get().unwrap_or_default().into_any(),
+            }}
+        </span>
+    }
+}
+
+/// SelectContent - The listbox (`role="listbox"`)
+///
+/// Stays mounted (hidden) while closed so the value text, typeahead on the
+/// trigger and the native select know every item, including under SSR.
+#[component]
+pub fn SelectContent(
+    /// Item-aligned (default) or popper positioning
+    #[prop(into, optional)]
+    position: MaybeProp<SelectPosition>,
+    /// Popper only: preferred side of the trigger (default: bottom)
+    #[prop(into, optional)]
+    side: MaybeProp<Side>,
+    /// Popper only: distance in px from the trigger
+    #[prop(into, optional)]
+    side_offset: MaybeProp<f64>,
+    /// Popper only: preferred alignment against the trigger (default: start)
+    #[prop(into, optional)]
+    align: MaybeProp<Align>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: Children,
+) -> impl IntoView {
+    let context = expect_context::<SelectContextValue>();
+    let select = context.select;
+    let typeahead = use_typeahead();
+    let is_popper =
+        Signal::derive(move || position.get().unwrap_or_default() == SelectPosition::Popper);
+    let (aligned, set_aligned) = signal(None::<(f64, f64)>);
+    let (trigger_width, set_trigger_width) = signal(0.0);
+
+    let options = Signal::derive(move || FloatingOptions {
+        side: side.get().unwrap_or_default(),
+        align: align.get().unwrap_or(Align::Start),
+        side_offset: side_offset.get().unwrap_or(4.0),
+        ..Default::default()
+    });
+    let popper_open = Signal::derive(move || select.open.get() && is_popper.get());
+    let popper = use_floating_position(
+        popper_open,
+        move || {
+            select
+                .trigger_ref
+                .get()
+                .map(|trigger| trigger.unchecked_into())
+        },
+        select.content_ref,
+        options,
+    );
+
+    let selected_option = move || {
+        let value = context.value.get_untracked();
+        context
+            .options()
+            .into_iter()
+            .find(|option| option.get_attribute("data-value").as_deref() == Some(value.as_str()))
+    };
+
+    // A release anywhere else ends the opening press too; wait until the
+    // event has reached the items first
+    Effect::new(move |_| {
+        if !select.open.get() {
+            context.trigger_pointer_down.set_value(None);
+            return;
+        }
+        let handle = window_event_listener(leptos::ev::pointerup, move |_| {
+            set_timeout(
+                move || {
+                    context.trigger_pointer_down.try_set_value(None);
+                },
+                Duration::ZERO,
+            );
+        });
+        on_cleanup(move || handle.remove());
+    });
+
+    // Measure the trigger and, for item-aligned content, line up the selected item
+    Effect::new(move |_| {
+        if !select.open.get() {
+            set_aligned.set(None);
+            return;
+        }
+        let is_popper = is_popper.get();
+        request_animation_frame(move || {
+            let (Some(trigger), Some(content)) = (
+                select.trigger_ref.get_untracked(),
+                select.content_ref.get_untracked(),
+            ) else {
+                return;
+            };
+            let trigger = Rect::from_element(&trigger);
+            set_trigger_width.set(trigger.width);
+            context.update_scroll_buttons();
+            if is_popper {
+                return;
+            }
+
+            let content_element: &Element = &content;
+            let content_rect = Rect::from_element(content_element);
+            let (item_top, item_height) = selected_option()
+                .or_else(|| context.options().into_iter().next())
+                .map(|option| {
+                    let rect = Rect::from_element(&option);
+                    (rect.y - content_rect.y, rect.height)
+                })
+                .unwrap_or((0.0, trigger.height));
+            let viewport = web_sys::window()
+                .map(|window| {
+                    (
+                        window
+                            .inner_width()
+                            .ok()
+                            .and_then(|w| w.as_f64())
+                            .unwrap_or(0.0),
+                        window
+                            .inner_height()
+                            .ok()
+                            .and_then(|h| h.as_f64())
+                            .unwrap_or(0.0),
+                    )
+                })
+                .unwrap_or_default();
+            set_aligned.set(Some(compute_item_aligned_position(
+                trigger,
+                (content_rect.width, content_rect.height),
+                item_top,
+                item_height,
+                viewport,
+            )));
+        });
+    });
+
+    // Focus the selected item (or the first one) once the content is placed
+    Effect::new(move |_| {
+        let is_placed = if is_popper.get() {
+            popper.placement.get().is_some()
+        } else {
+            aligned.get().is_some()
+        };
+        if !select.open.get() || !is_placed {
+            return;
+        }
+        let target = selected_option().or_else(|| context.options().into_iter().next());
+        match target {
+            Some(option) => {
+                let _ = option.focus();
+                option.scroll_into_view_with_bool(false);
+            }
+            None => {
+                if let Some(content) = select.content_ref.get_untracked() {
+                    let _ = content.focus();
+                }
+            }
+        }
+    });
+
+    let style = move || {
+        if !select.open.get() {
+            return "display: none;".to_string();
+        }
+        let min_width = format!(
+            "min-width: {}px; --select-trigger-width: {}px;",
+            trigger_width.get(),
+            trigger_width.get()
+        );
+        let position = if is_popper.get() {
+            popper.style.get()
+        } else {
+            match aligned.get() {
+                Some((x, y)) => format!("position: fixed; left: {x}px; top: {y}px;"),
+                None => "position: fixed; left: 0px; top: 0px; visibility: hidden;".to_string(),
+            }
+        };
+        format!(
+            "{position} {min_width} max-height: calc(100vh - {}px);",
+            CONTENT_MARGIN * 2.0
+        )
+    };
+
+    let on_keydown = move |event: KeyboardEvent| {
+        let options = context.options();
+        let current =
+            active_element().and_then(|active| options.iter().position(|option| *option == active));
+        let key = event.key();
+
+        let movement = match key.as_str() {
+            "ArrowDown" => Some(Movement::Next),
+            "ArrowUp" => Some(Movement::Previous),
+            "Home" => Some(Movement::First),
+            "End" => Some(Movement::Last),
+            _ => None,
+        };
+        if let Some(movement) = movement {
+            event.prevent_default();
+            if let Some(option) = move_index(current, options.len(), movement, false)
+                .and_then(|index| options.get(index))
+            {
+                let _ = option.focus();
+                option.scroll_into_view_with_bool(false);
+            }
+            return;
+        }
+
+        match key.as_str() {
+            // Focus stays inside the open listbox
+            "Tab" => event.prevent_default(),
+            _ if key.chars().count() == 1
+                && !event.ctrl_key()
+                && !event.alt_key()
+                && !event.meta_key() =>
+            {
+                if key == " " && typeahead.search.get_untracked().is_empty() {
+                    return;
+                }
+                event.prevent_default();
+                let labels = options.iter().map(get_option_label).collect();
+                if let Some(index) = typeahead.on_key.run((key, labels, current)) {
+                    let _ = options[index].focus();
+                    options[index].scroll_into_view_with_bool(false);
+                }
+            }
+            _ => {}
+        }
+    };
+
+    view! {
+        <Provider value=SelectContentContextValue { search: typeahead.search }>
+            <div
+                node_ref=select.content_ref
+                id=move || select.content_id.get()
+                role="listbox"
+                aria-labelledby=move || select.trigger_id.get()
+                tabindex="-1"
+                hidden=move || !select.open.get()
+                data-state=move || select.get_state_attr.get()
+                data-position=move || if is_popper.get() { "popper" } else { "item-aligned" }
+                data-side=move || is_popper.get().then(|| popper.side.get().as_str())
+                style=style
+                class=move || get_select_content_classes(class.get().unwrap_or_default())
+                on:keydown=on_keydown
+            >
+                {children()}
+            </div>
+        </Provider>
+    }
+}
+
+/// SelectViewport - Scrollable container for the items
+#[component]
+pub fn SelectViewport(
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: Children,
+) -> impl IntoView {
+    let context = expect_context::<SelectContextValue>();
+
+    view! {
+        <div
+            node_ref=context.viewport_ref
+            role="presentation"
+            style="overflow: hidden auto; scrollbar-width: none;"
+            class=move || format!("flex-1 p-1 {}", class.get().unwrap_or_default())
+            on:scroll=move |_| context.update_scroll_buttons()
+        >
+            {children()}
+        </div>
+    }
+}
+
+/// SelectItem - One option (`role="option"`)
+#[component]
+pub fn SelectItem(
+    #[prop(into)] value: String,
+    #[prop(into, optional)] disabled: MaybeProp<bool>,
+    /// Label used for typeahead and the native option when the content is not plain text
+    #[prop(into, optional)]
+    text_value: MaybeProp<String>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: Children,
+) -> impl IntoView {
+    let context = expect_context::<SelectContextValue>();
+    let content = expect_context::<SelectContentContextValue>();
+    let item_value = StoredValue::new(value);
+    let item_ref = NodeRef::<Div>::new();
+    let highlighted = RwSignal::new(false);
+    let is_disabled = Signal::derive(move || disabled.get().unwrap_or(false));
+    let is_selected =
+        Signal::derive(move || item_value.with_value(|value| *value == context.value.get()));
+    let text_id = use_id_with_prefix("select-item-text");
+
+    // Register with the Select for the native option and SelectValue
+    let value = item_value.get_value();
+    context.items.update(|items| {
+        items.push(SelectItemData {
+            value: value.clone(),
+            label: text_value.get_untracked().unwrap_or_else(|| value.clone()),
+            disabled: is_disabled.get_untracked(),
+            text: None,
+        })
+    });
+    on_cleanup(move || {
+        context.items.try_update(|items| {
+            item_value.with_value(|value| items.retain(|item| item.value != *value))
+        });
+    });
+
+    let choose = move || {
+        if !is_disabled.get_untracked() {
+            context.choose(item_value.get_value());
+        }
+    };
+    // Mouse selection happens on pointerup; click only serves touch and pen
+    let is_mouse = StoredValue::new(false);
+
+    view! {
+        <Provider value=SelectItemContextValue { value: item_value, is_selected, text_id }>
+            <div
+                node_ref=item_ref
+                role="option"
+                tabindex="-1"
+                aria-labelledby=move || text_id.get()
+                aria-selected=move || if is_selected.get() { "true" } else { "false" }
+                aria-disabled=move || is_disabled.get().then_some("true")
+                data-value=move || item_value.get_value()
+                data-state=move || if is_selected.get() { "checked" } else { "unchecked" }
+                data-disabled=move || is_disabled.get().then_some("")
+                data-highlighted=move || highlighted.get().then_some("")
+                data-text-value=move || text_value.get()
+                class=move || get_select_item_classes(class.get().unwrap_or_default())
+                on:focus=move |_| highlighted.set(true)
+                on:blur=move |_| highlighted.set(false)
+                on:pointermove=move |event: PointerEvent| {
+                    if event.pointer_type() == "mouse" && !is_disabled.get_untracked() && !highlighted.get_untracked() {
+                        if let Some(item) = item_ref.get_untracked() {
+                            let _ = item.focus();
+                        }
+                    }
+                }
+                on:pointerleave=move |_| {
+                    if highlighted.get_untracked() {
+                        if let Some(content) = context.select.content_ref.get_untracked() {
+                            let _ = content.focus();
+                        }
+                    }
+                }
+                on:pointerdown=move |event: PointerEvent| {
+                    is_mouse.set_value(event.pointer_type() == "mouse");
+                }
+                on:pointerup=move |event: PointerEvent| {
+                    if event.pointer_type() != "mouse" || event.button() != 0 {
+                        return;
+                    }
+                    // Releasing the press that opened the select does not choose
+                    if !context.is_opening_release(event.client_x(), event.client_y()) {
+                        choose();
+                    }
+                }
+                on:click=move |_| {
+                    if !is_mouse.get_value() {
+                        choose();
+                    }
+                }
+                on:keydown=move |event: KeyboardEvent| {
+                    let key = event.key();
+                    if key == "Enter" || (key == " " && content.search.get_untracked().is_empty()) {
+                        event.prevent_default();
+                        choose();
+                    }
+                }
+            >
+                {children()}
+            </div>
+        </Provider>
+    }
+}
+
+/// SelectItemText - The item's label, also shown by SelectValue when selected
+#[component]
+pub fn SelectItemText(
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: ChildrenFn,
+) -> impl IntoView {
+    let context = expect_context::<SelectContextValue>();
+    let item = expect_context::<SelectItemContextValue>();
+
+    let value = item.value.get_value();
+    let text = children.clone();
+    context.items.update(|items| {
+        if let Some(data) = items.iter_mut().find(|data| data.value == value) {
+            data.text = Some(text);
+        }
+    });
+
+    view! {
+        <span id=move || item.text_id.get() class=move || class.get()>
+            {children()}
+        </span>
+    }
+}
+
+/// SelectItemIndicator - Rendered only while its item is selected
+#[component]
+pub fn SelectItemIndicator(
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: ChildrenFn,
+) -> impl IntoView {
+    let item = expect_context::<SelectItemContextValue>();
+    let children = StoredValue::new(children);
+
+    view! {
+        <Show when=move || item.is_selected.get()>
+            <span class=move || {
+                format!(
+                    "absolute left-2 inline-flex h-4 w-4 items-center justify-center {}",
+                    class.get().unwrap_or_default(),
+                )
+            }>
+                {children.read_value()()}
+            </span>
+        </Show>
+    }
+}
+
+/// SelectGroup - Groups related items (`role="group"`, labelled by its SelectLabel)
+#[component]
+pub fn SelectGroup(
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: Children,
+) -> impl IntoView {
+    let label_id = use_id_with_prefix("select-group-label");
+
+    view! {
+        <Provider value=SelectGroupContextValue { label_id }>
+            <div role="group" aria-labelledby=move || label_id.get() class=move || class.get()>
+                {children()}
+            </div>
+        </Provider>
+    }
+}
+
+/// SelectLabel - Non-interactive heading for a SelectGroup
+#[component]
+pub fn SelectLabel(
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: Children,
+) -> impl IntoView {
+    let group = use_context::<SelectGroupContextValue>();
+
+    view! {
+        <div
+            id=move || group.map(|group| group.label_id.get())
+            class=move || {
+                format!(
+                    "py-1.5 pl-8 pr-2 text-xs font-semibold text-gray-500 {}",
+                    class.get().unwrap_or_default(),
+                )
+            }
+        >
+            {children()}
+        </div>
+    }
+}
+
+/// SelectSeparator - Divider between items (reuses Separator)
+#[component]
+pub fn SelectSeparator(#[prop(into, optional)] class: MaybeProp<String>) -> impl IntoView {
+    view! {
+        <Separator class=Signal::derive(move || {
+            format!("-mx-1 my-1 w-auto {}", class.get().unwrap_or_default())
+        }) />
+    }
+}
+
+/// Shared behavior of the scroll buttons: scroll the viewport while hovered
+#[component]
+fn SelectScrollButton(
+    direction: f64,
+    visible: RwSignal<bool>,
+    class: MaybeProp<String>,
+    children: ChildrenFn,
+) -> impl IntoView {
+    let context = expect_context::<SelectContextValue>();
+    let children = StoredValue::new(children);
+    let timer = StoredValue::new(None::<IntervalHandle>);
+
+    let stop = move || {
+        if let Some(handle) = timer.try_update_value(Option::take).flatten() {
+            handle.clear();
+        }
+    };
+    let start = move || {
+        if timer.with_value(Option::is_some) {
+            return;
+        }
+        let handle = set_interval_with_handle(
+            move || {
+                if let Some(viewport) = context.viewport_ref.get_untracked() {
+                    viewport.scroll_by_with_x_and_y(0.0, direction * SCROLL_STEP);
+                }
+            },
+            Duration::from_millis(SCROLL_INTERVAL_MS),
+        )
+        .ok();
+        timer.set_value(handle);
+    };
+
+    // Stop once the end is reached (the button unmounts)
+    Effect::new(move |_| {
+        if !visible.get() {
+            stop();
+        }
+    });
+    on_cleanup(stop);
+
+    view! {
+        <Show when=move || visible.get()>
+            <div
+                aria-hidden="true"
+                class=move || {
+                    format!(
+                        "flex h-6 shrink-0 cursor-default items-center justify-center {}",
+                        class.get().unwrap_or_default(),
+                    )
+                }
+                on:pointerdown=move |_| start()
+                on:pointermove=move |_| start()
+                on:pointerleave=move |_| stop()
+            >
+                {children.read_value()()}
+            </div>
+        </Show>
+    }
+}
+
+/// SelectScrollUpButton - Shown while the viewport can scroll up; scrolls on hover
+#[component]
+pub fn SelectScrollUpButton(
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: ChildrenFn,
+) -> impl IntoView {
+    let context = expect_context::<SelectContextValue>();
+
+    view! {
+        <SelectScrollButton direction=-1.0 visible=context.can_scroll_up class=class children=children />
+    }
+}
+
+/// SelectScrollDownButton - Shown while the viewport can scroll down; scrolls on hover
+#[component]
+pub fn SelectScrollDownButton(
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: ChildrenFn,
+) -> impl IntoView {
+    let context = expect_context::<SelectContextValue>();
+
+    view! {
+        <SelectScrollButton direction=1.0 visible=context.can_scroll_down class=class children=children />
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trigger() -> Rect {
+        Rect {
+            x: 100.0,
+            y: 300.0,
+            width: 160.0,
+            height: 36.0,
+        }
+    }
+
+    #[test]
+    fn test_default_position_is_item_aligned() {
+        assert_eq!(SelectPosition::default(), SelectPosition::ItemAligned);
+    }
+
+    #[test]
+    fn test_item_aligned_centers_selected_item_on_trigger() {
+        // Third item (top 64, height 32) centered on the trigger's middle (318)
+        let (x, y) =
+            compute_item_aligned_position(trigger(), (180.0, 200.0), 64.0, 32.0, (1024.0, 768.0));
+        assert_eq!(x, 100.0);
+        assert_eq!(y, 318.0 - 80.0);
+    }
+
+    #[test]
+    fn test_item_aligned_is_clamped_to_viewport() {
+        // Selected item far down the list would push the content above the viewport
+        let (_, y) =
+            compute_item_aligned_position(trigger(), (180.0, 600.0), 500.0, 32.0, (1024.0, 768.0));
+        assert_eq!(y, 10.0);
+
+        let (x, _) = compute_item_aligned_position(
+            Rect {
+                x: 950.0,
+                ..trigger()
+            },
+            (180.0, 200.0),
+            0.0,
+            32.0,
+            (1024.0, 768.0),
+        );
+        assert_eq!(x, 1024.0 - 180.0 - 10.0);
+    }
+
+    #[test]
+    fn test_release_near_opening_press_is_ignored() {
+        // The pointerup of the click that opened item-aligned content
+        assert!(is_within_release_tolerance((100, 300), (104, 293)));
+        assert!(!is_within_release_tolerance((100, 300), (100, 340)));
+    }
+}
@@ -22,7 +22,7 @@ fn get_separator_classes(orientation: &str, decorative: bool, user_class: String
 /// Styled with Tailwind CSS 4 ONLY - no custom CSS allowed.
 ///
 /// # Examples
-/// ```rust
+/// ```rust,ignore
 /// // Horizontal separator (default)
 /// view! {
 ///     <Separator />
@@ -0,0 +1,447 @@
+//! Slider Component - Value (or range) picker along a track
+//!
+//! Features:
+//! - ✅ Controlled/uncontrolled values (value, default_value, on_value_change) via use_slider_state
+//! - ✅ Multiple thumbs for ranges, with min_steps_between_thumbs
+//! - ✅ min / max / step, horizontal / vertical, inverted and RTL
+//! - ✅ Keyboard: arrows (Shift for 10 steps), PageUp/PageDown, Home/End
+//! - ✅ Pointer dragging with pointer capture; on_value_commit when the interaction ends (or is cancelled)
+//! - ✅ Hidden input per thumb for form submission
+
+use crate::hooks::{get_closest_value_index, use_slider_state, UseSliderStateReturn};
+use crate::utils::query_elements;
+use leptos::context::Provider;
+use leptos::html::Span;
+use leptos::prelude::*;
+use web_sys::{KeyboardEvent, PointerEvent};
+
+/// Helper function to generate slider root CSS classes
+fn get_slider_classes(vertical: bool, user_class: String) -> String {
+    let base = "relative flex touch-none select-none items-center";
+    let orientation = if vertical {
+        "h-48 w-5 flex-col"
+    } else {
+        "h-5 w-full"
+    };
+    let disabled = "data-[disabled]:opacity-50 data-[disabled]:cursor-not-allowed";
+
+    format!("{base} {orientation} {disabled} {user_class}")
+}
+
+/// Helper function to generate slider thumb CSS classes
+fn get_slider_thumb_classes(user_class: String) -> String {
+    let base = "block h-5 w-5 rounded-full bg-white shadow-lg transition-colors";
+    let focus = "focus:outline-none focus:ring-2 focus:ring-black focus:ring-offset-0";
+    let hover = "hover:bg-hover-purple";
+
+    format!("{base} {focus} {hover} {user_class}")
+}
+
+/// Number of steps an arrow / page key moves the thumb (negative = towards min)
+///
+/// `reversed` means the track starts at the right (horizontal) or top
+/// (vertical) edge; arrow keys follow the visual direction.
+pub(crate) fn get_slider_key_steps(
+    key: &str,
+    shift: bool,
+    vertical: bool,
+    reversed: bool,
+) -> Option<f64> {
+    let arrow = if shift { 10.0 } else { 1.0 };
+    let forward = match (vertical, reversed) {
+        (false, false) => ["ArrowRight", "ArrowUp"],
+        (false, true) => ["ArrowLeft", "ArrowUp"],
+        (true, false) => ["ArrowUp", "ArrowRight"],
+        (true, true) => ["ArrowDown", "ArrowRight"],
+    };
+
+    match key {
+        "PageUp" => Some(10.0),
+        "PageDown" => Some(-10.0),
+        key if forward.contains(&key) => Some(arrow),
+        "ArrowLeft" | "ArrowRight" | "ArrowUp" | "ArrowDown" => Some(-arrow),
+        _ => None,
+    }
+}
+
+/// Context value shared between Slider and its parts
+#[derive(Clone, Copy)]
+pub struct SliderContextValue {
+    pub state: UseSliderStateReturn,
+    pub vertical: Signal<bool>,
+    /// The track starts at the right / top edge (inverted, or RTL when horizontal)
+    pub reversed: Signal<bool>,
+    pub disabled: Signal<bool>,
+    pub name: Signal<Option<String>>,
+    pub form: Signal<Option<String>>,
+    /// Thumb being dragged or last focused
+    active_thumb: RwSignal<usize>,
+    root_ref: NodeRef<Span>,
+}
+
+impl SliderContextValue {
+    /// Focus the thumb element at `index`
+    fn focus_thumb(&self, index: usize) {
+        let thumb = self.root_ref.get_untracked().and_then(|root| {
+            query_elements(&root, "[role='slider']")
+                .into_iter()
+                .nth(index)
+        });
+        if let Some(thumb) = thumb {
+            let _ = thumb.focus();
+        }
+    }
+
+    /// CSS for an element placed at `percent` along the track
+    fn position_style(&self, percent: f64) -> String {
+        match (self.vertical.get(), self.reversed.get()) {
+            (false, false) => format!("left: {percent}%; transform: translateX(-50%);"),
+            (false, true) => format!("right: {percent}%; transform: translateX(50%);"),
+            (true, false) => format!("bottom: {percent}%; transform: translateY(50%);"),
+            (true, true) => format!("top: {percent}%; transform: translateY(-50%);"),
+        }
+    }
+}
+
+/// Slider component - Hook-first implementation
+///
+/// # Example
+/// ```rust,ignore
+/// view! {
+///     <Slider name="price" default_value=vec![20.0, 80.0] step=5.0 min_steps_between_thumbs=1.0>
+///         <SliderTrack>
+///             <SliderRange />
+///         </SliderTrack>
+///         <SliderThumb index=0 aria_label="Minimum price" />
+///         <SliderThumb index=1 aria_label="Maximum price" />
+///     </Slider>
+/// }
+/// ```
+#[component]
+pub fn Slider(
+    // Core state management (from our hook library)
+    #[prop(into, optional)] value: MaybeProp<Vec<f64>>,
+    #[prop(into, optional)] default_value: MaybeProp<Vec<f64>>,
+    #[prop(into, optional)] on_value_change: Option<Callback<Vec<f64>>>,
+    /// Called once a drag or key press has finished changing the value
+    #[prop(into, optional)]
+    on_value_commit: Option<Callback<Vec<f64>>>,
+
+    // Range
+    #[prop(into, optional)] min: MaybeProp<f64>,
+    #[prop(into, optional)] max: MaybeProp<f64>,
+    #[prop(into, optional)] step: MaybeProp<f64>,
+    /// Minimum distance between thumbs, in steps
+    #[prop(into, optional)]
+    min_steps_between_thumbs: MaybeProp<f64>,
+
+    // Layout
+    /// "horizontal" (default) or "vertical"
+    #[prop(into, optional)]
+    orientation: MaybeProp<String>,
+    /// Start the track at the opposite edge
+    #[prop(into, optional)]
+    inverted: MaybeProp<bool>,
+    /// Reading direction ("ltr" or "rtl")
+    #[prop(into, optional)]
+    dir: MaybeProp<String>,
+
+    // Form integration
+    #[prop(into, optional)] disabled: MaybeProp<bool>,
+    #[prop(into, optional)] name: MaybeProp<String>,
+    #[prop(into, optional)] form: MaybeProp<String>,
+
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: Children,
+) -> impl IntoView {
+    let state = use_slider_state(
+        value,
+        default_value,
+        on_value_change,
+        on_value_commit,
+        min,
+        max,
+        step,
+        min_steps_between_thumbs,
+    );
+    let vertical = Signal::derive(move || orientation.get().as_deref() == Some("vertical"));
+    let reversed = Signal::derive(move || {
+        let inverted = inverted.get().unwrap_or(false);
+        let rtl = dir.get().as_deref() == Some("rtl");
+        if vertical.get() {
+            inverted
+        } else {
+            inverted != rtl
+        }
+    });
+    let is_disabled = Signal::derive(move || disabled.get().unwrap_or(false));
+    let root_ref = NodeRef::<Span>::new();
+
+    let context = SliderContextValue {
+        state,
+        vertical,
+        reversed,
+        disabled: is_disabled,
+        name: Signal::derive(move || name.get()),
+        form: Signal::derive(move || form.get()),
+        active_thumb: RwSignal::new(0),
+        root_ref,
+    };
+
+    // Value under the pointer, from the root's bounding box
+    let value_at = move |event: &PointerEvent| {
+        let root = root_ref.get_untracked()?;
+        let rect = root.get_bounding_client_rect();
+        let (x, y) = (f64::from(event.client_x()), f64::from(event.client_y()));
+        let fraction = if vertical.get_untracked() {
+            (rect.bottom() - y) / rect.height()
+        } else {
+            (x - rect.left()) / rect.width()
+        };
+        let fraction = if reversed.get_untracked() {
+            1.0 - fraction
+        } else {
+            fraction
+        };
+        let (min, max) = (state.min.get_untracked(), state.max.get_untracked());
+        Some(min + fraction.clamp(0.0, 1.0) * (max - min))
+    };
+
+    view! {
+        <Provider value=context>
+            <span
+                node_ref=root_ref
+                dir=move || dir.get()
+                aria-disabled=move || is_disabled.get().then_some("true")
+                data-orientation=move || if vertical.get() { "vertical" } else { "horizontal" }
+                data-disabled=move || is_disabled.get().then_some("")
+                class=move || get_slider_classes(vertical.get(), class.get().unwrap_or_default())
+                on:pointerdown=move |event: PointerEvent| {
+                    if is_disabled.get_untracked() || event.button() != 0 {
+                        return;
+                    }
+                    let Some(value) = value_at(&event) else {
+                        return;
+                    };
+                    // Keep receiving moves while dragging outside the slider
+                    if let Some(root) = root_ref.get_untracked() {
+                        let _ = root.set_pointer_capture(event.pointer_id());
+                    }
+                    event.prevent_default();
+                    let closest = get_closest_value_index(&state.values.get_untracked(), value);
+                    let index = state.set_thumb.run((closest, value));
+                    context.active_thumb.set(index);
+                    context.focus_thumb(index);
+                }
+                on:pointermove=move |event: PointerEvent| {
+                    let is_dragging = root_ref
+                        .get_untracked()
+                        .is_some_and(|root| root.has_pointer_capture(event.pointer_id()));
+                    if !is_dragging {
+                        return;
+                    }
+                    if let Some(value) = value_at(&event) {
+                        let index = state.set_thumb.run((context.active_thumb.get_untracked(), value));
+                        if index != context.active_thumb.get_untracked() {
+                            context.active_thumb.set(index);
+                            context.focus_thumb(index);
+                        }
+                    }
+                }
+                on:pointerup=move |event: PointerEvent| {
+                    if let Some(root) = root_ref.get_untracked() {
+                        let _ = root.release_pointer_capture(event.pointer_id());
+                    }
+                }
+                on:pointercancel=move |event: PointerEvent| {
+                    if let Some(root) = root_ref.get_untracked() {
+                        let _ = root.release_pointer_capture(event.pointer_id());
+                    }
+                }
+                // Every drag ends here: released, cancelled or taken away
+                on:lostpointercapture=move |_| state.commit.run(())
+            >
+                {children()}
+            </span>
+        </Provider>
+    }
+}
+
+/// SliderTrack - The bar the thumbs move along
+#[component]
+pub fn SliderTrack(
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: Children,
+) -> impl IntoView {
+    let context = expect_context::<SliderContextValue>();
+
+    view! {
+        <span
+            data-orientation=move || if context.vertical.get() { "vertical" } else { "horizontal" }
+            data-disabled=move || context.disabled.get().then_some("")
+            class=move || {
+                let size = if context.vertical.get() { "h-full w-1.5" } else { "h-1.5 w-full" };
+                format!("relative grow overflow-hidden rounded-full bg-[#221B3E] {size} {}", class.get().unwrap_or_default())
+            }
+        >
+            {children()}
+        </span>
+    }
+}
+
+/// SliderRange - The filled part of the track (up to the thumb, or between thumbs)
+#[component]
+pub fn SliderRange(#[prop(into, optional)] class: MaybeProp<String>) -> impl IntoView {
+    let context = expect_context::<SliderContextValue>();
+    let state = context.state;
+
+    let style = move || {
+        let values = state.values.get();
+        let end = values.iter().copied().fold(f64::MIN, f64::max);
+        let start = if values.len() > 1 {
+            state.get_percent(values.iter().copied().fold(f64::MAX, f64::min))
+        } else {
+            0.0
+        };
+        let end = state.get_percent(end);
+        let (from, to) = match (context.vertical.get(), context.reversed.get()) {
+            (false, false) => ("left", "right"),
+            (false, true) => ("right", "left"),
+            (true, false) => ("bottom", "top"),
+            (true, true) => ("top", "bottom"),
+        };
+        format!(
+            "position: absolute; {from}: {start}%; {to}: {}%;",
+            100.0 - end
+        )
+    };
+
+    view! {
+        <span
+            data-orientation=move || if context.vertical.get() { "vertical" } else { "horizontal" }
+            data-disabled=move || context.disabled.get().then_some("")
+            style=style
+            class=move || {
+                let size = if context.vertical.get() { "w-full" } else { "h-full" };
+                format!("rounded-full bg-white {size} {}", class.get().unwrap_or_default())
+            }
+        />
+    }
+}
+
+/// SliderThumb - A draggable handle (`role="slider"`) with its hidden input
+#[component]
+pub fn SliderThumb(
+    /// Which value this thumb controls (default: 0, the first)
+    #[prop(optional)]
+    index: usize,
+    /// Accessible name, e.g. "Minimum price" for range sliders
+    #[prop(into, optional)]
+    aria_label: MaybeProp<String>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+) -> impl IntoView {
+    let context = expect_context::<SliderContextValue>();
+    let state = context.state;
+
+    let value = Signal::derive(move || state.values.get().get(index).copied());
+    let percent = Signal::derive(move || {
+        value
+            .get()
+            .map(|value| state.get_percent(value))
+            .unwrap_or(0.0)
+    });
+    let input_name = move || {
+        let name = context.name.get()?;
+        Some(if state.values.get().len() > 1 {
+            format!("{name}[]")
+        } else {
+            name
+        })
+    };
+
+    let on_keydown = move |event: KeyboardEvent| {
+        if context.disabled.get_untracked() {
+            return;
+        }
+        let key = event.key();
+        let moved_to = match key.as_str() {
+            "Home" => state.set_thumb.run((index, state.min.get_untracked())),
+            "End" => state.set_thumb.run((index, state.max.get_untracked())),
+            _ => match get_slider_key_steps(
+                &key,
+                event.shift_key(),
+                context.vertical.get_untracked(),
+                context.reversed.get_untracked(),
+            ) {
+                Some(steps) => state.step_thumb.run((index, steps)),
+                None => return,
+            },
+        };
+        event.prevent_default();
+        if moved_to != index {
+            context.focus_thumb(moved_to);
+        }
+        state.commit.run(());
+    };
+
+    view! {
+        <span
+            style=move || format!("position: absolute; {}", context.position_style(percent.get()))
+            class=move || if value.get().is_none() { "hidden" } else { "" }
+        >
+            <span
+                role="slider"
+                tabindex=move || if context.disabled.get() { "-1" } else { "0" }
+                aria-label=move || aria_label.get()
+                aria-valuemin=move || state.min.get()
+                aria-valuemax=move || state.max.get()
+                aria-valuenow=move || value.get()
+                aria-orientation=move || if context.vertical.get() { "vertical" } else { "horizontal" }
+                aria-disabled=move || context.disabled.get().then_some("true")
+                data-orientation=move || if context.vertical.get() { "vertical" } else { "horizontal" }
+                data-disabled=move || context.disabled.get().then_some("")
+                class=move || get_slider_thumb_classes(class.get().unwrap_or_default())
+                on:focus=move |_| context.active_thumb.set(index)
+                on:keydown=on_keydown
+            />
+            // Hidden input for form integration
+            <input
+                type="hidden"
+                name=input_name
+                form=move || context.form.get()
+                value=move || value.get().map(|value| value.to_string())
+                disabled=move || context.disabled.get()
+            />
+        </span>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_key_steps_follow_direction() {
+        assert_eq!(
+            get_slider_key_steps("ArrowRight", false, false, false),
+            Some(1.0)
+        );
+        assert_eq!(
+            get_slider_key_steps("ArrowRight", false, false, true),
+            Some(-1.0)
+        );
+        assert_eq!(
+            get_slider_key_steps("ArrowUp", true, false, false),
+            Some(10.0)
+        );
+        assert_eq!(
+            get_slider_key_steps("ArrowDown", false, true, true),
+            Some(1.0)
+        );
+        assert_eq!(
+            get_slider_key_steps("PageDown", false, false, false),
+            Some(-10.0)
+        );
+        assert_eq!(get_slider_key_steps("Tab", false, false, false), None);
+    }
+}
@@ -7,6 +7,7 @@
 //! - ✅ Perfect styling (dark purple/black theme, focus rings)
 //! - ✅ Form integration (hidden input for form submission)
 
+use super::label::use_labelled_by;
 use crate::hooks::{use_switch_state, UseSwitchStateReturn};
 use leptos::context::Provider;
 use leptos::ev;
@@ -89,6 +90,7 @@ struct SwitchConfig {
     is_disabled: Signal<bool>,
     is_required: Signal<bool>,
     final_id: Signal<String>,
+    labelled_by: Signal<Option<String>>,
     input_value: Signal<String>,
     name: MaybeProp<String>,
     form: MaybeProp<String>,
@@ -118,6 +120,7 @@ fn SwitchView(config: SwitchConfig, children: ChildrenFn) -> impl IntoView {
                 class="absolute opacity-0 pointer-events-none"
                 style="position: absolute; opacity: 0; pointer-events: none; margin: 0; width: 1px; height: 1px;"
                 tabindex="-1"
+                aria-hidden="true"
             />
 
             <button
@@ -126,6 +129,7 @@ fn SwitchView(config: SwitchConfig, children: ChildrenFn) -> impl IntoView {
                 role="switch"
                 // ARIA attributes from our hook
                 aria-checked=move || config.switch_state.get_aria_checked.get()
+                aria-labelledby=move || config.labelled_by.get()
                 aria-disabled=move || get_aria_disabled(config.is_disabled.get())
                 aria-required=move || get_aria_required(config.is_required.get())
                 // Data attributes for Tailwind CSS styling
@@ -194,6 +198,7 @@ pub fn Switch(
         is_disabled,
         is_required,
         final_id,
+        labelled_by: use_labelled_by(final_id),
         input_value,
         name,
         form,
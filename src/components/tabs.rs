@@ -0,0 +1,387 @@
+//! Tabs Component - Layered panels of content shown one at a time
+//!
+//! Features:
+//! - ✅ Controlled/uncontrolled value (value, default_value, on_value_change)
+//! - ✅ Horizontal and vertical orientation (arrow keys follow it, RTL aware)
+//! - ✅ Automatic activation (on focus) or manual activation (Enter/Space/click)
+//! - ✅ Roving tabindex: the tab list is a single tab stop
+//! - ✅ aria-controls/aria-labelledby pairs from use_related_ids
+//! - ✅ force_mount keeps inactive panels in the DOM (hidden)
+
+use crate::hooks::{use_controllable_state, use_related_ids};
+use crate::utils::{get_roving_focus_movement, move_index, query_elements, JumpKeys, Movement};
+use leptos::context::Provider;
+use leptos::ev;
+use leptos::html::Div;
+use leptos::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::HtmlElement;
+
+/// Selector for the triggers that can receive focus
+const TAB_SELECTOR: &str = "[role='tab']:not([disabled])";
+
+/// When a tab becomes active during keyboard navigation
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum TabsActivationMode {
+    /// Moving focus to a tab activates it
+    #[default]
+    Automatic,
+    /// Focus moves freely; Enter, Space or a click activates the focused tab
+    Manual,
+}
+
+/// Helper function to generate tabs root CSS classes
+fn get_tabs_classes(user_class: String) -> String {
+    let base = "flex flex-col gap-2 data-[orientation=vertical]:flex-row";
+
+    format!("{base} {user_class}")
+}
+
+/// Helper function to generate tabs list CSS classes
+fn get_tabs_list_classes(user_class: String) -> String {
+    let base = "inline-flex shrink-0 items-center gap-1 rounded bg-white/10 p-1";
+    let vertical = "data-[orientation=vertical]:flex-col data-[orientation=vertical]:items-stretch";
+
+    format!("{base} {vertical} {user_class}")
+}
+
+/// Helper function to generate tabs trigger CSS classes
+fn get_tabs_trigger_classes(user_class: String) -> String {
+    let base = "inline-flex items-center justify-center rounded px-3 py-1.5 text-sm font-medium text-white/80 transition-colors duration-200 cursor-pointer";
+    let focus = "focus:outline-none focus:ring-2 focus:ring-black focus:ring-offset-0";
+    let states = "hover:text-white data-[state=active]:bg-white data-[state=active]:text-black data-[state=active]:shadow-sm";
+    let disabled = "data-[disabled]:opacity-50 data-[disabled]:cursor-not-allowed";
+
+    format!("{base} {focus} {states} {disabled} {user_class}")
+}
+
+/// Helper function to generate tabs content CSS classes
+fn get_tabs_content_classes(user_class: String) -> String {
+    let base = "flex-1 text-sm text-white data-[state=inactive]:hidden";
+    let focus = "focus:outline-none focus-visible:ring-2 focus-visible:ring-black";
+
+    format!("{base} {focus} {user_class}")
+}
+
+/// Movement for a navigation key in a tab list
+///
+/// Only the arrows along `orientation` move; `rtl` swaps Left and Right.
+pub fn get_tabs_movement(key: &str, orientation: &str, rtl: bool) -> Option<Movement> {
+    get_roving_focus_movement(key, Some(orientation), rtl, JumpKeys::HomeEndPage)
+}
+
+/// Element id for a trigger or panel: the related id plus the tab value
+fn make_tab_id(base: &str, value: &str) -> String {
+    let value: String = value
+        .chars()
+        .map(|c| if c.is_whitespace() { '-' } else { c })
+        .collect();
+    format!("{base}-{value}")
+}
+
+/// Context value shared between Tabs and its parts
+#[derive(Clone, Copy)]
+pub struct TabsContextValue {
+    pub value: Signal<String>,
+    pub set_value: Callback<String>,
+    pub orientation: Signal<String>,
+    pub dir: Signal<Option<String>>,
+    pub activation_mode: Signal<TabsActivationMode>,
+    /// Id prefixes for the trigger/panel pairs
+    trigger_id: Signal<String>,
+    content_id: Signal<String>,
+    /// Trigger that last had focus (the tab stop when no trigger is active)
+    focused: RwSignal<Option<String>>,
+    /// Enabled trigger values in render order
+    triggers: RwSignal<Vec<String>>,
+}
+
+impl TabsContextValue {
+    /// The single trigger reachable with Tab: the active one, else the last
+    /// focused one, else the first enabled trigger
+    fn tab_stop(&self) -> Option<String> {
+        let value = self.value.get();
+        self.triggers.with(|triggers| {
+            if triggers.contains(&value) {
+                return Some(value);
+            }
+            self.focused
+                .get()
+                .filter(|focused| triggers.contains(focused))
+                .or_else(|| triggers.first().cloned())
+        })
+    }
+
+    fn trigger_id_for(&self, value: &str) -> String {
+        make_tab_id(&self.trigger_id.get(), value)
+    }
+
+    fn content_id_for(&self, value: &str) -> String {
+        make_tab_id(&self.content_id.get(), value)
+    }
+}
+
+/// Tabs component - Hook-first implementation
+///
+/// Uses our proven hook library for state management and ARIA compliance.
+/// Styled with Tailwind CSS 4 ONLY - no custom CSS allowed.
+///
+/// # Example
+/// ```rust,ignore
+/// view! {
+///     <Tabs default_value="account" activation_mode=TabsActivationMode::Manual>
+///         <TabsList aria_label="Manage your account">
+///             <TabsTrigger value="account">"Account"</TabsTrigger>
+///             <TabsTrigger value="password">"Password"</TabsTrigger>
+///         </TabsList>
+///         <TabsContent value="account">"Make changes to your account."</TabsContent>
+///         <TabsContent value="password" force_mount=true>"Change your password."</TabsContent>
+///     </Tabs>
+/// }
+/// ```
+#[component]
+pub fn Tabs(
+    // Core state management (from our hook library)
+    #[prop(into, optional)] value: MaybeProp<String>,
+    #[prop(into, optional)] default_value: MaybeProp<String>,
+    #[prop(into, optional)] on_value_change: Option<Callback<String>>,
+
+    // Keyboard navigation
+    /// "horizontal" (default) or "vertical"
+    #[prop(into, optional)]
+    orientation: MaybeProp<String>,
+    /// Reading direction ("ltr" or "rtl") for Left/Right
+    #[prop(into, optional)]
+    dir: MaybeProp<String>,
+    /// Whether focusing a tab activates it (default: automatic)
+    #[prop(into, optional)]
+    activation_mode: MaybeProp<TabsActivationMode>,
+
+    // Styling
+    #[prop(into, optional)] class: MaybeProp<String>,
+
+    children: Children,
+) -> impl IntoView {
+    // Phase 0: Compose hooks - no manual state management!
+    let state = use_controllable_state(value, default_value, on_value_change);
+    let ids = use_related_ids("tabs");
+
+    let current_orientation = Signal::derive(move || {
+        orientation
+            .get()
+            .unwrap_or_else(|| "horizontal".to_string())
+    });
+
+    // Context for child components
+    let context_value = TabsContextValue {
+        value: state.value,
+        set_value: state.set_value,
+        orientation: current_orientation,
+        dir: Signal::derive(move || dir.get()),
+        activation_mode: Signal::derive(move || activation_mode.get().unwrap_or_default()),
+        trigger_id: ids.trigger_id,
+        content_id: ids.content_id,
+        focused: RwSignal::new(None),
+        triggers: RwSignal::new(Vec::new()),
+    };
+
+    view! {
+        <Provider value=context_value>
+            <div
+                id=move || ids.base_id.get()
+                dir=move || dir.get()
+                data-orientation=move || current_orientation.get()
+                // 🚨 TAILWIND CSS 4 ONLY - Layout follows the orientation
+                class=move || get_tabs_classes(class.get().unwrap_or_default())
+            >
+                {children()}
+            </div>
+        </Provider>
+    }
+}
+
+/// TabsList - The tablist holding the triggers
+#[component]
+pub fn TabsList(
+    /// Wrap around at the ends (default: true)
+    #[prop(into, optional)]
+    loop_focus: MaybeProp<bool>,
+
+    // Accessibility & DOM
+    #[prop(into, optional)] aria_label: MaybeProp<String>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+
+    children: Children,
+) -> impl IntoView {
+    let context = expect_context::<TabsContextValue>();
+    let list_ref = NodeRef::<Div>::new();
+
+    // Arrow keys move focus between the triggers (activation happens on focus)
+    let on_keydown = move |ev: ev::KeyboardEvent| {
+        let rtl = context.dir.get_untracked().as_deref() == Some("rtl");
+        let Some(movement) =
+            get_tabs_movement(&ev.key(), &context.orientation.get_untracked(), rtl)
+        else {
+            return;
+        };
+        let Some(list) = list_ref.get_untracked() else {
+            return;
+        };
+        let triggers: Vec<HtmlElement> = query_elements(&list, TAB_SELECTOR)
+            .into_iter()
+            .filter(|trigger| {
+                trigger
+                    .closest("[role='tablist']")
+                    .ok()
+                    .flatten()
+                    .is_some_and(|owner| owner == **list)
+            })
+            .collect();
+        let current = ev
+            .target()
+            .and_then(|target| target.dyn_into::<HtmlElement>().ok())
+            .and_then(|target| triggers.iter().position(|trigger| *trigger == target));
+
+        ev.prevent_default();
+        let looping = loop_focus.get_untracked().unwrap_or(true);
+        if let Some(next) = move_index(current, triggers.len(), movement, looping)
+            .and_then(|index| triggers.get(index))
+        {
+            let _ = next.focus();
+        }
+    };
+
+    view! {
+        <div
+            node_ref=list_ref
+            role="tablist"
+            aria-label=move || aria_label.get()
+            aria-orientation=move || context.orientation.get()
+            data-orientation=move || context.orientation.get()
+            // 🚨 TAILWIND CSS 4 ONLY - Professional list styling
+            class=move || get_tabs_list_classes(class.get().unwrap_or_default())
+            on:keydown=on_keydown
+        >
+            {children()}
+        </div>
+    }
+}
+
+/// TabsTrigger - The tab that activates its panel
+#[component]
+pub fn TabsTrigger(
+    #[prop(into)] value: String,
+    #[prop(into, optional)] disabled: MaybeProp<bool>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+
+    children: Children,
+) -> impl IntoView {
+    let context = expect_context::<TabsContextValue>();
+    let trigger_value = StoredValue::new(value);
+
+    // Pre-compute common values to reduce complexity
+    let is_disabled = Signal::derive(move || disabled.get().unwrap_or(false));
+    let is_active =
+        Signal::derive(move || trigger_value.with_value(|value| context.value.get() == *value));
+    let is_tab_stop = Signal::derive(move || {
+        trigger_value.with_value(|value| context.tab_stop().as_ref() == Some(value))
+    });
+
+    // Register enabled triggers for the roving tab stop (during render too,
+    // so the server-rendered HTML already has one tab stop)
+    if !is_disabled.get_untracked() {
+        context
+            .triggers
+            .update(|triggers| triggers.push(trigger_value.get_value()));
+    }
+    Effect::new(move |_| {
+        let enabled = !is_disabled.get();
+        let value = trigger_value.get_value();
+        context.triggers.update(|triggers| {
+            let registered = triggers.contains(&value);
+            if enabled && !registered {
+                triggers.push(value);
+            } else if !enabled && registered {
+                triggers.retain(|trigger| *trigger != value);
+            }
+        });
+    });
+    on_cleanup(move || {
+        context.triggers.try_update(|triggers| {
+            trigger_value.with_value(|value| triggers.retain(|trigger| trigger != value))
+        });
+    });
+
+    let activate = move || {
+        if !is_disabled.get_untracked() && !is_active.get_untracked() {
+            context.set_value.run(trigger_value.get_value());
+        }
+    };
+
+    view! {
+        <button
+            type="button"
+            role="tab"
+            id=move || trigger_value.with_value(|value| context.trigger_id_for(value))
+            aria-selected=move || if is_active.get() { "true" } else { "false" }
+            aria-controls=move || trigger_value.with_value(|value| context.content_id_for(value))
+            // Roving tabindex: one tab stop for the whole list
+            tabindex=move || if is_tab_stop.get() { "0" } else { "-1" }
+            // Data attributes for Tailwind CSS 4 styling
+            data-state=move || if is_active.get() { "active" } else { "inactive" }
+            data-orientation=move || context.orientation.get()
+            data-disabled=move || if is_disabled.get() { Some("") } else { None }
+            disabled=move || is_disabled.get()
+            // 🚨 TAILWIND CSS 4 ONLY - Professional trigger styling
+            class=move || get_tabs_trigger_classes(class.get().unwrap_or_default())
+            on:focus=move |_| {
+                context.focused.set(Some(trigger_value.get_value()));
+                if context.activation_mode.get_untracked() == TabsActivationMode::Automatic {
+                    activate();
+                }
+            }
+            // Enter and Space click the button, which covers manual activation
+            on:click=move |_| activate()
+        >
+            {children()}
+        </button>
+    }
+}
+
+/// TabsContent - The panel shown while its trigger is active
+#[component]
+pub fn TabsContent(
+    #[prop(into)] value: String,
+    /// Keep the panel in the DOM (hidden) while inactive
+    #[prop(into, optional)]
+    force_mount: MaybeProp<bool>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+
+    children: ChildrenFn,
+) -> impl IntoView {
+    let context = expect_context::<TabsContextValue>();
+    let content_value = StoredValue::new(value);
+
+    let is_active =
+        Signal::derive(move || content_value.with_value(|value| context.value.get() == *value));
+    let is_present = Signal::derive(move || force_mount.get().unwrap_or(false) || is_active.get());
+
+    view! {
+        <Show when=move || is_present.get()>
+            <div
+                role="tabpanel"
+                id=move || content_value.with_value(|value| context.content_id_for(value))
+                aria-labelledby=move || content_value.with_value(|value| context.trigger_id_for(value))
+                hidden=move || !is_active.get()
+                tabindex="0"
+                // Data attributes for Tailwind CSS 4 styling
+                data-state=move || if is_active.get() { "active" } else { "inactive" }
+                data-orientation=move || context.orientation.get()
+                // 🚨 TAILWIND CSS 4 ONLY - Professional panel styling
+                class=move || get_tabs_content_classes(class.get().unwrap_or_default())
+            >
+                {children()}
+            </div>
+        </Show>
+    }
+}
@@ -0,0 +1,790 @@
+//! Toast Component - Brief notifications that dismiss themselves
+//!
+//! Features:
+//! - ✅ ToastProvider + ToastViewport: toasts render into the viewport from anywhere
+//! - ✅ Controlled/uncontrolled open state (open, default_open, on_open_change)
+//! - ✅ Timed auto-dismiss, paused on hover, focus and window blur
+//! - ✅ Swipe to dismiss in a configurable direction (data-swipe, --toast-swipe-* variables)
+//! - ✅ F8 (configurable) focuses the viewport; Escape dismisses the focused toast
+//! - ✅ Announced through polite/assertive live regions
+//! - ✅ Imperative use_toast() to push toasts from any component
+
+use crate::hooks::{use_controllable_state, use_presence};
+use crate::utils::query_elements;
+use leptos::context::Provider;
+use leptos::ev;
+use leptos::html::{Li, Ol};
+use leptos::portal::Portal;
+use leptos::prelude::*;
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement, Node};
+
+/// Swipe distance before a swipe starts moving the toast (lets clicks through)
+const SWIPE_START_TOLERANCE: f64 = 5.0;
+
+/// Direction a toast can be swiped away in
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum ToastSwipeDirection {
+    Up,
+    Down,
+    Left,
+    #[default]
+    Right,
+}
+
+impl Display for ToastSwipeDirection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                ToastSwipeDirection::Up => "up",
+                ToastSwipeDirection::Down => "down",
+                ToastSwipeDirection::Left => "left",
+                ToastSwipeDirection::Right => "right",
+            }
+        )
+    }
+}
+
+/// Pointer movement kept along the swipe direction; movement the other way is dropped
+pub(crate) fn clamp_swipe_delta(direction: ToastSwipeDirection, dx: f64, dy: f64) -> (f64, f64) {
+    match direction {
+        ToastSwipeDirection::Right => (dx.max(0.0), 0.0),
+        ToastSwipeDirection::Left => (dx.min(0.0), 0.0),
+        ToastSwipeDirection::Down => (0.0, dy.max(0.0)),
+        ToastSwipeDirection::Up => (0.0, dy.min(0.0)),
+    }
+}
+
+/// Whether a (clamped) swipe went far enough to dismiss the toast
+pub(crate) fn is_swipe_dismiss(delta: (f64, f64), threshold: f64) -> bool {
+    delta.0.abs().max(delta.1.abs()) >= threshold
+}
+
+/// Helper function to generate toast viewport CSS classes
+fn get_toast_viewport_classes(user_class: String) -> String {
+    let base = "fixed bottom-0 right-0 z-[100] m-0 flex w-96 max-w-[100vw] list-none flex-col gap-2.5 p-6 outline-none";
+
+    format!("{base} {user_class}")
+}
+
+/// Helper function to generate toast CSS classes
+fn get_toast_classes(user_class: String) -> String {
+    let base = "grid grid-cols-[auto_max-content] items-center gap-x-4 rounded-md border border-white/10 bg-zinc-900 p-4 text-white shadow-lg";
+    let focus = "focus:outline-none focus-visible:ring-2 focus-visible:ring-white/60";
+    let animation = "data-[state=open]:animate-fade-in data-[state=closed]:animate-fade-out";
+    let swipe = "data-[swipe=move]:translate-x-[var(--toast-swipe-move-x)] data-[swipe=move]:translate-y-[var(--toast-swipe-move-y)] data-[swipe=cancel]:translate-x-0 data-[swipe=cancel]:translate-y-0 data-[swipe=cancel]:transition-transform data-[swipe=end]:translate-x-[var(--toast-swipe-end-x)] data-[swipe=end]:translate-y-[var(--toast-swipe-end-y)]";
+
+    format!("{base} {focus} {animation} {swipe} {user_class}")
+}
+
+/// Helper function to generate toast title CSS classes
+fn get_toast_title_classes(user_class: String) -> String {
+    let base = "col-start-1 text-sm font-medium";
+
+    format!("{base} {user_class}")
+}
+
+/// Helper function to generate toast description CSS classes
+fn get_toast_description_classes(user_class: String) -> String {
+    let base = "col-start-1 text-sm text-white/70";
+
+    format!("{base} {user_class}")
+}
+
+/// Helper function to generate toast action/close CSS classes
+fn get_toast_button_classes(user_class: String) -> String {
+    let base = "col-start-2 row-span-2 inline-flex h-8 items-center justify-center rounded px-3 text-sm font-medium text-white cursor-pointer transition-colors duration-200 hover:bg-white/10";
+    let focus = "focus:outline-none focus-visible:ring-2 focus-visible:ring-white/60";
+
+    format!("{base} {focus} {user_class}")
+}
+
+fn now() -> f64 {
+    window()
+        .performance()
+        .map(|performance| performance.now())
+        .unwrap_or(0.0)
+}
+
+fn clear_timer(timer: StoredValue<Option<TimeoutHandle>>) {
+    if let Some(handle) = timer.try_update_value(Option::take).flatten() {
+        handle.clear();
+    }
+}
+
+/// Text read out for a toast: buttons marked with an alternative text are
+/// replaced by it, the close button is left out
+fn get_announce_text(toast: &HtmlElement) -> String {
+    let Some(copy) = toast
+        .clone_node_with_deep(true)
+        .ok()
+        .and_then(|copy| copy.dyn_into::<Element>().ok())
+    else {
+        return String::new();
+    };
+    for excluded in query_elements(&copy, "[data-toast-announce-exclude]") {
+        excluded.remove();
+    }
+    for action in query_elements(&copy, "[data-toast-announce-alt]") {
+        let alt = action.get_attribute("data-toast-announce-alt");
+        action.set_text_content(alt.as_deref());
+    }
+    copy.text_content()
+        .unwrap_or_default()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Action button shown on a toast pushed with use_toast()
+#[derive(Clone)]
+pub struct ToastActionOptions {
+    pub label: String,
+    /// How screen reader users can perform the action elsewhere
+    pub alt_text: String,
+    pub on_click: Callback<()>,
+}
+
+/// Content of a toast pushed with use_toast()
+#[derive(Clone, Default)]
+pub struct ToastOptions {
+    pub title: String,
+    pub description: Option<String>,
+    /// Milliseconds before dismissing (default: the provider's duration)
+    pub duration: Option<u32>,
+    /// Announce politely instead of assertively
+    pub background: bool,
+    pub action: Option<ToastActionOptions>,
+}
+
+/// A toast pushed with use_toast()
+#[derive(Clone)]
+struct QueuedToast {
+    id: usize,
+    options: ToastOptions,
+    open: RwSignal<bool>,
+}
+
+/// Context value shared between ToastProvider, the viewport and the toasts
+#[derive(Clone, Copy)]
+pub struct ToastProviderContextValue {
+    /// Prefix of every announcement (default: "Notification")
+    pub label: Signal<String>,
+    /// Default milliseconds before a toast dismisses itself
+    pub duration: Signal<u32>,
+    pub swipe_direction: Signal<ToastSwipeDirection>,
+    /// Swipe distance in pixels that dismisses a toast
+    pub swipe_threshold: Signal<f64>,
+    /// Timers are paused (viewport hovered or focused, window blurred)
+    pub paused: Signal<bool>,
+    viewport_ref: NodeRef<Ol>,
+    hovered: RwSignal<bool>,
+    focused: RwSignal<bool>,
+    polite_announcement: RwSignal<String>,
+    assertive_announcement: RwSignal<String>,
+    queue: RwSignal<Vec<QueuedToast>>,
+    next_id: StoredValue<usize>,
+}
+
+impl ToastProviderContextValue {
+    fn announce(&self, text: String, assertive: bool) {
+        let region = if assertive {
+            self.assertive_announcement
+        } else {
+            self.polite_announcement
+        };
+        // Empty the region first so a repeated message is read again
+        region.set(String::new());
+        request_animation_frame(move || {
+            region.try_set(text);
+        });
+    }
+
+    fn focus_viewport(&self) {
+        if let Some(viewport) = self.viewport_ref.get_untracked() {
+            let _ = viewport.focus();
+        }
+    }
+}
+
+/// ToastProvider component - Hook-first implementation
+///
+/// Uses our proven hook library for state management and ARIA compliance.
+/// Styled with Tailwind CSS 4 ONLY - no custom CSS allowed.
+///
+/// Wrap the app once and place a single ToastViewport inside it.
+///
+/// # Example
+/// ```rust,ignore
+/// view! {
+///     <ToastProvider swipe_direction=ToastSwipeDirection::Right>
+///         <App />
+///         <ToastViewport />
+///     </ToastProvider>
+/// }
+/// ```
+#[component]
+pub fn ToastProvider(
+    /// Prefix of every announcement (default: "Notification")
+    #[prop(into, optional)]
+    label: MaybeProp<String>,
+    /// Milliseconds before a toast dismisses itself (default: 5000)
+    #[prop(into, optional)]
+    duration: MaybeProp<u32>,
+    /// Direction that dismisses a toast (default: right)
+    #[prop(into, optional)]
+    swipe_direction: MaybeProp<ToastSwipeDirection>,
+    /// Pixels a swipe must travel to dismiss (default: 50)
+    #[prop(into, optional)]
+    swipe_threshold: MaybeProp<f64>,
+
+    children: Children,
+) -> impl IntoView {
+    let hovered = RwSignal::new(false);
+    let focused = RwSignal::new(false);
+    let window_blurred = RwSignal::new(false);
+
+    // Context for child components
+    let context_value = ToastProviderContextValue {
+        label: Signal::derive(move || label.get().unwrap_or_else(|| "Notification".to_string())),
+        duration: Signal::derive(move || duration.get().unwrap_or(5000)),
+        swipe_direction: Signal::derive(move || swipe_direction.get().unwrap_or_default()),
+        swipe_threshold: Signal::derive(move || swipe_threshold.get().unwrap_or(50.0)),
+        paused: Signal::derive(move || hovered.get() || focused.get() || window_blurred.get()),
+        viewport_ref: NodeRef::new(),
+        hovered,
+        focused,
+        polite_announcement: RwSignal::new(String::new()),
+        assertive_announcement: RwSignal::new(String::new()),
+        queue: RwSignal::new(Vec::new()),
+        next_id: StoredValue::new(0),
+    };
+
+    // Timers stop while the user is in another window
+    Effect::new(move |_| {
+        let blur = window_event_listener(ev::blur, move |_| window_blurred.set(true));
+        let focus = window_event_listener(ev::focus, move |_| window_blurred.set(false));
+        on_cleanup(move || {
+            blur.remove();
+            focus.remove();
+        });
+    });
+
+    view! {
+        <Provider value=context_value>
+            {children()}
+            <For
+                each=move || context_value.queue.get()
+                key=|toast| toast.id
+                children=|toast| view! { <QueuedToastView toast=toast /> }
+            />
+            // Live regions exist before any toast so announcements are picked up
+            <div role="status" aria-live="polite" aria-atomic="true" class="sr-only">
+                {move || context_value.polite_announcement.get()}
+            </div>
+            <div role="alert" aria-live="assertive" aria-atomic="true" class="sr-only">
+                {move || context_value.assertive_announcement.get()}
+            </div>
+        </Provider>
+    }
+}
+
+/// Renders a toast pushed with use_toast()
+#[component]
+fn QueuedToastView(toast: QueuedToast) -> impl IntoView {
+    let context = expect_context::<ToastProviderContextValue>();
+    let QueuedToast { id, options, open } = toast;
+    let duration = options.duration;
+    let background = options.background;
+    let options = StoredValue::new(options);
+
+    view! {
+        <Toast
+            open=open
+            on_open_change=Callback::new(move |next| open.set(next))
+            // Leave the queue only once the exit animation is over
+            on_exit_complete=Callback::new(move |_| {
+                context.queue.try_update(|queue| queue.retain(|toast| toast.id != id));
+            })
+            duration=duration
+            r#type=if background { "background" } else { "foreground" }
+        >
+            <ToastTitle>{options.with_value(|options| options.title.clone())}</ToastTitle>
+            {options
+                .with_value(|options| options.description.clone())
+                .map(|description| view! { <ToastDescription>{description}</ToastDescription> })}
+            {options
+                .with_value(|options| options.action.clone())
+                .map(|action| {
+                    view! {
+                        <ToastAction alt_text=action.alt_text on_click=action.on_click>
+                            {action.label}
+                        </ToastAction>
+                    }
+                })}
+            <ToastClose />
+        </Toast>
+    }
+}
+
+/// Return type for use_toast
+#[derive(Clone, Copy)]
+pub struct UseToastReturn {
+    /// Show a toast; returns its id
+    pub toast: Callback<ToastOptions, usize>,
+    /// Dismiss a toast by id
+    pub dismiss: Callback<usize>,
+}
+
+/// Push toasts from any component below a ToastProvider
+///
+/// # Example
+/// ```rust,ignore
+/// let toasts = use_toast();
+///
+/// toasts.toast.run(ToastOptions {
+///     title: "Saved".to_string(),
+///     description: Some("Your changes are stored.".to_string()),
+///     ..Default::default()
+/// });
+/// ```
+pub fn use_toast() -> UseToastReturn {
+    let context = expect_context::<ToastProviderContextValue>();
+
+    let toast = Callback::new(move |options: ToastOptions| {
+        let id = context.next_id.get_value();
+        context.next_id.set_value(id + 1);
+        context.queue.update(|queue| {
+            queue.push(QueuedToast {
+                id,
+                options,
+                open: RwSignal::new(true),
+            });
+        });
+        id
+    });
+
+    let dismiss = Callback::new(move |id: usize| {
+        context.queue.with_untracked(|queue| {
+            if let Some(toast) = queue.iter().find(|toast| toast.id == id) {
+                toast.open.set(false);
+            }
+        });
+    });
+
+    UseToastReturn { toast, dismiss }
+}
+
+/// ToastViewport - Fixed list the toasts render into
+///
+/// A labelled landmark; the hotkey (default: F8) moves focus to it.
+#[component]
+pub fn ToastViewport(
+    /// `KeyboardEvent.code` that focuses the viewport (default: "F8")
+    #[prop(into, optional)]
+    hotkey: MaybeProp<String>,
+    /// Landmark label; `{hotkey}` is replaced (default: "Notifications ({hotkey})")
+    #[prop(into, optional)]
+    label: MaybeProp<String>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+) -> impl IntoView {
+    let context = expect_context::<ToastProviderContextValue>();
+    let hotkey = Signal::derive(move || hotkey.get().unwrap_or_else(|| "F8".to_string()));
+
+    Effect::new(move |_| {
+        let handle = window_event_listener(ev::keydown, move |ev| {
+            if ev.code() == hotkey.get_untracked() {
+                context.focus_viewport();
+            }
+        });
+        on_cleanup(move || handle.remove());
+    });
+
+    let aria_label = move || {
+        label
+            .get()
+            .unwrap_or_else(|| "Notifications ({hotkey})".to_string())
+            .replace("{hotkey}", &hotkey.get())
+    };
+
+    let on_focusout = move |ev: ev::FocusEvent| {
+        let next = ev
+            .related_target()
+            .and_then(|target| target.dyn_into::<Node>().ok());
+        let inside = context
+            .viewport_ref
+            .get_untracked()
+            .is_some_and(|viewport| viewport.contains(next.as_ref()));
+        if !inside {
+            context.focused.set(false);
+        }
+    };
+
+    view! {
+        <div role="region" aria-label=aria_label tabindex="-1">
+            <ol
+                node_ref=context.viewport_ref
+                tabindex="-1"
+                // 🚨 TAILWIND CSS 4 ONLY - Fixed bottom-right stack
+                class=move || get_toast_viewport_classes(class.get().unwrap_or_default())
+                on:pointerenter=move |_| context.hovered.set(true)
+                on:pointerleave=move |_| context.hovered.set(false)
+                on:focusin=move |_| context.focused.set(true)
+                on:focusout=on_focusout
+            />
+        </div>
+    }
+}
+
+/// Context value shared between a Toast and its parts
+#[derive(Clone, Copy)]
+pub struct ToastContextValue {
+    pub close: Callback<()>,
+}
+
+/// Toast - One notification, rendered into the ToastViewport
+#[component]
+pub fn Toast(
+    // Core state management (from our hook library)
+    #[prop(into, optional)] open: MaybeProp<bool>,
+    #[prop(into, optional)] default_open: MaybeProp<bool>,
+    #[prop(into, optional)] on_open_change: Option<Callback<bool>>,
+    /// Called once the toast has closed and its exit animation ended
+    #[prop(into, optional)]
+    on_exit_complete: Option<Callback<()>>,
+
+    /// Milliseconds before dismissing (default: the provider's duration)
+    #[prop(into, optional)]
+    duration: MaybeProp<u32>,
+    /// "foreground" (default, announced assertively) or "background" (politely)
+    #[prop(into, optional)]
+    r#type: MaybeProp<String>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+
+    children: ChildrenFn,
+) -> impl IntoView {
+    let context = expect_context::<ToastProviderContextValue>();
+    // Phase 0: Compose hooks - no manual state management!
+    let state = use_controllable_state(
+        open,
+        default_open.get_untracked().unwrap_or(true).into(),
+        on_open_change,
+    );
+    let toast_ref = NodeRef::<Li>::new();
+    let presence = use_presence(state.value, toast_ref);
+
+    Effect::new(move |was_present: Option<bool>| {
+        let is_present = presence.is_present.get();
+        if was_present == Some(true) && !is_present {
+            if let Some(on_exit_complete) = on_exit_complete {
+                on_exit_complete.run(());
+            }
+        }
+        is_present
+    });
+
+    // Swipe state
+    let swipe = RwSignal::new(None::<&'static str>);
+    let swipe_start = StoredValue::new(None::<(f64, f64)>);
+    let swipe_delta = StoredValue::new((0.0, 0.0));
+
+    let close = Callback::new(move |_: ()| {
+        // Keep keyboard users inside the viewport
+        let had_focus = toast_ref.get_untracked().is_some_and(|toast| {
+            let active = document().active_element().map(Node::from);
+            toast.contains(active.as_ref())
+        });
+        if had_focus {
+            context.focus_viewport();
+        }
+        state.set_value.run(false);
+    });
+
+    // Auto-dismiss timer, keeping the remaining time across pauses
+    let timer = StoredValue::new(None::<TimeoutHandle>);
+    let remaining = StoredValue::new(0.0_f64);
+    let started_at = StoredValue::new(None::<f64>);
+    let was_open = StoredValue::new(false);
+    Effect::new(move |_| {
+        let is_open = state.value.get();
+        let is_paused = context.paused.get() || swipe.get() == Some("move");
+        let duration = duration.get().unwrap_or_else(|| context.duration.get());
+        clear_timer(timer);
+        if !is_open {
+            was_open.set_value(false);
+            started_at.set_value(None);
+            return;
+        }
+        if !was_open.get_value() {
+            was_open.set_value(true);
+            remaining.set_value(f64::from(duration));
+            swipe.set(None);
+        }
+        if let Some(start) = started_at.try_update_value(Option::take).flatten() {
+            remaining.update_value(|remaining| *remaining -= now() - start);
+        }
+        if is_paused {
+            return;
+        }
+        started_at.set_value(Some(now()));
+        let delay = Duration::from_millis(remaining.get_value().max(0.0) as u64);
+        timer.set_value(set_timeout_with_handle(move || close.run(()), delay).ok());
+    });
+    on_cleanup(move || clear_timer(timer));
+
+    // Announce once the toast is in the DOM
+    let is_assertive = Signal::derive(move || r#type.get().as_deref() != Some("background"));
+    Effect::new(move |_| {
+        if !state.value.get() {
+            return;
+        }
+        let Some(toast) = toast_ref.get() else {
+            return;
+        };
+        let label = context.label.get_untracked();
+        let assertive = is_assertive.get_untracked();
+        request_animation_frame(move || {
+            let text = get_announce_text(&toast);
+            context.announce(format!("{label} {text}"), assertive);
+        });
+    });
+
+    // Render after hydration, once the viewport exists to portal into
+    let is_mounted = RwSignal::new(false);
+    Effect::new(move |_| is_mounted.set(true));
+
+    let set_swipe_property = move |name: &str, delta: (f64, f64)| {
+        if let Some(toast) = toast_ref.get_untracked() {
+            let style = HtmlElement::style(&toast);
+            let _ = style.set_property(
+                &format!("--toast-swipe-{name}-x"),
+                &format!("{}px", delta.0),
+            );
+            let _ = style.set_property(
+                &format!("--toast-swipe-{name}-y"),
+                &format!("{}px", delta.1),
+            );
+        }
+    };
+
+    let on_pointerdown = move |ev: ev::PointerEvent| {
+        if ev.button() == 0 {
+            swipe_start.set_value(Some((f64::from(ev.client_x()), f64::from(ev.client_y()))));
+        }
+    };
+    let on_pointermove = move |ev: ev::PointerEvent| {
+        let Some((x, y)) = swipe_start.get_value() else {
+            return;
+        };
+        let delta = clamp_swipe_delta(
+            context.swipe_direction.get_untracked(),
+            f64::from(ev.client_x()) - x,
+            f64::from(ev.client_y()) - y,
+        );
+        swipe_delta.set_value(delta);
+        let is_moving = swipe.get_untracked() == Some("move");
+        if !is_moving && !is_swipe_dismiss(delta, SWIPE_START_TOLERANCE) {
+            return;
+        }
+        if !is_moving {
+            if let Some(toast) = toast_ref.get_untracked() {
+                let _ = toast.set_pointer_capture(ev.pointer_id());
+            }
+            swipe.set(Some("move"));
+        }
+        set_swipe_property("move", delta);
+    };
+    let on_pointerup = move |ev: ev::PointerEvent| {
+        if swipe_start
+            .try_update_value(Option::take)
+            .flatten()
+            .is_none()
+        {
+            return;
+        }
+        if swipe.get_untracked() != Some("move") {
+            return;
+        }
+        if let Some(toast) = toast_ref.get_untracked() {
+            let _ = toast.release_pointer_capture(ev.pointer_id());
+        }
+        let delta = swipe_delta.get_value();
+        if is_swipe_dismiss(delta, context.swipe_threshold.get_untracked()) {
+            set_swipe_property("end", delta);
+            swipe.set(Some("end"));
+            close.run(());
+        } else {
+            swipe.set(Some("cancel"));
+        }
+    };
+    // A swipe ends with a click on the toast; don't let it reach buttons
+    let on_click_capture = move |ev: ev::MouseEvent| {
+        if swipe.get_untracked() == Some("end") {
+            ev.stop_propagation();
+            ev.prevent_default();
+        }
+    };
+
+    let toast_context = ToastContextValue { close };
+    let children = StoredValue::new(children);
+
+    let content = move || {
+        view! {
+            <li
+                node_ref=toast_ref
+                role="status"
+                aria-live="off"
+                aria-atomic="true"
+                tabindex="0"
+                // Data attributes for Tailwind CSS 4 styling
+                data-state=move || if state.value.get() { "open" } else { "closed" }
+                data-swipe=move || swipe.get()
+                data-swipe-direction=move || context.swipe_direction.get().to_string()
+                // 🚨 TAILWIND CSS 4 ONLY - Swipe offsets come from the CSS variables
+                class=move || get_toast_classes(class.get().unwrap_or_default())
+                on:keydown=move |ev| {
+                    if ev.key() == "Escape" {
+                        close.run(());
+                    }
+                }
+                on:pointerdown=on_pointerdown
+                on:pointermove=on_pointermove
+                on:pointerup=on_pointerup
+                on:pointercancel=on_pointerup
+                on:click:capture=on_click_capture
+                on:animationend=move |ev| presence.on_animation_end.run(ev)
+            >
+                {children.read_value()()}
+            </li>
+        }
+    };
+
+    view! {
+        <Provider value=toast_context>
+            <Show when=move || is_mounted.get() && presence.is_present.get()>
+                {move || {
+                    let viewport: Option<Element> = context
+                        .viewport_ref
+                        .get_untracked()
+                        .map(|viewport| viewport.unchecked_into());
+                    viewport.map(|viewport| view! { <Portal mount=viewport>{content()}</Portal> })
+                }}
+            </Show>
+        </Provider>
+    }
+}
+
+/// ToastTitle - Short summary of the notification
+#[component]
+pub fn ToastTitle(
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: Children,
+) -> impl IntoView {
+    view! {
+        // 🚨 TAILWIND CSS 4 ONLY - Title row
+        <div class=move || get_toast_title_classes(class.get().unwrap_or_default())>
+            {children()}
+        </div>
+    }
+}
+
+/// ToastDescription - Supporting text
+#[component]
+pub fn ToastDescription(
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: Children,
+) -> impl IntoView {
+    view! {
+        // 🚨 TAILWIND CSS 4 ONLY - Muted supporting text
+        <div class=move || get_toast_description_classes(class.get().unwrap_or_default())>
+            {children()}
+        </div>
+    }
+}
+
+/// ToastAction - Button that acts and dismisses the toast
+///
+/// Toasts disappear on their own, so `alt_text` tells screen reader users how
+/// to do the same thing elsewhere; it replaces the button in the announcement.
+#[component]
+pub fn ToastAction(
+    #[prop(into)] alt_text: String,
+    #[prop(into, optional)] on_click: Option<Callback<()>>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+
+    children: Children,
+) -> impl IntoView {
+    let toast = expect_context::<ToastContextValue>();
+
+    view! {
+        <button
+            type="button"
+            data-toast-announce-alt=alt_text
+            // 🚨 TAILWIND CSS 4 ONLY - Compact action button
+            class=move || get_toast_button_classes(class.get().unwrap_or_default())
+            on:click=move |_| {
+                if let Some(on_click) = on_click {
+                    on_click.run(());
+                }
+                toast.close.run(());
+            }
+        >
+            {children()}
+        </button>
+    }
+}
+
+/// ToastClose - Button that dismisses the toast (left out of announcements)
+#[component]
+pub fn ToastClose(
+    /// Accessible name (default: "Close")
+    #[prop(into, optional)]
+    aria_label: MaybeProp<String>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+
+    #[prop(optional)] children: Option<Children>,
+) -> impl IntoView {
+    let toast = expect_context::<ToastContextValue>();
+
+    view! {
+        <button
+            type="button"
+            data-toast-announce-exclude=""
+            aria-label=move || aria_label.get().unwrap_or_else(|| "Close".to_string())
+            // 🚨 TAILWIND CSS 4 ONLY - Compact close button
+            class=move || get_toast_button_classes(class.get().unwrap_or_default())
+            on:click=move |_| toast.close.run(())
+        >
+            {children.map(|children| children().into_any()).unwrap_or_else(|| "✕".into_any())}
+        </button>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_swipe_only_follows_the_swipe_direction() {
+        use ToastSwipeDirection::*;
+
+        assert_eq!(clamp_swipe_delta(Right, 30.0, 12.0), (30.0, 0.0));
+        assert_eq!(clamp_swipe_delta(Right, -30.0, 12.0), (0.0, 0.0));
+        assert_eq!(clamp_swipe_delta(Left, -30.0, 12.0), (-30.0, 0.0));
+        assert_eq!(clamp_swipe_delta(Down, 5.0, 40.0), (0.0, 40.0));
+        assert_eq!(clamp_swipe_delta(Up, 5.0, 40.0), (0.0, 0.0));
+        assert_eq!(ToastSwipeDirection::default(), Right);
+        assert_eq!(Up.to_string(), "up");
+    }
+
+    #[test]
+    fn test_swipe_dismisses_past_threshold() {
+        assert!(is_swipe_dismiss((50.0, 0.0), 50.0));
+        assert!(is_swipe_dismiss((0.0, -80.0), 50.0));
+        assert!(!is_swipe_dismiss((49.0, 0.0), 50.0));
+    }
+}
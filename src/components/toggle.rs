@@ -0,0 +1,75 @@
+//! Toggle Component - A two-state button that can be on or off
+//!
+//! Features:
+//! - ✅ Controlled/uncontrolled pressed state (pressed, default_pressed, on_pressed_change)
+//! - ✅ aria-pressed and data-state="on|off" from use_toggle_state
+//! - ✅ Native button keyboard behavior (Enter/Space)
+
+use crate::hooks::use_toggle_state;
+use leptos::prelude::*;
+
+/// Helper function to generate toggle CSS classes (shared with ToggleGroupItem)
+pub(crate) fn get_toggle_classes(user_class: String) -> String {
+    let base = "inline-flex h-9 min-w-9 items-center justify-center gap-2 rounded px-2 text-sm font-medium text-white transition-colors duration-200 cursor-pointer";
+    let focus = "focus:outline-none focus:ring-2 focus:ring-black focus:ring-offset-0";
+    let states = "hover:bg-white/10 data-[state=on]:bg-white data-[state=on]:text-black";
+    let disabled = "data-[disabled]:opacity-50 data-[disabled]:cursor-not-allowed data-[disabled]:hover:bg-transparent";
+
+    format!("{base} {focus} {states} {disabled} {user_class}")
+}
+
+/// Toggle component - Hook-first implementation
+///
+/// Uses our proven hook library for state management and ARIA compliance.
+/// Styled with Tailwind CSS 4 ONLY - no custom CSS allowed.
+///
+/// # Example
+/// ```rust,ignore
+/// view! {
+///     <Toggle aria_label="Toggle bold" default_pressed=true>
+///         <strong>"B"</strong>
+///     </Toggle>
+/// }
+/// ```
+#[component]
+pub fn Toggle(
+    // Core state management (from our hook library)
+    #[prop(into, optional)] pressed: MaybeProp<bool>,
+    #[prop(into, optional)] default_pressed: MaybeProp<bool>,
+    #[prop(into, optional)] on_pressed_change: Option<Callback<bool>>,
+
+    #[prop(into, optional)] disabled: MaybeProp<bool>,
+
+    // Accessibility & DOM
+    #[prop(into, optional)] aria_label: MaybeProp<String>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+
+    children: Children,
+) -> impl IntoView {
+    // Compose hooks - no manual state management!
+    let toggle_state = use_toggle_state(pressed, default_pressed, on_pressed_change);
+
+    let is_disabled = Signal::derive(move || disabled.get().unwrap_or(false));
+
+    view! {
+        <button
+            type="button"
+            // ARIA attributes from our hook
+            aria-pressed=move || toggle_state.get_aria_pressed.get()
+            aria-label=move || aria_label.get()
+            // Data attributes for Tailwind CSS 4 styling
+            data-state=move || toggle_state.get_state_attr.get()
+            data-disabled=move || if is_disabled.get() { Some("") } else { None }
+            disabled=move || is_disabled.get()
+            // 🚨 TAILWIND CSS 4 ONLY - Professional data-driven styling
+            class=move || get_toggle_classes(class.get().unwrap_or_default())
+            on:click=move |_| {
+                if !is_disabled.get_untracked() {
+                    toggle_state.toggle.run(());
+                }
+            }
+        >
+            {children()}
+        </button>
+    }
+}
@@ -0,0 +1,340 @@
+//! ToggleGroup Component - A set of two-state buttons
+//!
+//! Features:
+//! - ✅ type="single" (radio-like, can be emptied) or type="multiple"
+//! - ✅ Controlled/uncontrolled pressed items (value, default_value, on_value_change)
+//! - ✅ Roving focus: the group is a single tab stop (can be turned off)
+//! - ✅ Arrow keys follow orientation and reading direction (RTL), Home/End
+//! - ✅ data-state="on|off" like Toggle
+
+use super::toggle::get_toggle_classes;
+use crate::hooks::use_controllable_state;
+use crate::utils::{get_roving_focus_movement, move_index, query_elements, JumpKeys};
+use leptos::context::Provider;
+use leptos::ev;
+use leptos::html::Div;
+use leptos::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::HtmlElement;
+
+/// Selector for the items that can receive focus
+const ITEM_SELECTOR: &str = "[data-toggle-group-item]:not([disabled])";
+
+/// Helper function to generate toggle group CSS classes
+fn get_toggle_group_classes(user_class: String) -> String {
+    let base = "inline-flex items-center gap-1 rounded bg-white/10 p-1";
+    let vertical = "data-[orientation=vertical]:flex-col";
+
+    format!("{base} {vertical} {user_class}")
+}
+
+/// Pressed items after toggling `item`
+///
+/// In single mode pressing an item releases the others, and pressing the
+/// pressed item empties the group.
+pub(crate) fn toggle_group_value(
+    pressed_items: &[String],
+    item: &str,
+    multiple: bool,
+) -> Vec<String> {
+    let is_pressed = pressed_items.iter().any(|pressed| pressed == item);
+
+    match (multiple, is_pressed) {
+        (_, true) => pressed_items
+            .iter()
+            .filter(|pressed| *pressed != item)
+            .cloned()
+            .collect(),
+        (true, false) => {
+            let mut next = pressed_items.to_vec();
+            next.push(item.to_string());
+            next
+        }
+        (false, false) => vec![item.to_string()],
+    }
+}
+
+/// Context value shared between ToggleGroup and its items
+#[derive(Clone, Copy)]
+pub struct ToggleGroupContextValue {
+    /// Values of the pressed items
+    pub value: Signal<Vec<String>>,
+    pub multiple: Signal<bool>,
+    pub disabled: Signal<bool>,
+    /// Whether the group manages its own tab stop
+    pub roving_focus: Signal<bool>,
+    /// Press or release an item by value
+    pub toggle: Callback<String>,
+    /// Item that last had focus
+    focused: RwSignal<Option<String>>,
+    /// Enabled item values in render order
+    items: RwSignal<Vec<String>>,
+}
+
+impl ToggleGroupContextValue {
+    /// The single item reachable with Tab: the last focused one, else the
+    /// first pressed one, else the first enabled item
+    fn tab_stop(&self) -> Option<String> {
+        let value = self.value.get();
+        self.items.with(|items| {
+            self.focused
+                .get()
+                .filter(|focused| items.contains(focused))
+                .or_else(|| items.iter().find(|item| value.contains(item)).cloned())
+                .or_else(|| items.first().cloned())
+        })
+    }
+}
+
+/// ToggleGroup component - Hook-first implementation
+///
+/// Uses our proven hook library for state management and ARIA compliance.
+/// Styled with Tailwind CSS 4 ONLY - no custom CSS allowed.
+///
+/// `value` lists the pressed items in both modes (at most one in single mode).
+///
+/// # Example
+/// ```rust,ignore
+/// view! {
+///     <ToggleGroup r#type="multiple" aria_label="Text formatting">
+///         <ToggleGroupItem value="bold" aria_label="Bold">"B"</ToggleGroupItem>
+///         <ToggleGroupItem value="italic" aria_label="Italic">"I"</ToggleGroupItem>
+///     </ToggleGroup>
+/// }
+/// ```
+#[component]
+pub fn ToggleGroup(
+    /// "single" (default) or "multiple"
+    #[prop(into, optional)]
+    r#type: MaybeProp<String>,
+
+    // Core state management (from our hook library)
+    #[prop(into, optional)] value: MaybeProp<Vec<String>>,
+    #[prop(into, optional)] default_value: MaybeProp<Vec<String>>,
+    #[prop(into, optional)] on_value_change: Option<Callback<Vec<String>>>,
+
+    #[prop(into, optional)] disabled: MaybeProp<bool>,
+
+    // Keyboard navigation
+    /// Manage a single tab stop with arrow key navigation (default: true)
+    #[prop(into, optional)]
+    roving_focus: MaybeProp<bool>,
+    /// "horizontal" or "vertical" limits the arrow keys (default: all arrows)
+    #[prop(into, optional)]
+    orientation: MaybeProp<String>,
+    /// Reading direction ("ltr" or "rtl") for Left/Right
+    #[prop(into, optional)]
+    dir: MaybeProp<String>,
+    /// Wrap around at the ends (default: true)
+    #[prop(into, optional)]
+    loop_focus: MaybeProp<bool>,
+
+    // Accessibility & DOM
+    #[prop(into, optional)] aria_label: MaybeProp<String>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+
+    children: Children,
+) -> impl IntoView {
+    // Phase 0: Compose hooks - no manual state management!
+    let state = use_controllable_state(value, default_value, on_value_change);
+    let root_ref = NodeRef::<Div>::new();
+
+    // Pre-compute common values to reduce complexity
+    let is_multiple = Signal::derive(move || r#type.get().as_deref() == Some("multiple"));
+    let is_disabled = Signal::derive(move || disabled.get().unwrap_or(false));
+    let is_roving = Signal::derive(move || roving_focus.get().unwrap_or(true));
+
+    let toggle = Callback::new(move |item: String| {
+        let next = toggle_group_value(
+            &state.value.get_untracked(),
+            &item,
+            is_multiple.get_untracked(),
+        );
+        state.set_value.run(next);
+    });
+
+    // Context for child components
+    let context_value = ToggleGroupContextValue {
+        value: state.value,
+        multiple: is_multiple,
+        disabled: is_disabled,
+        roving_focus: is_roving,
+        toggle,
+        focused: RwSignal::new(None),
+        items: RwSignal::new(Vec::new()),
+    };
+
+    // Arrow keys move focus between the items of this group
+    let on_keydown = move |ev: ev::KeyboardEvent| {
+        if !is_roving.get_untracked() {
+            return;
+        }
+        let rtl = dir.get_untracked().as_deref() == Some("rtl");
+        let Some(movement) = get_roving_focus_movement(
+            &ev.key(),
+            orientation.get_untracked().as_deref(),
+            rtl,
+            JumpKeys::HomeEndPage,
+        ) else {
+            return;
+        };
+        let Some(root) = root_ref.get_untracked() else {
+            return;
+        };
+        let items: Vec<HtmlElement> = query_elements(&root, ITEM_SELECTOR)
+            .into_iter()
+            .filter(|item| {
+                item.closest("[role='group']")
+                    .ok()
+                    .flatten()
+                    .is_some_and(|group| group == **root)
+            })
+            .collect();
+        let current = ev
+            .target()
+            .and_then(|target| target.dyn_into::<HtmlElement>().ok())
+            .and_then(|target| items.iter().position(|item| *item == target));
+
+        ev.prevent_default();
+        let looping = loop_focus.get_untracked().unwrap_or(true);
+        if let Some(next) =
+            move_index(current, items.len(), movement, looping).and_then(|index| items.get(index))
+        {
+            let _ = next.focus();
+        }
+    };
+
+    view! {
+        <Provider value=context_value>
+            <div
+                node_ref=root_ref
+                role="group"
+                aria-label=move || aria_label.get()
+                dir=move || dir.get()
+                data-orientation=move || orientation.get()
+                data-disabled=move || if is_disabled.get() { Some("") } else { None }
+                // 🚨 TAILWIND CSS 4 ONLY - Layout follows the orientation
+                class=move || get_toggle_group_classes(class.get().unwrap_or_default())
+                on:keydown=on_keydown
+            >
+                {children()}
+            </div>
+        </Provider>
+    }
+}
+
+/// ToggleGroupItem - One toggle of the group
+#[component]
+pub fn ToggleGroupItem(
+    #[prop(into)] value: String,
+    #[prop(into, optional)] disabled: MaybeProp<bool>,
+
+    // Accessibility & DOM
+    #[prop(into, optional)] aria_label: MaybeProp<String>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+
+    children: Children,
+) -> impl IntoView {
+    let context = expect_context::<ToggleGroupContextValue>();
+    let item_value = StoredValue::new(value);
+
+    // Pre-compute common values to reduce complexity
+    let is_disabled =
+        Signal::derive(move || context.disabled.get() || disabled.get().unwrap_or(false));
+    let is_pressed = Signal::derive(move || {
+        item_value.with_value(|value| context.value.with(|pressed| pressed.contains(value)))
+    });
+    let is_tab_stop = Signal::derive(move || {
+        item_value.with_value(|value| context.tab_stop().as_ref() == Some(value))
+    });
+
+    // Register enabled items for the roving tab stop (during render too, so
+    // the server-rendered HTML already has one tab stop)
+    if !is_disabled.get_untracked() {
+        context
+            .items
+            .update(|items| items.push(item_value.get_value()));
+    }
+    Effect::new(move |_| {
+        let enabled = !is_disabled.get();
+        let value = item_value.get_value();
+        context.items.update(|items| {
+            let registered = items.contains(&value);
+            if enabled && !registered {
+                items.push(value);
+            } else if !enabled && registered {
+                items.retain(|item| *item != value);
+            }
+        });
+    });
+    on_cleanup(move || {
+        context
+            .items
+            .try_update(|items| item_value.with_value(|value| items.retain(|item| item != value)));
+    });
+
+    // Single mode items behave like radios, multiple mode items like toggles
+    let is_single = move || !context.multiple.get();
+
+    view! {
+        <button
+            type="button"
+            data-toggle-group-item=""
+            role=move || is_single().then_some("radio")
+            aria-checked=move || is_single().then(|| if is_pressed.get() { "true" } else { "false" })
+            aria-pressed=move || (!is_single()).then(|| if is_pressed.get() { "true" } else { "false" })
+            aria-label=move || aria_label.get()
+            // Roving tabindex: one tab stop for the whole group
+            tabindex=move || {
+                context
+                    .roving_focus
+                    .get()
+                    .then(|| if is_tab_stop.get() { "0" } else { "-1" })
+            }
+            // Data attributes for Tailwind CSS 4 styling
+            data-state=move || if is_pressed.get() { "on" } else { "off" }
+            data-disabled=move || if is_disabled.get() { Some("") } else { None }
+            disabled=move || is_disabled.get()
+            // 🚨 TAILWIND CSS 4 ONLY - Same look as Toggle
+            class=move || get_toggle_classes(class.get().unwrap_or_default())
+            on:focus=move |_| context.focused.set(Some(item_value.get_value()))
+            on:click=move |_| {
+                if !is_disabled.get_untracked() {
+                    context.toggle.run(item_value.get_value());
+                }
+            }
+        >
+            {children()}
+        </button>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn items(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn test_single_mode_presses_one_item() {
+        assert_eq!(
+            toggle_group_value(&items(&["list"]), "grid", false),
+            items(&["grid"])
+        );
+        assert!(toggle_group_value(&items(&["list"]), "list", false).is_empty());
+    }
+
+    #[test]
+    fn test_multiple_mode_toggles_independently() {
+        assert_eq!(
+            toggle_group_value(&items(&["bold"]), "italic", true),
+            items(&["bold", "italic"])
+        );
+        assert_eq!(
+            toggle_group_value(&items(&["bold", "italic"]), "bold", true),
+            items(&["italic"])
+        );
+    }
+}
@@ -0,0 +1,553 @@
+//! Toolbar Component - A container grouping buttons, links and toggles
+//!
+//! Features:
+//! - ✅ One roving tab stop across every control (buttons, links, toggle items)
+//! - ✅ Arrow keys follow orientation and reading direction (RTL), Home/End
+//! - ✅ ToolbarToggleGroup builds on ToggleGroup (with its own roving focus off)
+//! - ✅ ToolbarSeparator reuses Separator, perpendicular to the toolbar
+
+use super::separator::Separator;
+use super::toggle_group::{ToggleGroup, ToggleGroupContextValue, ToggleGroupItem};
+use crate::hooks::use_id_with_prefix;
+use crate::utils::{get_roving_focus_movement, move_index, query_elements, JumpKeys};
+use leptos::context::Provider;
+use leptos::ev;
+use leptos::html::Div;
+use leptos::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::HtmlElement;
+
+/// Selector for the controls that can receive focus
+const TOOLBAR_ITEM_SELECTOR: &str = "[data-toolbar-item]:not([disabled])";
+
+/// Helper function to generate toolbar CSS classes
+fn get_toolbar_classes(user_class: String) -> String {
+    let base = "flex w-full min-w-max items-center gap-1 rounded bg-white/10 p-1";
+    let vertical = "data-[orientation=vertical]:w-auto data-[orientation=vertical]:flex-col";
+
+    format!("{base} {vertical} {user_class}")
+}
+
+/// Helper function to generate toolbar button CSS classes
+fn get_toolbar_button_classes(user_class: String) -> String {
+    let base = "inline-flex h-8 items-center justify-center rounded px-2 text-sm font-medium text-white transition-colors duration-200 cursor-pointer hover:bg-white/10";
+    let focus = "focus:outline-none focus:ring-2 focus:ring-black focus:ring-offset-0";
+    let disabled = "data-[disabled]:opacity-50 data-[disabled]:cursor-not-allowed data-[disabled]:hover:bg-transparent";
+
+    format!("{base} {focus} {disabled} {user_class}")
+}
+
+/// Helper function to generate toolbar link CSS classes
+fn get_toolbar_link_classes(user_class: String) -> String {
+    let base = "inline-flex h-8 items-center px-2 text-sm text-white/80 underline-offset-2 hover:text-white hover:underline";
+    let focus = "focus:outline-none focus:ring-2 focus:ring-black focus:ring-offset-0";
+
+    format!("{base} {focus} {user_class}")
+}
+
+/// Context value shared between Toolbar and its controls
+#[derive(Clone, Copy)]
+pub struct ToolbarContextValue {
+    pub orientation: Signal<String>,
+    pub dir: Signal<Option<String>>,
+    /// Control that last had focus
+    focused: RwSignal<Option<String>>,
+    /// Keys of the enabled controls in render order
+    items: RwSignal<Vec<String>>,
+}
+
+impl ToolbarContextValue {
+    /// The single control reachable with Tab: the last focused one, else the
+    /// first enabled control
+    fn tab_stop(&self) -> Option<String> {
+        self.items.with(|items| {
+            self.focused
+                .get()
+                .filter(|focused| items.contains(focused))
+                .or_else(|| items.first().cloned())
+        })
+    }
+}
+
+/// Roving tab stop state of one toolbar control
+#[derive(Clone, Copy)]
+struct ToolbarItem {
+    key: StoredValue<String>,
+    is_tab_stop: Signal<bool>,
+}
+
+impl ToolbarItem {
+    fn tabindex(&self) -> &'static str {
+        if self.is_tab_stop.get() {
+            "0"
+        } else {
+            "-1"
+        }
+    }
+
+    fn on_focus(&self, context: ToolbarContextValue) {
+        context.focused.set(Some(self.key.get_value()));
+    }
+}
+
+/// Register a control for the toolbar's roving tab stop (during render too,
+/// so the server-rendered HTML already has one tab stop)
+fn use_toolbar_item(context: ToolbarContextValue, disabled: Signal<bool>) -> ToolbarItem {
+    let key = StoredValue::new(use_id_with_prefix("toolbar-item").get_untracked());
+
+    if !disabled.get_untracked() {
+        context.items.update(|items| items.push(key.get_value()));
+    }
+    Effect::new(move |_| {
+        let enabled = !disabled.get();
+        let key = key.get_value();
+        context.items.update(|items| {
+            let registered = items.contains(&key);
+            if enabled && !registered {
+                items.push(key);
+            } else if !enabled && registered {
+                items.retain(|item| *item != key);
+            }
+        });
+    });
+    // Unregister with the owner of an effect: it is disposed when the control
+    // unmounts, while writing out the server HTML (where effects never run)
+    // leaves the other controls' tab stop alone
+    Effect::new(move |_| {
+        on_cleanup(move || {
+            context
+                .items
+                .try_update(|items| key.with_value(|key| items.retain(|item| item != key)));
+        });
+    });
+
+    ToolbarItem {
+        key,
+        is_tab_stop: Signal::derive(move || {
+            key.with_value(|key| context.tab_stop().as_ref() == Some(key))
+        }),
+    }
+}
+
+/// Index of the control a navigation key moves focus to, if it is one
+///
+/// Only the arrows along `orientation` move; `rtl` swaps Left and Right,
+/// Home/End jump to the ends and `looping` wraps around past them.
+fn get_toolbar_focus_index(
+    key: &str,
+    orientation: &str,
+    rtl: bool,
+    looping: bool,
+    current: usize,
+    count: usize,
+) -> Option<usize> {
+    let movement = get_roving_focus_movement(key, Some(orientation), rtl, JumpKeys::HomeEndPage)?;
+    move_index(Some(current), count, movement, looping)
+}
+
+/// Toolbar component - Hook-first implementation
+///
+/// Uses our proven hook library for ARIA compliance.
+/// Styled with Tailwind CSS 4 ONLY - no custom CSS allowed.
+///
+/// # Example
+/// ```rust,ignore
+/// view! {
+///     <Toolbar aria_label="Formatting options">
+///         <ToolbarToggleGroup r#type="multiple" aria_label="Text formatting">
+///             <ToolbarToggleItem value="bold" aria_label="Bold">"B"</ToolbarToggleItem>
+///             <ToolbarToggleItem value="italic" aria_label="Italic">"I"</ToolbarToggleItem>
+///         </ToolbarToggleGroup>
+///         <ToolbarSeparator />
+///         <ToolbarLink href="#">"Edited 2 hours ago"</ToolbarLink>
+///         <ToolbarButton>"Share"</ToolbarButton>
+///     </Toolbar>
+/// }
+/// ```
+#[component]
+pub fn Toolbar(
+    // Keyboard navigation
+    /// "horizontal" (default) or "vertical"
+    #[prop(into, optional)]
+    orientation: MaybeProp<String>,
+    /// Reading direction ("ltr" or "rtl") for Left/Right
+    #[prop(into, optional)]
+    dir: MaybeProp<String>,
+    /// Wrap around at the ends (default: true)
+    #[prop(into, optional)]
+    loop_focus: MaybeProp<bool>,
+
+    // Accessibility & DOM
+    #[prop(into, optional)] aria_label: MaybeProp<String>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+
+    children: Children,
+) -> impl IntoView {
+    let root_ref = NodeRef::<Div>::new();
+    let current_orientation = Signal::derive(move || {
+        orientation
+            .get()
+            .unwrap_or_else(|| "horizontal".to_string())
+    });
+
+    // Context for child components
+    let context_value = ToolbarContextValue {
+        orientation: current_orientation,
+        dir: Signal::derive(move || dir.get()),
+        focused: RwSignal::new(None),
+        items: RwSignal::new(Vec::new()),
+    };
+
+    // Arrow keys move focus between the controls of this toolbar
+    let on_keydown = move |ev: ev::KeyboardEvent| {
+        let Some(root) = root_ref.get_untracked() else {
+            return;
+        };
+        let items: Vec<HtmlElement> = query_elements(&root, TOOLBAR_ITEM_SELECTOR)
+            .into_iter()
+            .filter(|item| {
+                item.closest("[role='toolbar']")
+                    .ok()
+                    .flatten()
+                    .is_some_and(|toolbar| toolbar == **root)
+            })
+            .collect();
+        let Some(current) = ev
+            .target()
+            .and_then(|target| target.dyn_into::<HtmlElement>().ok())
+            .and_then(|target| items.iter().position(|item| *item == target))
+        else {
+            return;
+        };
+
+        let Some(next) = get_toolbar_focus_index(
+            &ev.key(),
+            &current_orientation.get_untracked(),
+            dir.get_untracked().as_deref() == Some("rtl"),
+            loop_focus.get_untracked().unwrap_or(true),
+            current,
+            items.len(),
+        ) else {
+            return;
+        };
+
+        ev.prevent_default();
+        if let Some(next) = items.get(next) {
+            let _ = next.focus();
+        }
+    };
+
+    view! {
+        <Provider value=context_value>
+            <div
+                node_ref=root_ref
+                role="toolbar"
+                aria-label=move || aria_label.get()
+                aria-orientation=move || current_orientation.get()
+                dir=move || dir.get()
+                data-orientation=move || current_orientation.get()
+                // 🚨 TAILWIND CSS 4 ONLY - Layout follows the orientation
+                class=move || get_toolbar_classes(class.get().unwrap_or_default())
+                on:keydown=on_keydown
+            >
+                {children()}
+            </div>
+        </Provider>
+    }
+}
+
+/// ToolbarButton - A plain button in the toolbar
+#[component]
+pub fn ToolbarButton(
+    #[prop(into, optional)] disabled: MaybeProp<bool>,
+    #[prop(into, optional)] on_click: Option<Callback<()>>,
+
+    // Accessibility & DOM
+    #[prop(into, optional)] aria_label: MaybeProp<String>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+
+    children: Children,
+) -> impl IntoView {
+    let context = expect_context::<ToolbarContextValue>();
+    let is_disabled = Signal::derive(move || disabled.get().unwrap_or(false));
+    let item = use_toolbar_item(context, is_disabled);
+
+    view! {
+        <button
+            type="button"
+            data-toolbar-item=""
+            aria-label=move || aria_label.get()
+            // Roving tabindex: one tab stop for the whole toolbar
+            tabindex=move || item.tabindex()
+            data-orientation=move || context.orientation.get()
+            data-disabled=move || if is_disabled.get() { Some("") } else { None }
+            disabled=move || is_disabled.get()
+            // 🚨 TAILWIND CSS 4 ONLY - Professional button styling
+            class=move || get_toolbar_button_classes(class.get().unwrap_or_default())
+            on:focus=move |_| item.on_focus(context)
+            on:click=move |_| {
+                if let Some(callback) = on_click {
+                    callback.run(());
+                }
+            }
+        >
+            {children()}
+        </button>
+    }
+}
+
+/// ToolbarLink - A link in the toolbar (Space follows it, like a button)
+#[component]
+pub fn ToolbarLink(
+    #[prop(into)] href: String,
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: Children,
+) -> impl IntoView {
+    let context = expect_context::<ToolbarContextValue>();
+    let item = use_toolbar_item(context, Signal::derive(|| false));
+
+    view! {
+        <a
+            href=href
+            data-toolbar-item=""
+            // Roving tabindex: one tab stop for the whole toolbar
+            tabindex=move || item.tabindex()
+            data-orientation=move || context.orientation.get()
+            // 🚨 TAILWIND CSS 4 ONLY - Professional link styling
+            class=move || get_toolbar_link_classes(class.get().unwrap_or_default())
+            on:focus=move |_| item.on_focus(context)
+            on:keydown=move |ev: ev::KeyboardEvent| {
+                if ev.key() == " " {
+                    ev.prevent_default();
+                    if let Some(link) = ev
+                        .current_target()
+                        .and_then(|target| target.dyn_into::<HtmlElement>().ok())
+                    {
+                        link.click();
+                    }
+                }
+            }
+        >
+            {children()}
+        </a>
+    }
+}
+
+/// ToolbarToggleGroup - A ToggleGroup whose items join the toolbar's tab stop
+#[component]
+pub fn ToolbarToggleGroup(
+    /// "single" (default) or "multiple"
+    #[prop(into, optional)]
+    r#type: MaybeProp<String>,
+
+    // Core state management (from our hook library)
+    #[prop(into, optional)] value: MaybeProp<Vec<String>>,
+    #[prop(into, optional)] default_value: MaybeProp<Vec<String>>,
+    #[prop(into, optional)] on_value_change: Option<Callback<Vec<String>>>,
+
+    #[prop(into, optional)] disabled: MaybeProp<bool>,
+
+    // Accessibility & DOM
+    #[prop(into, optional)] aria_label: MaybeProp<String>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+
+    children: Children,
+) -> impl IntoView {
+    let context = expect_context::<ToolbarContextValue>();
+
+    // The toolbar handles the arrow keys for the whole row
+    view! {
+        <ToggleGroup
+            r#type=r#type
+            value=value
+            default_value=default_value
+            on_value_change=Callback::new(move |value| {
+                if let Some(callback) = on_value_change {
+                    callback.run(value);
+                }
+            })
+            disabled=disabled
+            roving_focus=false
+            orientation=context.orientation
+            dir=context.dir
+            aria_label=aria_label
+            class=class
+        >
+            {children()}
+        </ToggleGroup>
+    }
+}
+
+/// ToolbarToggleItem - An item of a ToolbarToggleGroup
+#[component]
+pub fn ToolbarToggleItem(
+    #[prop(into)] value: String,
+    #[prop(into, optional)] disabled: MaybeProp<bool>,
+
+    // Accessibility & DOM
+    #[prop(into, optional)] aria_label: MaybeProp<String>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+
+    children: Children,
+) -> impl IntoView {
+    let context = expect_context::<ToolbarContextValue>();
+    let group = expect_context::<ToggleGroupContextValue>();
+    let is_disabled =
+        Signal::derive(move || group.disabled.get() || disabled.get().unwrap_or(false));
+    let item = use_toolbar_item(context, is_disabled);
+
+    view! {
+        <ToggleGroupItem
+            value=value
+            disabled=is_disabled
+            aria_label=aria_label
+            class=class
+            attr:data-toolbar-item=""
+            // Roving tabindex: one tab stop for the whole toolbar
+            attr:tabindex=move || item.tabindex()
+            on:focus=move |_| item.on_focus(context)
+        >
+            {children()}
+        </ToggleGroupItem>
+    }
+}
+
+/// ToolbarSeparator - Separator perpendicular to the toolbar
+#[component]
+pub fn ToolbarSeparator(#[prop(into, optional)] class: MaybeProp<String>) -> impl IntoView {
+    let context = expect_context::<ToolbarContextValue>();
+
+    let orientation = Signal::derive(move || {
+        if context.orientation.get() == "vertical" {
+            "horizontal".to_string()
+        } else {
+            "vertical".to_string()
+        }
+    });
+
+    view! {
+        <Separator
+            orientation=orientation
+            class=Signal::derive(move || {
+                format!(
+                    "data-[orientation=vertical]:mx-1 data-[orientation=vertical]:h-5 data-[orientation=horizontal]:my-1 {}",
+                    class.get().unwrap_or_default(),
+                )
+            })
+        />
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn context(items: &[&str], focused: Option<&str>) -> ToolbarContextValue {
+        ToolbarContextValue {
+            orientation: Signal::derive(|| "horizontal".to_string()),
+            dir: Signal::derive(|| None),
+            focused: RwSignal::new(focused.map(str::to_string)),
+            items: RwSignal::new(items.iter().map(|item| item.to_string()).collect()),
+        }
+    }
+
+    #[test]
+    fn test_tab_stop_is_first_control_until_one_is_focused() {
+        assert_eq!(
+            context(&["bold", "italic", "share"], None)
+                .tab_stop()
+                .as_deref(),
+            Some("bold")
+        );
+        assert_eq!(
+            context(&["bold", "italic", "share"], Some("share"))
+                .tab_stop()
+                .as_deref(),
+            Some("share")
+        );
+        // A focused control that was disabled or removed hands the stop back
+        assert_eq!(
+            context(&["bold", "share"], Some("italic"))
+                .tab_stop()
+                .as_deref(),
+            Some("bold")
+        );
+        assert_eq!(context(&[], None).tab_stop(), None);
+    }
+
+    #[test]
+    fn test_arrows_follow_orientation() {
+        assert_eq!(
+            get_toolbar_focus_index("ArrowRight", "horizontal", false, true, 0, 3),
+            Some(1)
+        );
+        assert_eq!(
+            get_toolbar_focus_index("ArrowLeft", "horizontal", false, true, 2, 3),
+            Some(1)
+        );
+        assert_eq!(
+            get_toolbar_focus_index("ArrowDown", "horizontal", false, true, 0, 3),
+            None
+        );
+        assert_eq!(
+            get_toolbar_focus_index("ArrowDown", "vertical", false, true, 0, 3),
+            Some(1)
+        );
+        assert_eq!(
+            get_toolbar_focus_index("ArrowRight", "vertical", false, true, 0, 3),
+            None
+        );
+        assert_eq!(
+            get_toolbar_focus_index("Enter", "horizontal", false, true, 0, 3),
+            None
+        );
+    }
+
+    #[test]
+    fn test_rtl_swaps_left_and_right() {
+        assert_eq!(
+            get_toolbar_focus_index("ArrowLeft", "horizontal", true, true, 0, 3),
+            Some(1)
+        );
+        assert_eq!(
+            get_toolbar_focus_index("ArrowRight", "horizontal", true, true, 1, 3),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn test_wraps_only_when_looping() {
+        assert_eq!(
+            get_toolbar_focus_index("ArrowRight", "horizontal", false, true, 2, 3),
+            Some(0)
+        );
+        assert_eq!(
+            get_toolbar_focus_index("ArrowLeft", "horizontal", false, true, 0, 3),
+            Some(2)
+        );
+        assert_eq!(
+            get_toolbar_focus_index("ArrowRight", "horizontal", false, false, 2, 3),
+            Some(2)
+        );
+        assert_eq!(
+            get_toolbar_focus_index("ArrowLeft", "horizontal", false, false, 0, 3),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn test_home_and_end_jump_to_the_ends() {
+        assert_eq!(
+            get_toolbar_focus_index("Home", "horizontal", false, false, 2, 3),
+            Some(0)
+        );
+        assert_eq!(
+            get_toolbar_focus_index("End", "vertical", false, false, 0, 3),
+            Some(2)
+        );
+        assert_eq!(
+            get_toolbar_focus_index("PageDown", "horizontal", false, false, 0, 3),
+            Some(2)
+        );
+    }
+}
@@ -0,0 +1,282 @@
+//! Tooltip Component - Short description shown on hover or keyboard focus
+//!
+//! Features:
+//! - ✅ Opens on hover (after a delay) and on keyboard focus (immediately)
+//! - ✅ Configurable open/close delays via TooltipProvider or per tooltip
+//! - ✅ Provider-level delay group: moving between triggers skips the delay
+//! - ✅ role="tooltip" wired to the trigger through aria-describedby (use_related_ids)
+//! - ✅ Escape, pointer press and blur dismissal
+//! - ✅ Collision-aware positioning with data-side / data-align and optional arrow
+
+use crate::hooks::{
+    get_arrow_style, use_floating_position, use_tooltip_behavior, Align, FloatingOptions,
+    Placement, Side, TooltipDelayGroup, TooltipDelays, UseTooltipBehaviorReturn,
+};
+use leptos::context::Provider;
+use leptos::html::{Button, Div};
+use leptos::prelude::*;
+use wasm_bindgen::JsCast;
+
+/// Helper function to generate tooltip content CSS classes
+fn get_tooltip_content_classes(user_class: String) -> String {
+    let base = "z-50 rounded-md bg-gray-900 px-3 py-1.5 text-sm text-white shadow-md select-none";
+    let animation = "data-[state=delayed-open]:animate-in data-[state=delayed-open]:fade-in-0 data-[state=delayed-open]:zoom-in-95 data-[side=bottom]:slide-in-from-top-2 data-[side=top]:slide-in-from-bottom-2 data-[side=left]:slide-in-from-right-2 data-[side=right]:slide-in-from-left-2";
+
+    format!("{base} {animation} {user_class}")
+}
+
+/// Helper function to generate tooltip trigger CSS classes
+fn get_tooltip_trigger_classes(user_class: String) -> String {
+    let base = "inline-flex items-center justify-center rounded px-3 py-1.5 text-sm font-medium transition-colors";
+    let focus = "focus:outline-none focus:ring-2 focus:ring-black focus:ring-offset-0";
+
+    format!("{base} {focus} {user_class}")
+}
+
+/// Context value shared between Tooltip and its parts
+#[derive(Clone, Copy)]
+pub struct TooltipContextValue {
+    pub tooltip: UseTooltipBehaviorReturn,
+    pub trigger_ref: NodeRef<Button>,
+    pub content_ref: NodeRef<Div>,
+}
+
+/// Context value shared between TooltipContent and TooltipArrow
+#[derive(Clone, Copy)]
+struct TooltipContentContextValue {
+    placement: Signal<Option<Placement>>,
+}
+
+/// TooltipProvider - Shares delays and skip-delay state between tooltips
+///
+/// # Example
+/// ```rust,ignore
+/// view! {
+///     <TooltipProvider delay_duration=400 skip_delay_duration=300>
+///         <Tooltip>...</Tooltip>
+///         <Tooltip>...</Tooltip>
+///     </TooltipProvider>
+/// }
+/// ```
+#[component]
+pub fn TooltipProvider(
+    /// Hover time in ms before a tooltip opens (default: 700)
+    #[prop(into, optional)]
+    delay_duration: MaybeProp<u64>,
+    /// Time in ms after the pointer leaves before a tooltip closes (default: 0)
+    #[prop(into, optional)]
+    close_delay_duration: MaybeProp<u64>,
+    /// Time in ms during which moving to another trigger opens instantly (default: 300)
+    #[prop(into, optional)]
+    skip_delay_duration: MaybeProp<u64>,
+
+    children: Children,
+) -> impl IntoView {
+    let defaults = TooltipDelays::default();
+    let delays = Signal::derive(move || TooltipDelays {
+        open_delay: delay_duration.get().unwrap_or(defaults.open_delay),
+        close_delay: close_delay_duration.get().unwrap_or(defaults.close_delay),
+        skip_delay: skip_delay_duration.get().unwrap_or(defaults.skip_delay),
+    });
+
+    view! {
+        <Provider value=TooltipDelayGroup::new(delays)>
+            {children()}
+        </Provider>
+    }
+}
+
+/// Tooltip component - Hook-first implementation
+///
+/// # Example
+/// ```rust,ignore
+/// view! {
+///     <Tooltip>
+///         <TooltipTrigger>"Add"</TooltipTrigger>
+///         <TooltipContent side=Side::Top side_offset=6.0>
+///             "Add to library"
+///             <TooltipArrow />
+///         </TooltipContent>
+///     </Tooltip>
+/// }
+/// ```
+#[component]
+pub fn Tooltip(
+    // Core state management (from our hook library)
+    #[prop(into, optional)] open: MaybeProp<bool>,
+    #[prop(into, optional)] default_open: MaybeProp<bool>,
+    #[prop(into, optional)] on_open_change: Option<Callback<bool>>,
+
+    /// Overrides the provider's open delay in ms for this tooltip
+    #[prop(into, optional)]
+    delay_duration: MaybeProp<u64>,
+
+    children: Children,
+) -> impl IntoView {
+    let group = use_context::<TooltipDelayGroup>();
+    let delays = delay_duration.get_untracked().is_some().then(|| {
+        let base = group.map(|group| group.delays);
+        Signal::derive(move || TooltipDelays {
+            open_delay: delay_duration.get().unwrap_or_default(),
+            ..base.map(|delays| delays.get()).unwrap_or_default()
+        })
+    });
+
+    let tooltip = use_tooltip_behavior(open, default_open, on_open_change, group, delays);
+
+    let context_value = TooltipContextValue {
+        tooltip,
+        trigger_ref: NodeRef::new(),
+        content_ref: NodeRef::new(),
+    };
+
+    view! {
+        <Provider value=context_value>
+            {children()}
+        </Provider>
+    }
+}
+
+/// TooltipTrigger - Button the tooltip describes
+#[component]
+pub fn TooltipTrigger(
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: Children,
+) -> impl IntoView {
+    let TooltipContextValue {
+        tooltip,
+        trigger_ref,
+        ..
+    } = expect_context::<TooltipContextValue>();
+
+    // A press focuses the button; that focus must not reopen the tooltip
+    let pointer_down = RwSignal::new(false);
+
+    view! {
+        <button
+            node_ref=trigger_ref
+            id=move || tooltip.trigger_id.get()
+            type="button"
+            aria-describedby=move || tooltip.open.get().then(|| tooltip.content_id.get())
+            data-state=move || tooltip.get_state_attr.get()
+            class=move || get_tooltip_trigger_classes(class.get().unwrap_or_default())
+            on:pointerenter=move |event| {
+                if event.pointer_type() != "touch" {
+                    tooltip.on_trigger_enter.run(());
+                }
+            }
+            on:pointerleave=move |_| tooltip.on_trigger_leave.run(())
+            on:pointerdown=move |_| {
+                pointer_down.set(true);
+                tooltip.close.run(());
+            }
+            on:pointerup=move |_| pointer_down.set(false)
+            on:focus=move |_| {
+                if !pointer_down.get_untracked() {
+                    tooltip.on_focus.run(());
+                }
+            }
+            on:blur=move |_| tooltip.close.run(())
+        >
+            {children()}
+        </button>
+    }
+}
+
+/// TooltipContent - The floating label (`role="tooltip"`)
+#[component]
+pub fn TooltipContent(
+    /// Preferred side of the trigger (default: top)
+    #[prop(into, optional)]
+    side: MaybeProp<Side>,
+    /// Preferred alignment against the trigger (default: center)
+    #[prop(into, optional)]
+    align: MaybeProp<Align>,
+    /// Distance in px from the trigger
+    #[prop(into, optional)]
+    side_offset: MaybeProp<f64>,
+    /// Offset in px from the aligned edge
+    #[prop(into, optional)]
+    align_offset: MaybeProp<f64>,
+    /// Flip/shift to stay in the viewport (default: true)
+    #[prop(into, optional)]
+    avoid_collisions: MaybeProp<bool>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+    children: ChildrenFn,
+) -> impl IntoView {
+    let TooltipContextValue {
+        tooltip,
+        trigger_ref,
+        content_ref,
+    } = expect_context::<TooltipContextValue>();
+    let children = StoredValue::new(children);
+
+    let options = Signal::derive(move || FloatingOptions {
+        side: side.get().unwrap_or(Side::Top),
+        align: align.get().unwrap_or_default(),
+        side_offset: side_offset.get().unwrap_or(0.0),
+        align_offset: align_offset.get().unwrap_or(0.0),
+        avoid_collisions: avoid_collisions.get().unwrap_or(true),
+        ..Default::default()
+    });
+
+    let position = use_floating_position(
+        tooltip.open,
+        move || trigger_ref.get().map(|trigger| trigger.unchecked_into()),
+        content_ref,
+        options,
+    );
+
+    let content_context = TooltipContentContextValue {
+        placement: position.placement,
+    };
+
+    view! {
+        <Show when=move || tooltip.open.get()>
+            <Provider value=content_context>
+                <div
+                    node_ref=content_ref
+                    id=move || tooltip.content_id.get()
+                    role="tooltip"
+                    data-state=move || tooltip.get_state_attr.get()
+                    data-side=move || position.side.get().as_str()
+                    data-align=move || position.align.get().as_str()
+                    style=move || position.style.get()
+                    class=move || get_tooltip_content_classes(class.get().unwrap_or_default())
+                    on:pointerenter=move |_| tooltip.on_content_enter.run(())
+                    on:pointerleave=move |_| tooltip.on_content_leave.run(())
+                >
+                    {children.read_value()()}
+                </div>
+            </Provider>
+        </Show>
+    }
+}
+
+/// TooltipArrow - Small triangle on the content edge pointing at the trigger
+#[component]
+pub fn TooltipArrow(
+    #[prop(into, optional)] width: MaybeProp<f64>,
+    #[prop(into, optional)] height: MaybeProp<f64>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+) -> impl IntoView {
+    let content_context = expect_context::<TooltipContentContextValue>();
+    let width = Signal::derive(move || width.get().unwrap_or(10.0));
+    let height = Signal::derive(move || height.get().unwrap_or(5.0));
+
+    view! {
+        <svg
+            width=move || width.get()
+            height=move || height.get()
+            viewBox="0 0 30 10"
+            preserveAspectRatio="none"
+            aria-hidden="true"
+            style=move || {
+                get_arrow_style(content_context.placement.get(), width.get(), height.get())
+            }
+            class=move || format!("fill-gray-900 {}", class.get().unwrap_or_default())
+        >
+            <polygon points="0,0 30,0 15,10" />
+        </svg>
+    }
+}
@@ -5,22 +5,27 @@
 // pub mod use_composed_refs;  // TODO: Fix NodeRef type issues
 pub mod use_controllable_state;
 pub mod use_escape_key;
-// pub mod use_focus_trap;     // TODO: Fix NodeRef type issues
+pub mod use_focus_trap;
 pub mod use_id_generator;
-// pub mod use_outside_click;  // TODO: Fix NodeRef type issues
+pub mod use_outside_click;
 pub mod use_previous;
+pub mod use_typeahead;
 
 // Layer 2: Component-Specific Hooks (Building Blocks)
 pub mod use_checkbox_state;
 pub mod use_progress_state;
-// pub mod use_radio_group_state;     // TODO: Implement
-// pub mod use_slider_state;          // TODO: Implement
+pub mod use_radio_group_state;
+pub mod use_slider_state;
 pub mod use_switch_state;
+pub mod use_toggle_state;
 
 // Layer 3: Behavior Hooks (Complex Interactions)
-// pub mod use_dialog_behavior;       // TODO: Implement
-// pub mod use_dropdown_behavior;     // TODO: Implement
-// pub mod use_tooltip_behavior;      // TODO: Implement
+pub mod use_dialog_behavior;
+pub mod use_dropdown_behavior;
+pub mod use_floating_position;
+pub mod use_image_loading_status;
+pub mod use_presence;
+pub mod use_tooltip_behavior;
 
 // Layer 4: Integration Hooks (External Systems)
 // pub mod use_accessibility_announcer; // TODO: Implement
@@ -35,19 +40,24 @@ pub use use_switch_state::*;
 // Core utilities
 // pub use use_composed_refs::*;  // TODO: Fix NodeRef type issues
 pub use use_escape_key::*;
-// pub use use_focus_trap::*;     // TODO: Fix NodeRef type issues
+pub use use_focus_trap::*;
 pub use use_id_generator::*;
-// pub use use_outside_click::*;  // TODO: Fix NodeRef type issues
+pub use use_outside_click::*;
 pub use use_previous::*;
+pub use use_typeahead::*;
 
 // Component-specific
-// pub use use_radio_group_state::*;     // TODO: Implement
-// pub use use_slider_state::*;          // TODO: Implement
+pub use use_radio_group_state::*;
+pub use use_slider_state::*;
+pub use use_toggle_state::*;
 
 // Behavior hooks
-// pub use use_dialog_behavior::*;       // TODO: Implement
-// pub use use_dropdown_behavior::*;     // TODO: Implement
-// pub use use_tooltip_behavior::*;      // TODO: Implement
+pub use use_dialog_behavior::*;
+pub use use_dropdown_behavior::*;
+pub use use_floating_position::*;
+pub use use_image_loading_status::*;
+pub use use_presence::*;
+pub use use_tooltip_behavior::*;
 
 // Integration hooks
 // pub use use_accessibility_announcer::*; // TODO: Implement
@@ -6,7 +6,7 @@ use leptos::prelude::*;
 /// controlled (external state) or uncontrolled (internal state).
 ///
 /// # Example
-/// ```rust
+/// ```rust,ignore
 /// let state = use_controllable_state(
 ///     checked,           // Optional controlled value
 ///     default_checked,   // Optional default value
@@ -1,14 +1,257 @@
+use super::{
+    use_controllable_state, use_escape_key_when, use_focus_trap_with_config, use_related_ids,
+    UseFocusTrapConfig,
+};
+use leptos::ev;
+use leptos::html::Div;
 use leptos::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::Element;
 
-// TODO: Implement dialog behavior hook
-// This will handle:
-// - Open/close state management
-// - Focus trap when open
-// - Escape key and outside click handling
-// - Body scroll lock
-// - ARIA attributes for dialog
+/// Options that distinguish the different dialog flavours (Dialog, AlertDialog, ...)
+#[derive(Clone)]
+pub struct UseDialogBehaviorOptions {
+    /// Modal dialogs trap focus and lock body scroll
+    pub modal: bool,
+    /// Whether pressing Escape closes the dialog
+    pub close_on_escape: bool,
+    /// Whether pointer presses outside the content close the dialog
+    pub close_on_outside_click: bool,
+    /// Selector (inside the content) for the element that receives focus on open
+    pub initial_focus: Option<String>,
+    /// Prefix for the generated ARIA ids
+    pub id_prefix: &'static str,
+}
+
+impl Default for UseDialogBehaviorOptions {
+    fn default() -> Self {
+        Self {
+            modal: true,
+            close_on_escape: true,
+            close_on_outside_click: true,
+            initial_focus: None,
+            id_prefix: "dialog",
+        }
+    }
+}
+
+/// Return type for use_dialog_behavior hook
+#[derive(Clone, Copy)]
+pub struct UseDialogBehaviorReturn {
+    /// Current open state
+    pub open: Signal<bool>,
+    /// Set the open state
+    pub set_open: Callback<bool>,
+    /// Toggle between open/closed
+    pub toggle: Callback<()>,
+    /// Close the dialog
+    pub close: Callback<()>,
+    /// Get data-state attribute value ("open" / "closed")
+    pub get_state_attr: Memo<&'static str>,
+    /// Whether the dialog is modal
+    pub modal: bool,
+    /// ID for the trigger element
+    pub trigger_id: Signal<String>,
+    /// ID for the content element
+    pub content_id: Signal<String>,
+    /// ID for the title element (aria-labelledby)
+    pub title_id: Signal<String>,
+    /// ID for the description element (aria-describedby)
+    pub description_id: Signal<String>,
+    /// Ref that must be attached to the content element
+    pub content_ref: NodeRef<Div>,
+    /// Whether the component is in controlled mode
+    pub is_controlled: Signal<bool>,
+}
+
+/// Hook for dialog behavior
+///
+/// Provides everything a modal overlay needs:
+/// - Controlled/uncontrolled open state
+/// - Focus trap while open (with focus restore on close)
+/// - Escape key and outside click dismissal
+/// - Body scroll lock
+/// - Related ARIA ids for trigger, content, title and description
+///
+/// # Example
+/// ```rust,ignore
+/// let dialog = use_dialog_behavior(open, default_open, on_open_change, Default::default());
+///
+/// view! {
+///     <button id=dialog.trigger_id on:click=move |_| dialog.toggle.run(())>"Open"</button>
+///     <div
+///         node_ref=dialog.content_ref
+///         role="dialog"
+///         aria-labelledby=dialog.title_id
+///         aria-describedby=dialog.description_id
+///     />
+/// }
+/// ```
+pub fn use_dialog_behavior(
+    open: MaybeProp<bool>,
+    default_open: MaybeProp<bool>,
+    on_open_change: Option<Callback<bool>>,
+    options: UseDialogBehaviorOptions,
+) -> UseDialogBehaviorReturn {
+    let state = use_controllable_state(
+        open,
+        default_open.get().unwrap_or(false).into(),
+        on_open_change,
+    );
+    let ids = use_related_ids(options.id_prefix);
+    let content_ref = NodeRef::<Div>::new();
+
+    let set_open = state.set_value;
+    let is_open = state.value;
+    let toggle = Callback::new(move |_: ()| set_open.run(!is_open.get_untracked()));
+    let close = Callback::new(move |_: ()| set_open.run(false));
 
-/// Placeholder for dialog behavior hook
-pub fn use_dialog_behavior() {
-    // TODO: Implement
+    let get_state_attr = Memo::new(move |_| if is_open.get() { "open" } else { "closed" });
+
+    // Modal-only behaviour: focus containment and scroll lock
+    let modal = options.modal;
+    let modal_open = Signal::derive(move || modal && is_open.get());
+    use_focus_trap_with_config(
+        content_ref,
+        modal_open,
+        UseFocusTrapConfig {
+            initial_focus: options.initial_focus.clone(),
+            ..Default::default()
+        },
+    );
+    use_body_scroll_lock(modal_open);
+
+    // Dismissal
+    let close_on_escape = options.close_on_escape;
+    use_escape_key_when(
+        Signal::derive(move || close_on_escape && is_open.get()),
+        Callback::new(move |_| close.run(())),
+    );
+
+    if options.close_on_outside_click {
+        use_dismiss_on_pointer_outside(content_ref, ids.trigger_id, is_open, close);
+    }
+
+    UseDialogBehaviorReturn {
+        open: is_open,
+        set_open,
+        toggle,
+        close,
+        get_state_attr,
+        modal,
+        trigger_id: ids.trigger_id,
+        content_id: ids.content_id,
+        title_id: ids.label_id,
+        description_id: ids.description_id,
+        content_ref,
+        is_controlled: state.is_controlled,
+    }
 }
+
+/// Close when a pointer press lands outside the content (the trigger is ignored
+/// so its own click handler stays in charge of toggling)
+fn use_dismiss_on_pointer_outside(
+    content_ref: NodeRef<Div>,
+    trigger_id: Signal<String>,
+    open: Signal<bool>,
+    close: Callback<()>,
+) {
+    Effect::new(move |_| {
+        if !open.get() {
+            return;
+        }
+
+        let Some(content) = content_ref.get() else {
+            return;
+        };
+        let content: Element = content.unchecked_into();
+        if !content.is_connected() {
+            return;
+        }
+
+        let handle = window_event_listener(ev::pointerdown, move |event| {
+            let Some(target) = event
+                .target()
+                .and_then(|target| target.dyn_into::<Element>().ok())
+            else {
+                return;
+            };
+
+            if content.contains(Some(&target)) {
+                return;
+            }
+
+            let trigger = web_sys::window()
+                .and_then(|window| window.document())
+                .and_then(|document| document.get_element_by_id(&trigger_id.get_untracked()));
+            if trigger.is_some_and(|trigger| trigger.contains(Some(&target))) {
+                return;
+            }
+
+            close.run(());
+        });
+
+        on_cleanup(move || handle.remove());
+    });
+}
+
+/// Hook to lock body scrolling while `locked` is true
+///
+/// Restores the previous `overflow` and compensates for the scrollbar width so
+/// the page does not shift when the scrollbar disappears.
+///
+/// # Example
+/// ```rust,ignore
+/// let (is_open, set_is_open) = signal(false);
+/// use_body_scroll_lock(is_open.into());
+/// ```
+pub fn use_body_scroll_lock(locked: Signal<bool>) {
+    Effect::new(move |_| {
+        if !locked.get() {
+            return;
+        }
+
+        let Some(window) = web_sys::window() else {
+            return;
+        };
+        let Some(body) = window.document().and_then(|document| document.body()) else {
+            return;
+        };
+
+        let style = body.style();
+        let previous_overflow = style.get_property_value("overflow").unwrap_or_default();
+        let previous_padding = style
+            .get_property_value("padding-right")
+            .unwrap_or_default();
+
+        let scrollbar_width = window
+            .inner_width()
+            .ok()
+            .and_then(|width| width.as_f64())
+            .zip(
+                window
+                    .document()
+                    .and_then(|document| document.document_element()),
+            )
+            .map(|(width, root)| width - f64::from(root.client_width()))
+            .unwrap_or(0.0);
+
+        let _ = style.set_property("overflow", "hidden");
+        if scrollbar_width > 0.0 {
+            let _ = style.set_property("padding-right", &format!("{scrollbar_width}px"));
+        }
+
+        on_cleanup(move || {
+            if let Some(body) = web_sys::window()
+                .and_then(|window| window.document())
+                .and_then(|document| document.body())
+            {
+                let style = body.style();
+                let _ = style.set_property("overflow", &previous_overflow);
+                let _ = style.set_property("padding-right", &previous_padding);
+            }
+        });
+    });
+}
+
+// TODO: Add tests with proper HydrationCtx
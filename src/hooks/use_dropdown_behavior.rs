@@ -1,14 +1,229 @@
+use super::{
+    use_controllable_state, use_escape_key_when, use_outside_click_multiple_when, use_related_ids,
+};
+use crate::utils::{active_element, is_point_in_polygon, move_index, query_elements, Movement};
+use leptos::html::{Button, Div};
 use leptos::prelude::*;
+use std::time::Duration;
+use web_sys::{Element, HtmlElement};
 
-// TODO: Implement dropdown behavior hook
-// This will handle:
-// - Open/close state management
-// - Positioning relative to trigger
-// - Keyboard navigation (arrow keys, enter, escape)
-// - Outside click and escape key handling
-// - ARIA attributes for dropdown
+/// Selector matching every kind of menu item
+pub const MENU_ITEM_SELECTOR: &str =
+    "[role='menuitem'], [role='menuitemcheckbox'], [role='menuitemradio']";
 
-/// Placeholder for dropdown behavior hook
-pub fn use_dropdown_behavior() {
-    // TODO: Implement
+/// How long the pointer may travel towards an open submenu before the grace area expires
+const POINTER_GRACE_MS: u64 = 300;
+
+/// The enabled items belonging to `content` (items of nested submenus excluded)
+pub fn get_menu_items(content: &Element) -> Vec<HtmlElement> {
+    query_elements(content, MENU_ITEM_SELECTOR)
+        .into_iter()
+        .filter(|item| !item.has_attribute("data-disabled"))
+        .filter(|item| {
+            item.parent_element()
+                .and_then(|parent| parent.closest("[role='menu']").ok().flatten())
+                .is_some_and(|menu| &menu == content)
+        })
+        .collect()
+}
+
+/// Move focus between the items of `content`, starting from the focused item
+///
+/// Returns `true` when an item received focus.
+pub fn focus_menu_item(content: &Element, movement: Movement) -> bool {
+    let items = get_menu_items(content);
+    let current = active_element().and_then(|active| items.iter().position(|item| *item == active));
+
+    move_index(current, items.len(), movement, false)
+        .and_then(|index| items.get(index))
+        .map(|item| item.focus().is_ok())
+        .unwrap_or(false)
+}
+
+/// Return type for use_dropdown_behavior hook
+#[derive(Clone, Copy)]
+pub struct UseDropdownBehaviorReturn {
+    /// Current open state
+    pub open: Signal<bool>,
+    /// Set the open state
+    pub set_open: Callback<bool>,
+    /// Open the menu and focus an item (`None` focuses the content itself)
+    pub open_with_focus: Callback<Option<Movement>>,
+    /// Toggle between open/closed
+    pub toggle: Callback<()>,
+    /// Close the menu, leaving focus where it is
+    pub close: Callback<()>,
+    /// Close the menu and return focus to the trigger (Escape, item selection)
+    pub dismiss: Callback<()>,
+    /// Get data-state attribute value ("open" / "closed")
+    pub get_state_attr: Memo<&'static str>,
+    /// Item to focus once the content mounts
+    pub initial_focus: RwSignal<Option<Movement>>,
+    /// ID for the trigger element
+    pub trigger_id: Signal<String>,
+    /// ID for the content element
+    pub content_id: Signal<String>,
+    /// Ref for the trigger button (counts as inside for outside clicks)
+    pub trigger_ref: NodeRef<Button>,
+    /// Ref that must be attached to the root content element
+    pub content_ref: NodeRef<Div>,
+    /// Set (or clear) the polygon the pointer may cross on its way into a submenu
+    pub set_pointer_grace: Callback<Option<Vec<(f64, f64)>>>,
+    /// Whether a pointer position lies inside the current grace polygon
+    pub is_pointer_in_grace: Callback<(f64, f64), bool>,
+}
+
+/// Hook for dropdown menu behavior
+///
+/// Provides:
+/// - Controlled/uncontrolled open state
+/// - Keyboard-aware opening (focus first/last item or the content)
+/// - Escape dismissal with focus returned to the trigger
+/// - Outside click dismissal (trigger and content, including submenus, count as inside)
+/// - Pointer grace area so moving diagonally into a submenu does not close it
+/// - Related ARIA ids for trigger and content
+///
+/// Item navigation itself is DOM based: see [`get_menu_items`] and [`focus_menu_item`].
+///
+/// # Example
+/// ```rust,ignore
+/// let menu = use_dropdown_behavior(open, default_open, on_open_change, "dropdown-menu");
+///
+/// view! {
+///     <button
+///         node_ref=menu.trigger_ref
+///         aria-haspopup="menu"
+///         on:click=move |_| menu.toggle.run(())
+///     />
+///     <div node_ref=menu.content_ref role="menu" />
+/// }
+/// ```
+pub fn use_dropdown_behavior(
+    open: MaybeProp<bool>,
+    default_open: MaybeProp<bool>,
+    on_open_change: Option<Callback<bool>>,
+    id_prefix: &'static str,
+) -> UseDropdownBehaviorReturn {
+    let state = use_controllable_state(
+        open,
+        default_open.get().unwrap_or(false).into(),
+        on_open_change,
+    );
+    let ids = use_related_ids(id_prefix);
+    let trigger_ref = NodeRef::<Button>::new();
+    let content_ref = NodeRef::<Div>::new();
+    let initial_focus = RwSignal::new(None::<Movement>);
+
+    let is_open = state.value;
+    let set_open = state.set_value;
+
+    let open_with_focus = Callback::new(move |focus: Option<Movement>| {
+        initial_focus.set(focus);
+        set_open.run(true);
+    });
+    let toggle = Callback::new(move |_: ()| {
+        if is_open.get_untracked() {
+            set_open.run(false);
+        } else {
+            open_with_focus.run(None);
+        }
+    });
+    let close = Callback::new(move |_: ()| {
+        if is_open.get_untracked() {
+            set_open.run(false);
+        }
+    });
+    let dismiss = Callback::new(move |_: ()| {
+        close.run(());
+        if let Some(trigger) = trigger_ref.get_untracked() {
+            let _ = trigger.focus();
+        }
+    });
+
+    let get_state_attr = Memo::new(move |_| if is_open.get() { "open" } else { "closed" });
+
+    // Dismissal
+    use_escape_key_when(is_open, Callback::new(move |_| dismiss.run(())));
+    use_outside_click_multiple_when(
+        is_open,
+        vec![Box::new(trigger_ref), Box::new(content_ref)],
+        Callback::new(move |_| close.run(())),
+    );
+
+    // Pointer grace: while the pointer crosses this polygon, sibling items
+    // ignore it so the open submenu stays open
+    let grace_area = StoredValue::new(None::<Vec<(f64, f64)>>);
+    let grace_timer = StoredValue::new(None::<TimeoutHandle>);
+    let clear_grace_timer = move || {
+        if let Some(handle) = grace_timer.try_update_value(Option::take).flatten() {
+            handle.clear();
+        }
+    };
+
+    let set_pointer_grace = Callback::new(move |area: Option<Vec<(f64, f64)>>| {
+        clear_grace_timer();
+        let has_area = area.is_some();
+        grace_area.set_value(area);
+        if has_area {
+            let handle = set_timeout_with_handle(
+                move || grace_area.set_value(None),
+                Duration::from_millis(POINTER_GRACE_MS),
+            )
+            .ok();
+            grace_timer.set_value(handle);
+        }
+    });
+    let is_pointer_in_grace = Callback::new(move |point: (f64, f64)| {
+        grace_area
+            .with_value(|area| {
+                area.as_ref()
+                    .map(|polygon| is_point_in_polygon(point, polygon))
+            })
+            .unwrap_or(false)
+    });
+
+    on_cleanup(clear_grace_timer);
+
+    UseDropdownBehaviorReturn {
+        open: is_open,
+        set_open,
+        open_with_focus,
+        toggle,
+        close,
+        dismiss,
+        get_state_attr,
+        initial_focus,
+        trigger_id: ids.trigger_id,
+        content_id: ids.content_id,
+        trigger_ref,
+        content_ref,
+        set_pointer_grace,
+        is_pointer_in_grace,
+    }
 }
+
+/// Grace polygon from the pointer's exit point to the near edge of a submenu
+///
+/// `exit` is where the pointer left the sub trigger; the polygon fans out to
+/// the submenu's rectangle so diagonal movement towards it stays inside.
+pub fn get_pointer_grace_area(exit: (f64, f64), submenu: &Element) -> Vec<(f64, f64)> {
+    let rect = submenu.get_bounding_client_rect();
+    let (x, y) = exit;
+    let opens_right = rect.left() >= x;
+    let bleed = if opens_right { -5.0 } else { 5.0 };
+    let (near, far) = if opens_right {
+        (rect.left(), rect.right())
+    } else {
+        (rect.right(), rect.left())
+    };
+
+    vec![
+        (x + bleed, y),
+        (near, rect.top()),
+        (far, rect.top()),
+        (far, rect.bottom()),
+        (near, rect.bottom()),
+    ]
+}
+
+// TODO: Add tests with proper HydrationCtx
@@ -8,7 +8,7 @@ use web_sys::{Event, KeyboardEvent};
 /// close when pressing Escape.
 ///
 /// # Example
-/// ```rust
+/// ```rust,ignore
 /// let (is_open, set_is_open) = signal(false);
 ///
 /// use_escape_key(Callback::new(move |_| {
@@ -16,26 +16,7 @@ use web_sys::{Event, KeyboardEvent};
 /// }));
 /// ```
 pub fn use_escape_key(on_escape: Callback<KeyboardEvent>) {
-    Effect::new(move |_| {
-        let callback = on_escape;
-
-        let closure = wasm_bindgen::closure::Closure::wrap(Box::new(move |event: Event| {
-            if let Ok(keyboard_event) = event.dyn_into::<KeyboardEvent>() {
-                if keyboard_event.key() == "Escape" {
-                    callback.run(keyboard_event);
-                }
-            }
-        }) as Box<dyn FnMut(Event)>);
-
-        // Add event listener to document
-        if let Some(document) = web_sys::window().and_then(|w| w.document()) {
-            let _ = document
-                .add_event_listener_with_callback("keydown", closure.as_ref().unchecked_ref());
-
-            // Store closure to prevent it from being dropped
-            closure.forget();
-        }
-    });
+    use_escape_key_when(Signal::derive(|| true), on_escape);
 }
 
 /// Hook to handle Escape key with conditional enabling
@@ -43,7 +24,7 @@ pub fn use_escape_key(on_escape: Callback<KeyboardEvent>) {
 /// Only listens for Escape when enabled is true.
 ///
 /// # Example
-/// ```rust
+/// ```rust,ignore
 /// let (is_modal_open, set_is_modal_open) = signal(false);
 ///
 /// use_escape_key_when(
@@ -57,22 +38,14 @@ pub fn use_escape_key_when(enabled: Signal<bool>, on_escape: Callback<KeyboardEv
             return;
         }
 
-        let callback = on_escape;
-
-        let closure = wasm_bindgen::closure::Closure::wrap(Box::new(move |event: Event| {
-            if let Ok(keyboard_event) = event.dyn_into::<KeyboardEvent>() {
-                if keyboard_event.key() == "Escape" {
-                    callback.run(keyboard_event);
-                }
+        let handle = window_event_listener(leptos::ev::keydown, move |keyboard_event| {
+            if keyboard_event.key() == "Escape" {
+                on_escape.run(keyboard_event);
             }
-        }) as Box<dyn FnMut(Event)>);
+        });
 
-        if let Some(document) = web_sys::window().and_then(|w| w.document()) {
-            let _ = document
-                .add_event_listener_with_callback("keydown", closure.as_ref().unchecked_ref());
-
-            closure.forget();
-        }
+        // Remove the listener once disabled so stale overlays stop reacting
+        on_cleanup(move || handle.remove());
     });
 }
 
@@ -102,7 +75,7 @@ impl Default for UseEscapeKeyConfig {
 /// Advanced escape key hook with configuration options
 ///
 /// # Example
-/// ```rust
+/// ```rust,ignore
 /// use_escape_key_with_config(
 ///     callback,
 ///     UseEscapeKeyConfig {
@@ -154,7 +127,7 @@ pub fn use_escape_key_with_config(on_key: Callback<KeyboardEvent>, config: UseEs
 /// Hook for handling multiple key combinations
 ///
 /// # Example
-/// ```rust
+/// ```rust,ignore
 /// use_key_combinations(vec![
 ///     ("Escape", Callback::new(move |_| close_modal())),
 ///     ("Enter", Callback::new(move |_| submit_form())),
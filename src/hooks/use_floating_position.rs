@@ -0,0 +1,351 @@
+use leptos::ev;
+use leptos::html::Div;
+use leptos::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::Element;
+
+/// Side of the anchor a floating element is placed on
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Default)]
+pub enum Side {
+    Top,
+    Right,
+    #[default]
+    Bottom,
+    Left,
+}
+
+impl Side {
+    /// Value used for the `data-side` attribute
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Side::Top => "top",
+            Side::Right => "right",
+            Side::Bottom => "bottom",
+            Side::Left => "left",
+        }
+    }
+
+    /// The side directly across from this one
+    pub fn opposite(&self) -> Side {
+        match self {
+            Side::Top => Side::Bottom,
+            Side::Right => Side::Left,
+            Side::Bottom => Side::Top,
+            Side::Left => Side::Right,
+        }
+    }
+
+    /// Whether the main axis is vertical (top/bottom)
+    pub fn is_vertical(&self) -> bool {
+        matches!(self, Side::Top | Side::Bottom)
+    }
+}
+
+/// Alignment of a floating element along the anchor's edge
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Default)]
+pub enum Align {
+    Start,
+    #[default]
+    Center,
+    End,
+}
+
+impl Align {
+    /// Value used for the `data-align` attribute
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Align::Start => "start",
+            Align::Center => "center",
+            Align::End => "end",
+        }
+    }
+}
+
+/// Viewport-relative rectangle (mirrors `DOMRect`)
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub struct Rect {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl Rect {
+    pub fn from_element(element: &Element) -> Self {
+        let rect = element.get_bounding_client_rect();
+        Self {
+            x: rect.x(),
+            y: rect.y(),
+            width: rect.width(),
+            height: rect.height(),
+        }
+    }
+}
+
+/// Placement options shared by every floating component
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FloatingOptions {
+    /// Preferred side of the anchor
+    pub side: Side,
+    /// Preferred alignment against the anchor
+    pub align: Align,
+    /// Distance in px from the anchor
+    pub side_offset: f64,
+    /// Offset in px from the aligned edge
+    pub align_offset: f64,
+    /// Flip and shift to stay inside the viewport
+    pub avoid_collisions: bool,
+    /// Minimum distance in px kept from the viewport edges
+    pub collision_padding: f64,
+}
+
+impl Default for FloatingOptions {
+    fn default() -> Self {
+        Self {
+            side: Side::Bottom,
+            align: Align::Center,
+            side_offset: 0.0,
+            align_offset: 0.0,
+            avoid_collisions: true,
+            collision_padding: 0.0,
+        }
+    }
+}
+
+/// Resolved position of a floating element
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Placement {
+    /// Left edge in viewport px
+    pub x: f64,
+    /// Top edge in viewport px
+    pub y: f64,
+    /// Side actually used (after collision flipping)
+    pub side: Side,
+    /// Alignment used
+    pub align: Align,
+    /// Center of the anchor along the cross axis, relative to the floating element
+    pub arrow_offset: f64,
+}
+
+/// Compute where a `content` sized floating element goes around `anchor`
+///
+/// Pure function so it can be unit-tested without a DOM.
+pub fn compute_position(
+    anchor: Rect,
+    content: (f64, f64),
+    viewport: (f64, f64),
+    options: &FloatingOptions,
+) -> Placement {
+    let (width, height) = content;
+    let (viewport_width, viewport_height) = viewport;
+    let padding = options.collision_padding;
+
+    let main_axis = |side: Side| -> f64 {
+        match side {
+            Side::Top => anchor.y - height - options.side_offset,
+            Side::Bottom => anchor.y + anchor.height + options.side_offset,
+            Side::Left => anchor.x - width - options.side_offset,
+            Side::Right => anchor.x + anchor.width + options.side_offset,
+        }
+    };
+
+    let overflow = |side: Side| -> f64 {
+        let start = main_axis(side);
+        match side {
+            Side::Top => padding - start,
+            Side::Bottom => start + height - (viewport_height - padding),
+            Side::Left => padding - start,
+            Side::Right => start + width - (viewport_width - padding),
+        }
+    };
+
+    let mut side = options.side;
+    if options.avoid_collisions
+        && overflow(side) > 0.0
+        && overflow(side.opposite()) < overflow(side)
+    {
+        side = side.opposite();
+    }
+
+    let (anchor_start, anchor_size, size, viewport_size) = if side.is_vertical() {
+        (anchor.x, anchor.width, width, viewport_width)
+    } else {
+        (anchor.y, anchor.height, height, viewport_height)
+    };
+
+    let mut cross = match options.align {
+        Align::Start => anchor_start + options.align_offset,
+        Align::Center => anchor_start + (anchor_size - size) / 2.0,
+        Align::End => anchor_start + anchor_size - size - options.align_offset,
+    };
+
+    if options.avoid_collisions {
+        let max = (viewport_size - size - padding).max(padding);
+        cross = cross.clamp(padding, max);
+    }
+
+    let arrow_offset = (anchor_start + anchor_size / 2.0 - cross).clamp(0.0, size.max(0.0));
+
+    let (x, y) = if side.is_vertical() {
+        (cross, main_axis(side))
+    } else {
+        (main_axis(side), cross)
+    };
+
+    Placement {
+        x,
+        y,
+        side,
+        align: options.align,
+        arrow_offset,
+    }
+}
+
+/// Return type for use_floating_position hook
+#[derive(Clone, Copy)]
+pub struct UseFloatingPositionReturn {
+    /// Latest placement (None until the content has been measured)
+    pub placement: Signal<Option<Placement>>,
+    /// Side actually used, for `data-side`
+    pub side: Signal<Side>,
+    /// Alignment used, for `data-align`
+    pub align: Signal<Align>,
+    /// Inline style positioning the content (`position: fixed`)
+    pub style: Signal<String>,
+}
+
+/// Hook that keeps a floating element positioned next to its anchor
+///
+/// Measures anchor and content while `open`, re-measuring on window resize
+/// and scroll. The content stays invisible until the first measurement so it
+/// never flashes at the wrong position.
+///
+/// # Example
+/// ```rust,ignore
+/// let position = use_floating_position(
+///     is_open,
+///     move || trigger_ref.get().map(|el| el.into()),
+///     content_ref,
+///     Signal::derive(FloatingOptions::default),
+/// );
+///
+/// view! { <div node_ref=content_ref style=position.style data-side=move || position.side.get().as_str() /> }
+/// ```
+pub fn use_floating_position<F>(
+    open: Signal<bool>,
+    anchor: F,
+    content_ref: NodeRef<Div>,
+    options: Signal<FloatingOptions>,
+) -> UseFloatingPositionReturn
+where
+    F: Fn() -> Option<Element> + 'static,
+{
+    let (placement, set_placement) = signal(None::<Placement>);
+
+    Effect::new(move |_| {
+        let options = options.get();
+        if !open.get() {
+            set_placement.set(None);
+            return;
+        }
+
+        let (Some(anchor), Some(content)) = (anchor(), content_ref.get()) else {
+            return;
+        };
+        let content: Element = content.unchecked_into();
+        if !content.is_connected() {
+            return;
+        }
+
+        let update = move || {
+            let Some(window) = web_sys::window() else {
+                return;
+            };
+            let viewport = (
+                window
+                    .inner_width()
+                    .ok()
+                    .and_then(|w| w.as_f64())
+                    .unwrap_or(0.0),
+                window
+                    .inner_height()
+                    .ok()
+                    .and_then(|h| h.as_f64())
+                    .unwrap_or(0.0),
+            );
+            let size = Rect::from_element(&content);
+            let next = compute_position(
+                Rect::from_element(&anchor),
+                (size.width, size.height),
+                viewport,
+                &options,
+            );
+            if placement.get_untracked() != Some(next) {
+                set_placement.set(Some(next));
+            }
+        };
+
+        update();
+
+        let update = std::rc::Rc::new(update);
+        let resize_handle = {
+            let update = update.clone();
+            window_event_listener(ev::resize, move |_| update())
+        };
+        let scroll_handle = window_event_listener(ev::scroll, move |_| update());
+
+        on_cleanup(move || {
+            resize_handle.remove();
+            scroll_handle.remove();
+        });
+    });
+
+    let side = Signal::derive(move || {
+        placement
+            .get()
+            .map(|placement| placement.side)
+            .unwrap_or_else(|| options.get().side)
+    });
+    let align = Signal::derive(move || options.get().align);
+    let style = Signal::derive(move || match placement.get() {
+        Some(placement) => format!(
+            "position: fixed; left: {}px; top: {}px;",
+            placement.x, placement.y
+        ),
+        None => "position: fixed; left: 0px; top: 0px; visibility: hidden;".to_string(),
+    });
+
+    UseFloatingPositionReturn {
+        placement: placement.into(),
+        side,
+        align,
+        style,
+    }
+}
+
+/// Inline style for an arrow element rendered inside the floating content
+///
+/// The arrow sits on the edge facing the anchor and points at its center.
+pub fn get_arrow_style(placement: Option<Placement>, width: f64, height: f64) -> String {
+    let Some(placement) = placement else {
+        return "position: absolute; visibility: hidden;".to_string();
+    };
+
+    let offset = placement.arrow_offset - width / 2.0;
+    match placement.side {
+        Side::Bottom => format!(
+            "position: absolute; left: {offset}px; top: -{height}px; transform: rotate(180deg);"
+        ),
+        Side::Top => format!("position: absolute; left: {offset}px; bottom: -{height}px;"),
+        Side::Right => format!(
+            "position: absolute; top: {}px; left: -{}px; transform: rotate(90deg);",
+            placement.arrow_offset - height / 2.0,
+            width / 2.0 + height / 2.0
+        ),
+        Side::Left => format!(
+            "position: absolute; top: {}px; right: -{}px; transform: rotate(-90deg);",
+            placement.arrow_offset - height / 2.0,
+            width / 2.0 + height / 2.0
+        ),
+    }
+}
@@ -1,18 +1,22 @@
+use crate::utils::{active_element, contains_element, focus_first, focus_last, FOCUSABLE_SELECTOR};
+use leptos::ev;
+use leptos::html::ElementType;
 use leptos::prelude::*;
-use leptos::html::AnyElement;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement};
 
 /// Hook to trap focus within a specific element
-/// 
+///
 /// Essential for modals, dialogs, and any overlay that should contain focus
 /// for accessibility compliance.
-/// 
+///
 /// # Example
-/// ```rust
-/// let modal_ref = NodeRef::<AnyElement>::new();
+/// ```rust,ignore
+/// let modal_ref = NodeRef::<leptos::html::Div>::new();
 /// let (is_modal_open, set_is_modal_open) = signal(false);
-/// 
-/// use_focus_trap(modal_ref, is_modal_open);
-/// 
+///
+/// use_focus_trap(modal_ref, is_modal_open.into());
+///
 /// view! {
 ///     <div node_ref=modal_ref role="dialog">
 ///         <button>"First focusable"</button>
@@ -21,29 +25,16 @@ use leptos::html::AnyElement;
 ///     </div>
 /// }
 /// ```
-pub fn use_focus_trap(
-    container_ref: NodeRef<AnyElement>,
-    enabled: Signal<bool>,
-) {
-    Effect::new(move |_| {
-        if !enabled.get() {
-            return;
-        }
-        
-        let container = container_ref.get();
-        if let Some(_container) = container {
-            // TODO: Implement focus trap logic
-            // 1. Find all focusable elements within container
-            // 2. Set focus to first focusable element
-            // 3. Listen for Tab/Shift+Tab and cycle focus
-            // 4. Prevent focus from leaving the container
-            
-            web_sys::console::log_1(&"Focus trap activated".into());
-        }
-    });
+pub fn use_focus_trap<E>(container_ref: NodeRef<E>, enabled: Signal<bool>)
+where
+    E: ElementType,
+    E::Output: JsCast + Clone + 'static,
+{
+    use_focus_trap_with_config(container_ref, enabled, UseFocusTrapConfig::default());
 }
 
 /// Configuration for focus trap behavior
+#[derive(Clone)]
 pub struct UseFocusTrapConfig {
     /// Whether to focus the first element when trap activates
     pub auto_focus: bool,
@@ -51,8 +42,8 @@ pub struct UseFocusTrapConfig {
     pub restore_focus: bool,
     /// Custom selector for focusable elements
     pub focusable_selector: Option<String>,
-    /// Whether the trap is enabled
-    pub enabled: bool,
+    /// Selector for the element that should receive focus first, if present
+    pub initial_focus: Option<String>,
 }
 
 impl Default for UseFocusTrapConfig {
@@ -61,42 +52,141 @@ impl Default for UseFocusTrapConfig {
             auto_focus: true,
             restore_focus: true,
             focusable_selector: None,
-            enabled: true,
+            initial_focus: None,
         }
     }
 }
 
 /// Advanced focus trap hook with configuration
-/// 
+///
 /// # Example
-/// ```rust
+/// ```rust,ignore
 /// use_focus_trap_with_config(
 ///     modal_ref,
+///     is_modal_open.into(),
 ///     UseFocusTrapConfig {
-///         auto_focus: true,
-///         restore_focus: true,
-///         focusable_selector: Some("button, input, [tabindex]:not([tabindex='-1'])".to_string()),
-///         enabled: is_modal_open.get(),
+///         initial_focus: Some("[data-autofocus]".to_string()),
+///         ..Default::default()
 ///     }
 /// );
 /// ```
-pub fn use_focus_trap_with_config(
-    container_ref: NodeRef<AnyElement>,
+pub fn use_focus_trap_with_config<E>(
+    container_ref: NodeRef<E>,
+    enabled: Signal<bool>,
     config: UseFocusTrapConfig,
-) {
+) where
+    E: ElementType,
+    E::Output: JsCast + Clone + 'static,
+{
+    let selector = config
+        .focusable_selector
+        .clone()
+        .unwrap_or_else(|| FOCUSABLE_SELECTOR.to_string());
+    let previously_focused = StoredValue::new_local(None::<HtmlElement>);
+
     Effect::new(move |_| {
-        if !config.enabled {
+        if !enabled.get() {
+            return;
+        }
+
+        let Some(container) = container_ref.get() else {
+            return;
+        };
+        let container: Element = container.unchecked_into();
+        if !container.is_connected() {
             return;
         }
-        
-        let container = container_ref.get();
-        if let Some(_container) = container {
-            // TODO: Implement advanced focus trap with config options
-            web_sys::console::log_1(&"Advanced focus trap activated".into());
+
+        previously_focused.set_value(active_element());
+
+        if config.auto_focus {
+            activate_initial_focus(&container, &selector, config.initial_focus.as_deref());
         }
+
+        // Cycle Tab / Shift+Tab between the first and last focusable elements
+        let keydown_handle = {
+            let container = container.clone();
+            let selector = selector.clone();
+            window_event_listener(ev::keydown, move |event| {
+                if event.key() != "Tab" {
+                    return;
+                }
+
+                let focusable = crate::utils::get_focusable_elements(&container, &selector);
+                let (Some(first), Some(last)) = (focusable.first(), focusable.last()) else {
+                    event.prevent_default();
+                    return;
+                };
+
+                let active = active_element();
+                let on_first = active.as_ref() == Some(first);
+                let on_last = active.as_ref() == Some(last);
+                let outside = active
+                    .as_ref()
+                    .map(|element| !contains_element(&container, element))
+                    .unwrap_or(true);
+
+                if event.shift_key() && (on_first || outside) {
+                    event.prevent_default();
+                    let _ = last.focus();
+                } else if !event.shift_key() && (on_last || outside) {
+                    event.prevent_default();
+                    let _ = first.focus();
+                }
+            })
+        };
+
+        // Pull focus back if it escapes the container (e.g. via pointer)
+        let focusin_handle = {
+            let container = container.clone();
+            let selector = selector.clone();
+            window_event_listener(ev::focusin, move |event| {
+                let Some(target) = event
+                    .target()
+                    .and_then(|target| target.dyn_into::<Element>().ok())
+                else {
+                    return;
+                };
+
+                if !contains_element(&container, &target) {
+                    focus_first(&container, &selector);
+                }
+            })
+        };
+
+        let restore_focus = config.restore_focus;
+        on_cleanup(move || {
+            keydown_handle.remove();
+            focusin_handle.remove();
+
+            if restore_focus {
+                if let Some(element) = previously_focused
+                    .try_update_value(|value| value.take())
+                    .flatten()
+                {
+                    let _ = element.focus();
+                }
+            }
+        });
     });
 }
 
+/// Focus the configured initial element, falling back to the first focusable one
+fn activate_initial_focus(container: &Element, selector: &str, initial_focus: Option<&str>) {
+    let initial = initial_focus
+        .and_then(|initial| container.query_selector(initial).ok().flatten())
+        .and_then(|element| element.dyn_into::<HtmlElement>().ok());
+
+    match initial {
+        Some(element) => {
+            let _ = element.focus();
+        }
+        None => {
+            focus_first(container, selector);
+        }
+    }
+}
+
 /// Return type for use_focus_trap_detailed hook
 pub struct UseFocusTrapDetailedReturn {
     /// Whether focus trap is currently active
@@ -112,65 +202,53 @@ pub struct UseFocusTrapDetailedReturn {
 }
 
 /// Detailed focus trap hook with manual controls
-/// 
+///
 /// # Example
-/// ```rust
+/// ```rust,ignore
 /// let focus_trap = use_focus_trap_detailed(modal_ref);
-/// 
+///
 /// // Manual control
 /// focus_trap.activate.run(());
 /// focus_trap.focus_first.run(());
 /// ```
-pub fn use_focus_trap_detailed(
-    container_ref: NodeRef<AnyElement>,
-) -> UseFocusTrapDetailedReturn {
+pub fn use_focus_trap_detailed<E>(container_ref: NodeRef<E>) -> UseFocusTrapDetailedReturn
+where
+    E: ElementType,
+    E::Output: JsCast + Clone + 'static,
+{
     let (is_active, set_is_active) = signal(false);
-    
-    let activate = {
-        let set_is_active = set_is_active;
-        move |_: ()| {
-            set_is_active.set(true);
-        }
+
+    let activate = move |_: ()| set_is_active.set(true);
+    let deactivate = move |_: ()| set_is_active.set(false);
+
+    let container = move || {
+        container_ref
+            .get_untracked()
+            .map(|element| element.unchecked_into::<Element>())
     };
-    
-    let deactivate = {
-        let set_is_active = set_is_active;
-        move |_: ()| {
-            set_is_active.set(false);
+
+    let first = move |_: ()| {
+        if let Some(container) = container() {
+            focus_first(&container, FOCUSABLE_SELECTOR);
         }
     };
-    
-    let focus_first = move |_: ()| {
-        // TODO: Implement focus first logic
-        web_sys::console::log_1(&"Focus first element".into());
-    };
-    
-    let focus_last = move |_: ()| {
-        // TODO: Implement focus last logic
-        web_sys::console::log_1(&"Focus last element".into());
+
+    let last = move |_: ()| {
+        if let Some(container) = container() {
+            focus_last(&container, FOCUSABLE_SELECTOR);
+        }
     };
-    
+
     // Main focus trap effect
-    Effect::new(move |_| {
-        if is_active.get() {
-            let container = container_ref.get();
-            if let Some(_container) = container {
-                // TODO: Implement focus trap logic
-                web_sys::console::log_1(&"Detailed focus trap activated".into());
-            }
-        }
-    });
-    
+    use_focus_trap(container_ref, is_active.into());
+
     UseFocusTrapDetailedReturn {
         is_active: is_active.into(),
         activate: Callback::new(activate),
         deactivate: Callback::new(deactivate),
-        focus_first: Callback::new(focus_first),
-        focus_last: Callback::new(focus_last),
+        focus_first: Callback::new(first),
+        focus_last: Callback::new(last),
     }
 }
 
-// TODO: Implement actual focus trap logic with DOM manipulation
-// TODO: Add support for custom focusable element detection
-// TODO: Add proper keyboard event handling for Tab/Shift+Tab
 // TODO: Add tests with proper HydrationCtx
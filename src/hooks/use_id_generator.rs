@@ -10,7 +10,7 @@ static ID_COUNTER: AtomicUsize = AtomicUsize::new(0);
 /// a stable, unique identifier.
 ///
 /// # Example
-/// ```rust
+/// ```rust,ignore
 /// let input_id = use_id();
 /// let label_id = use_id();
 ///
@@ -27,7 +27,7 @@ pub fn use_id() -> Signal<String> {
 /// Hook to generate a unique ID with a custom prefix
 ///
 /// # Example
-/// ```rust
+/// ```rust,ignore
 /// let dialog_id = use_id_with_prefix("dialog");
 /// let button_id = use_id_with_prefix("button");
 ///
@@ -58,7 +58,7 @@ pub struct UseRelatedIdsReturn {
 /// multiple related elements with proper ARIA relationships.
 ///
 /// # Example
-/// ```rust
+/// ```rust,ignore
 /// let ids = use_related_ids("tooltip");
 ///
 /// view! {
@@ -93,7 +93,7 @@ pub fn use_related_ids(prefix: &'static str) -> UseRelatedIdsReturn {
 /// Hook to generate IDs for form elements with proper relationships
 ///
 /// # Example
-/// ```rust
+/// ```rust,ignore
 /// let form_ids = use_form_ids("username");
 ///
 /// view! {
@@ -114,6 +114,7 @@ pub fn use_related_ids(prefix: &'static str) -> UseRelatedIdsReturn {
 ///     </div>
 /// }
 /// ```
+#[derive(Clone, Copy)]
 pub struct UseFormIdsReturn {
     /// ID for the input element
     pub input_id: Signal<String>,
@@ -125,21 +126,26 @@ pub struct UseFormIdsReturn {
     pub error_id: Signal<String>,
 }
 
-pub fn use_form_ids(field_name: &'static str) -> UseFormIdsReturn {
+pub fn use_form_ids(field_name: impl Into<String>) -> UseFormIdsReturn {
     let base_id = ID_COUNTER.fetch_add(1, Ordering::Relaxed);
+    let base = format!("{}-{base_id}", field_name.into());
+    let id = move |suffix: &'static str| {
+        let base = base.clone();
+        Signal::derive(move || format!("{base}-{suffix}"))
+    };
 
     UseFormIdsReturn {
-        input_id: Signal::derive(move || format!("{field_name}-{base_id}-input")),
-        label_id: Signal::derive(move || format!("{field_name}-{base_id}-label")),
-        description_id: Signal::derive(move || format!("{field_name}-{base_id}-description")),
-        error_id: Signal::derive(move || format!("{field_name}-{base_id}-error")),
+        input_id: id("input"),
+        label_id: id("label"),
+        description_id: id("description"),
+        error_id: id("error"),
     }
 }
 
 /// Hook for generating IDs with custom patterns
 ///
 /// # Example
-/// ```rust
+/// ```rust,ignore
 /// let custom_ids = use_custom_id_pattern("modal", vec!["header", "body", "footer"]);
 /// // Generates: modal-1-header, modal-1-body, modal-1-footer
 /// ```
@@ -160,7 +166,7 @@ pub fn use_custom_id_pattern(
 /// Uses a provided key to ensure the same ID is generated for the same key.
 ///
 /// # Example
-/// ```rust
+/// ```rust,ignore
 /// let stable_id = use_stable_id("my-unique-key");
 /// // Always generates the same ID for "my-unique-key"
 /// ```
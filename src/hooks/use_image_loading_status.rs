@@ -0,0 +1,92 @@
+use leptos::prelude::*;
+use std::fmt::{Display, Formatter};
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+use web_sys::HtmlImageElement;
+
+/// Loading status of an image source
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Default)]
+pub enum ImageLoadingStatus {
+    /// Not loading yet (server render and first client render)
+    #[default]
+    Idle,
+    Loading,
+    Loaded,
+    /// Missing source or failed to load
+    Error,
+}
+
+impl Display for ImageLoadingStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                ImageLoadingStatus::Idle => "idle",
+                ImageLoadingStatus::Loading => "loading",
+                ImageLoadingStatus::Loaded => "loaded",
+                ImageLoadingStatus::Error => "error",
+            }
+        )
+    }
+}
+
+/// Hook that tracks whether an image source loads
+///
+/// Loads `src` in a detached image so the visible `<img>` can wait until it is
+/// ready. The status stays `Idle` until the first effect runs, which keeps the
+/// server HTML and the hydrating client render identical.
+///
+/// # Example
+/// ```rust,ignore
+/// let status = use_image_loading_status(Signal::derive(move || Some(src.get())));
+///
+/// view! {
+///     <Show when=move || status.get() == ImageLoadingStatus::Loaded>
+///         <img src=src />
+///     </Show>
+/// }
+/// ```
+pub fn use_image_loading_status(src: Signal<Option<String>>) -> Signal<ImageLoadingStatus> {
+    let status = RwSignal::new(ImageLoadingStatus::Idle);
+
+    Effect::new(move |_| {
+        let Some(src) = src.get().filter(|src| !src.is_empty()) else {
+            status.set(ImageLoadingStatus::Error);
+            return;
+        };
+        let Ok(image) = HtmlImageElement::new() else {
+            status.set(ImageLoadingStatus::Error);
+            return;
+        };
+        status.set(ImageLoadingStatus::Loading);
+
+        let on_load = Closure::<dyn Fn()>::new(move || {
+            status.try_set(ImageLoadingStatus::Loaded);
+        });
+        let on_error = Closure::<dyn Fn()>::new(move || {
+            status.try_set(ImageLoadingStatus::Error);
+        });
+        image.set_onload(Some(on_load.as_ref().unchecked_ref()));
+        image.set_onerror(Some(on_error.as_ref().unchecked_ref()));
+        image.set_src(&src);
+        // Cached images may already be complete
+        if image.complete() && image.natural_width() > 0 {
+            status.set(ImageLoadingStatus::Loaded);
+        }
+
+        // Keep the closures alive until the source changes, then detach them
+        // so a stale load cannot overwrite the new status
+        let pending = StoredValue::new_local(Some((image, on_load, on_error)));
+        on_cleanup(move || {
+            if let Some((image, _, _)) = pending.try_update_value(Option::take).flatten() {
+                image.set_onload(None);
+                image.set_onerror(None);
+            }
+        });
+    });
+
+    status.into()
+}
+
+// TODO: Add tests with proper HydrationCtx
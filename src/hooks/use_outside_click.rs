@@ -1,6 +1,39 @@
+use leptos::html::ElementType;
 use leptos::prelude::*;
 use wasm_bindgen::JsCast;
-use web_sys::Event;
+use web_sys::{Element, Event};
+
+/// Anything that can resolve to a DOM element
+///
+/// Implemented for every `NodeRef`, so refs to different element types
+/// (e.g. a `<button>` trigger and a `<div>` content) can be mixed.
+pub trait ElementRef: Send + Sync + 'static {
+    /// The referenced element, if mounted (tracked when called inside an effect)
+    fn element(&self) -> Option<Element>;
+}
+
+impl<E> ElementRef for NodeRef<E>
+where
+    E: ElementType + 'static,
+    E::Output: JsCast + Clone + 'static,
+{
+    fn element(&self) -> Option<Element> {
+        self.get().map(|element| element.unchecked_into())
+    }
+}
+
+/// Whether the event target lies outside every element in `elements`
+fn is_outside(event: &Event, elements: &[Element]) -> bool {
+    event
+        .target()
+        .and_then(|target| target.dyn_into::<Element>().ok())
+        .map(|target| {
+            elements
+                .iter()
+                .all(|element| !element.contains(Some(&target)))
+        })
+        .unwrap_or(false)
+}
 
 /// Hook to detect clicks outside of a specific element
 ///
@@ -8,8 +41,8 @@ use web_sys::Event;
 /// close when clicking outside.
 ///
 /// # Example
-/// ```rust
-/// let dropdown_ref = NodeRef::<AnyElement>::new();
+/// ```rust,ignore
+/// let dropdown_ref = NodeRef::<leptos::html::Div>::new();
 /// let (is_open, set_is_open) = signal(false);
 ///
 /// use_outside_click(
@@ -23,38 +56,12 @@ use web_sys::Event;
 ///     </div>
 /// }
 /// ```
-pub fn use_outside_click(
-    element_ref: NodeRef<web_sys::Element>,
-    on_outside_click: Callback<Event>,
-) {
-    Effect::new(move |_| {
-        let element = element_ref.get();
-
-        if let Some(element) = element {
-            let callback = on_outside_click.clone();
-
-            let closure = wasm_bindgen::closure::Closure::wrap(Box::new(move |event: Event| {
-                if let Some(target) = event.target() {
-                    if let Ok(target_element) = target.dyn_into::<web_sys::Element>() {
-                        // Check if the click target is outside our element
-                        if !element.contains(Some(&target_element)) {
-                            callback.run(event);
-                        }
-                    }
-                }
-            })
-                as Box<dyn FnMut(Event)>);
-
-            // Add event listener to document
-            if let Some(document) = web_sys::window().and_then(|w| w.document()) {
-                let _ = document
-                    .add_event_listener_with_callback("click", closure.as_ref().unchecked_ref());
-
-                // Store closure to prevent it from being dropped
-                closure.forget();
-            }
-        }
-    });
+pub fn use_outside_click<E>(element_ref: NodeRef<E>, on_outside_click: Callback<Event>)
+where
+    E: ElementType + 'static,
+    E::Output: JsCast + Clone + 'static,
+{
+    use_outside_click_multiple(vec![Box::new(element_ref)], on_outside_click);
 }
 
 /// Hook to detect clicks outside multiple elements
@@ -63,49 +70,62 @@ pub fn use_outside_click(
 /// that should all be considered "inside".
 ///
 /// # Example
-/// ```rust
-/// let trigger_ref = NodeRef::<AnyElement>::new();
-/// let content_ref = NodeRef::<AnyElement>::new();
+/// ```rust,ignore
+/// let trigger_ref = NodeRef::<leptos::html::Button>::new();
+/// let content_ref = NodeRef::<leptos::html::Div>::new();
 ///
 /// use_outside_click_multiple(
-///     vec![trigger_ref, content_ref],
+///     vec![Box::new(trigger_ref), Box::new(content_ref)],
 ///     Callback::new(move |_| close_dropdown())
 /// );
 /// ```
 pub fn use_outside_click_multiple(
-    element_refs: Vec<NodeRef<AnyElement>>,
+    element_refs: Vec<Box<dyn ElementRef>>,
     on_outside_click: Callback<Event>,
 ) {
-    Effect::new(move |_| {
-        let elements: Vec<_> = element_refs.iter().filter_map(|r| r.get()).collect();
+    use_outside_click_multiple_when(Signal::derive(|| true), element_refs, on_outside_click);
+}
 
-        if !elements.is_empty() {
-            let callback = on_outside_click.clone();
+/// Hook to detect clicks outside multiple elements, only while `enabled` is true
+///
+/// The listener is removed as soon as `enabled` turns false, so a closed
+/// overlay never reports stale outside clicks.
+///
+/// # Example
+/// ```rust,ignore
+/// use_outside_click_multiple_when(
+///     is_open,
+///     vec![Box::new(trigger_ref), Box::new(content_ref)],
+///     Callback::new(move |_| set_is_open.set(false))
+/// );
+/// ```
+pub fn use_outside_click_multiple_when(
+    enabled: Signal<bool>,
+    element_refs: Vec<Box<dyn ElementRef>>,
+    on_outside_click: Callback<Event>,
+) {
+    Effect::new(move |_| {
+        if !enabled.get() {
+            return;
+        }
 
-            let closure = wasm_bindgen::closure::Closure::wrap(Box::new(move |event: Event| {
-                if let Some(target) = event.target() {
-                    if let Ok(target_element) = target.dyn_into::<web_sys::Element>() {
-                        // Check if the click target is outside ALL our elements
-                        let is_outside = elements
-                            .iter()
-                            .all(|element| !element.contains(Some(&target_element)));
-
-                        if is_outside {
-                            callback.run(event);
-                        }
-                    }
-                }
-            })
-                as Box<dyn FnMut(Event)>);
+        let elements: Vec<Element> = element_refs
+            .iter()
+            .filter_map(|element_ref| element_ref.element())
+            .filter(|element| element.is_connected())
+            .collect();
 
-            // Add event listener to document
-            if let Some(document) = web_sys::window().and_then(|w| w.document()) {
-                let _ = document
-                    .add_event_listener_with_callback("click", closure.as_ref().unchecked_ref());
+        if elements.is_empty() {
+            return;
+        }
 
-                closure.forget();
+        let handle = window_event_listener(leptos::ev::click, move |event| {
+            if is_outside(&event, &elements) {
+                on_outside_click.run(event.into());
             }
-        }
+        });
+
+        on_cleanup(move || handle.remove());
     });
 }
 
@@ -132,58 +152,108 @@ impl Default for UseOutsideClickConfig {
 /// Advanced outside click hook with configuration options
 ///
 /// # Example
-/// ```rust
+/// ```rust,ignore
 /// use_outside_click_with_config(
 ///     element_ref,
 ///     callback,
 ///     UseOutsideClickConfig {
 ///         events: vec!["click", "touchstart"],
 ///         capture: true,
-///         enabled: is_open.get(),
+///         enabled: true,
 ///     }
 /// );
 /// ```
-pub fn use_outside_click_with_config(
-    element_ref: NodeRef<AnyElement>,
+pub fn use_outside_click_with_config<E>(
+    element_ref: NodeRef<E>,
     on_outside_click: Callback<Event>,
     config: UseOutsideClickConfig,
-) {
+) where
+    E: ElementType + 'static,
+    E::Output: JsCast + Clone + 'static,
+{
+    use_outside_click_with_config_when(
+        Signal::derive(|| true),
+        element_ref,
+        on_outside_click,
+        config,
+    );
+}
+
+/// Configurable outside click hook, only listening while `enabled` is true
+///
+/// The document listeners are removed as soon as `enabled` turns false.
+///
+/// # Example
+/// ```rust,ignore
+/// use_outside_click_with_config_when(
+///     is_open,
+///     content_ref,
+///     Callback::new(move |_| set_is_open.set(false)),
+///     UseOutsideClickConfig {
+///         events: vec!["contextmenu"],
+///         capture: true,
+///         enabled: true,
+///     }
+/// );
+/// ```
+pub fn use_outside_click_with_config_when<E>(
+    enabled: Signal<bool>,
+    element_ref: NodeRef<E>,
+    on_outside_click: Callback<Event>,
+    config: UseOutsideClickConfig,
+) where
+    E: ElementType + 'static,
+    E::Output: JsCast + Clone + 'static,
+{
     Effect::new(move |_| {
-        if !config.enabled {
+        if !config.enabled || !enabled.get() {
+            return;
+        }
+
+        let Some(element) = element_ref.element() else {
+            return;
+        };
+
+        let Some(document) = web_sys::window().and_then(|w| w.document()) else {
             return;
+        };
+
+        let mut listeners = Vec::new();
+        for event_type in &config.events {
+            let element = element.clone();
+            let closure = wasm_bindgen::closure::Closure::wrap(Box::new(move |event: Event| {
+                if is_outside(&event, std::slice::from_ref(&element)) {
+                    on_outside_click.run(event);
+                }
+            })
+                as Box<dyn FnMut(Event)>);
+
+            let _ = document.add_event_listener_with_callback_and_bool(
+                event_type,
+                closure.as_ref().unchecked_ref(),
+                config.capture,
+            );
+            listeners.push((*event_type, closure));
         }
 
-        let element = element_ref.get();
-
-        if let Some(element) = element {
-            let callback = on_outside_click.clone();
-
-            for event_type in &config.events {
-                let callback = callback.clone();
-                let closure = wasm_bindgen::closure::Closure::wrap(Box::new(move |event: Event| {
-                    if let Some(target) = event.target() {
-                        if let Ok(target_element) = target.dyn_into::<web_sys::Element>() {
-                            if !element.contains(Some(&target_element)) {
-                                callback.run(event);
-                            }
-                        }
-                    }
-                })
-                    as Box<dyn FnMut(Event)>);
-
-                if let Some(document) = web_sys::window().and_then(|w| w.document()) {
-                    let _ = document.add_event_listener_with_callback_and_bool(
+        // Keep the closures alive until cleanup, then detach them
+        let listeners = StoredValue::new_local(listeners);
+        let capture = config.capture;
+        on_cleanup(move || {
+            let Some(listeners) = listeners.try_update_value(std::mem::take) else {
+                return;
+            };
+            if let Some(document) = web_sys::window().and_then(|w| w.document()) {
+                for (event_type, closure) in listeners {
+                    let _ = document.remove_event_listener_with_callback_and_bool(
                         event_type,
                         closure.as_ref().unchecked_ref(),
-                        config.capture,
+                        capture,
                     );
-
-                    closure.forget();
                 }
             }
-        }
+        });
     });
 }
 
-// TODO: Add proper cleanup for event listeners
 // TODO: Add tests with proper HydrationCtx
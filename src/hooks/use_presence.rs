@@ -0,0 +1,197 @@
+use leptos::html::ElementType;
+use leptos::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::{AnimationEvent, Element};
+
+/// Whether the element currently has a CSS animation applied
+fn has_animation(element: &Element) -> bool {
+    window()
+        .get_computed_style(element)
+        .ok()
+        .flatten()
+        .and_then(|style| style.get_property_value("animation-name").ok())
+        .is_some_and(|name| !name.is_empty() && name != "none")
+}
+
+/// Presence state machine (mirrors Radix's `usePresence`)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum PresenceState {
+    /// Rendered and present
+    Mounted,
+    /// No longer present, but rendered until the exit animation ends
+    UnmountSuspended,
+    /// Not rendered
+    Unmounted,
+}
+
+/// Inputs that move the presence state machine
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum PresenceEvent {
+    /// `present` turned on
+    Mount,
+    /// `present` turned off and there is no element to animate
+    Unmount,
+    /// `present` turned off while the element is rendered
+    AnimationOut,
+    /// The exit animation ended, was cancelled or never started
+    AnimationEnd,
+}
+
+impl PresenceState {
+    fn initial(present: bool) -> Self {
+        if present {
+            Self::Mounted
+        } else {
+            Self::Unmounted
+        }
+    }
+
+    /// Events that do not apply to the current state are ignored, so an
+    /// `animationend` of the enter animation keeps the element mounted.
+    fn next(self, event: PresenceEvent) -> Self {
+        match (self, event) {
+            (Self::Mounted, PresenceEvent::Unmount) => Self::Unmounted,
+            (Self::Mounted, PresenceEvent::AnimationOut) => Self::UnmountSuspended,
+            (Self::UnmountSuspended, PresenceEvent::Mount) => Self::Mounted,
+            (Self::UnmountSuspended, PresenceEvent::AnimationEnd) => Self::Unmounted,
+            (Self::Unmounted, PresenceEvent::Mount) => Self::Mounted,
+            (state, _) => state,
+        }
+    }
+}
+
+/// Presence hook: keeps an element mounted until its exit animation ends
+///
+/// `is_present` follows `present` immediately when it turns on. When it turns
+/// off, the element keeps rendering (with its closed `data-state`) until the
+/// `animationend` of its exit animation, or is removed right away when no
+/// animation applies. An exit animation that gets removed before it finishes
+/// fires `animationcancel` instead, which ends the presence just the same.
+/// A plain `<Show>` unmounts immediately, which makes `animate-out` classes
+/// useless.
+///
+/// # Example
+/// ```rust,ignore
+/// let indicator_ref = NodeRef::<Span>::new();
+/// let presence = use_presence(is_checked, indicator_ref);
+///
+/// view! {
+///     <Show when=move || presence.is_present.get()>
+///         <span
+///             node_ref=indicator_ref
+///             data-state=move || if is_checked.get() { "checked" } else { "unchecked" }
+///             on:animationend=move |ev| presence.on_animation_end.run(ev)
+///             on:animationcancel=move |ev| presence.on_animation_cancel.run(ev)
+///         />
+///     </Show>
+/// }
+/// ```
+pub fn use_presence<E>(present: Signal<bool>, node_ref: NodeRef<E>) -> UsePresenceReturn
+where
+    E: ElementType,
+    E::Output: JsCast + Clone + 'static,
+{
+    let state = RwSignal::new(PresenceState::initial(present.get_untracked()));
+    let send = move |event| state.update(|state| *state = state.next(event));
+
+    Effect::new(move |_| {
+        if present.get() {
+            send(PresenceEvent::Mount);
+            return;
+        }
+        let Some(element) = node_ref.get() else {
+            send(PresenceEvent::Unmount);
+            return;
+        };
+        send(PresenceEvent::AnimationOut);
+        let element = element.unchecked_into::<Element>();
+        // Check once the closed styles have been applied
+        request_animation_frame(move || {
+            if !has_animation(&element) {
+                send(PresenceEvent::AnimationEnd);
+            }
+        });
+    });
+
+    // Only the element's own exit animation ends its presence
+    let on_animation_end = Callback::new(move |ev: AnimationEvent| {
+        if ev.target() == ev.current_target() {
+            send(PresenceEvent::AnimationEnd);
+        }
+    });
+
+    UsePresenceReturn {
+        is_present: Signal::derive(move || state.get() != PresenceState::Unmounted),
+        on_animation_end,
+        // A cancelled exit animation will never send its animationend
+        on_animation_cancel: on_animation_end,
+    }
+}
+
+/// Return type for use_presence hook
+#[derive(Clone, Copy)]
+pub struct UsePresenceReturn {
+    /// Whether the element should be rendered
+    pub is_present: Signal<bool>,
+    /// Wire to the element's `on:animationend`
+    pub on_animation_end: Callback<AnimationEvent>,
+    /// Wire to the element's `on:animationcancel`
+    pub on_animation_cancel: Callback<AnimationEvent>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(present: bool, events: &[PresenceEvent]) -> PresenceState {
+        events
+            .iter()
+            .fold(PresenceState::initial(present), |state, event| {
+                state.next(*event)
+            })
+    }
+
+    #[test]
+    fn test_initial_state_follows_present() {
+        assert_eq!(PresenceState::initial(true), PresenceState::Mounted);
+        assert_eq!(PresenceState::initial(false), PresenceState::Unmounted);
+    }
+
+    #[test]
+    fn test_closing_waits_for_the_exit_animation() {
+        use PresenceEvent::*;
+
+        assert_eq!(run(true, &[AnimationOut]), PresenceState::UnmountSuspended);
+        assert_eq!(
+            run(true, &[AnimationOut, AnimationEnd]),
+            PresenceState::Unmounted
+        );
+    }
+
+    #[test]
+    fn test_closing_without_an_element_unmounts_at_once() {
+        assert_eq!(
+            run(true, &[PresenceEvent::Unmount]),
+            PresenceState::Unmounted
+        );
+    }
+
+    #[test]
+    fn test_reopening_during_the_exit_animation_stays_mounted() {
+        use PresenceEvent::*;
+
+        // The exit animation is replaced by the enter one and its end is ignored
+        assert_eq!(
+            run(true, &[AnimationOut, Mount, AnimationEnd]),
+            PresenceState::Mounted
+        );
+    }
+
+    #[test]
+    fn test_enter_animation_end_keeps_the_element_mounted() {
+        use PresenceEvent::*;
+
+        assert_eq!(run(false, &[Mount, AnimationEnd]), PresenceState::Mounted);
+        assert_eq!(run(false, &[AnimationEnd]), PresenceState::Unmounted);
+    }
+}
@@ -5,7 +5,7 @@ use leptos::prelude::*;
 /// Useful for animations, transitions, and comparing state changes.
 ///
 /// # Example
-/// ```rust
+/// ```rust,ignore
 /// let (count, set_count) = signal(0);
 /// let previous_count = use_previous(count);
 ///
@@ -35,7 +35,7 @@ where
 /// Only updates previous value when the comparison function returns true.
 ///
 /// # Example
-/// ```rust
+/// ```rust,ignore
 /// let (user, set_user) = signal(User { id: 1, name: "Alice" });
 /// let previous_user = use_previous_with(user, |prev, curr| prev.id != curr.id);
 ///
@@ -77,7 +77,7 @@ pub struct UsePreviousDetailedReturn<T: Send + Sync + 'static> {
 /// Hook that provides detailed information about value changes
 ///
 /// # Example
-/// ```rust
+/// ```rust,ignore
 /// let (count, set_count) = signal(0);
 /// let previous_info = use_previous_detailed(count);
 ///
@@ -4,7 +4,7 @@ use leptos::prelude::*;
 /// for progress components with value, max, and indeterminate states.
 ///
 /// # Example
-/// ```rust
+/// ```rust,ignore
 /// let progress_state = use_progress_state(
 ///     value,
 ///     max,
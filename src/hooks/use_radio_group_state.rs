@@ -1,13 +1,81 @@
+use super::use_controllable_state;
 use leptos::prelude::*;
 
-// TODO: Implement radio group state management hook
-// This will handle:
-// - Multiple radio options with single selection
-// - Keyboard navigation (arrow keys)
-// - Form integration
-// - ARIA attributes for radio group
+/// Radio group state hook: one selected value shared by every item
+/// (controlled or uncontrolled, like `use_switch_state`).
+///
+/// # Example
+/// ```rust,ignore
+/// let radio_group = use_radio_group_state(value, default_value, on_value_change);
+///
+/// // In each item
+/// view! {
+///     <button
+///         role="radio"
+///         aria-checked=move || radio_group.get_aria_checked("comfortable")
+///         data-state=move || radio_group.get_state_attr("comfortable")
+///         on:click=move |_| radio_group.select.run("comfortable".to_string())
+///     />
+/// }
+/// ```
+pub fn use_radio_group_state(
+    value: MaybeProp<String>,
+    default_value: MaybeProp<String>,
+    on_value_change: Option<Callback<String>>,
+) -> UseRadioGroupStateReturn {
+    let state = use_controllable_state(value, default_value, on_value_change);
 
-/// Placeholder for radio group state hook
-pub fn use_radio_group_state() {
-    // TODO: Implement
+    // Selecting the already selected item is a no-op (radios cannot be unchecked)
+    let select = {
+        let set_value = state.set_value;
+        move |item: String| {
+            if state.value.get_untracked() != item {
+                set_value.run(item);
+            }
+        }
+    };
+
+    UseRadioGroupStateReturn {
+        value: state.value,
+        select: Callback::new(select),
+        is_controlled: state.is_controlled,
+    }
+}
+
+/// Return type for use_radio_group_state hook
+#[derive(Clone, Copy)]
+pub struct UseRadioGroupStateReturn {
+    /// Currently selected value ("" when nothing is selected)
+    pub value: Signal<String>,
+    /// Select an item by value
+    pub select: Callback<String>,
+    /// Whether the component is in controlled mode
+    pub is_controlled: Signal<bool>,
+}
+
+impl UseRadioGroupStateReturn {
+    /// Whether `item` is the selected value (reactive)
+    pub fn is_checked(&self, item: &str) -> bool {
+        self.value.with(|value| value == item)
+    }
+
+    /// ARIA checked attribute value for `item`
+    pub fn get_aria_checked(&self, item: &str) -> &'static str {
+        if self.is_checked(item) {
+            "true"
+        } else {
+            "false"
+        }
+    }
+
+    /// Data state attribute value for `item`
+    pub fn get_state_attr(&self, item: &str) -> &'static str {
+        if self.is_checked(item) {
+            "checked"
+        } else {
+            "unchecked"
+        }
+    }
 }
+
+// TODO: Add tests with proper HydrationCtx
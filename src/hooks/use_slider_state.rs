@@ -1,14 +1,205 @@
+use super::use_controllable_state;
 use leptos::prelude::*;
 
-// TODO: Implement slider state management hook
-// This will handle:
-// - Value range management
-// - Drag and keyboard interactions
-// - Multiple thumbs for range sliders
-// - Step increments
-// - ARIA attributes for slider
+/// Snap `value` to the nearest step from `min` and clamp it to `[min, max]`
+///
+/// The result is rounded to the step's precision so `0.1` steps do not
+/// accumulate floating point noise.
+pub fn snap_to_step(value: f64, min: f64, max: f64, step: f64) -> f64 {
+    if step <= 0.0 {
+        return value.clamp(min, max);
+    }
+    let snapped = ((value - min) / step).round() * step + min;
+    let decimals = step
+        .to_string()
+        .split_once('.')
+        .map_or(0, |(_, fraction)| fraction.len()) as i32;
+    let factor = 10f64.powi(decimals);
 
-/// Placeholder for slider state hook
-pub fn use_slider_state() {
-    // TODO: Implement
+    ((snapped * factor).round() / factor).clamp(min, max)
 }
+
+/// Position of `value` within `[min, max]` as a percentage (0-100)
+pub fn value_to_percent(value: f64, min: f64, max: f64) -> f64 {
+    if max <= min {
+        return 0.0;
+    }
+    ((value - min) / (max - min) * 100.0).clamp(0.0, 100.0)
+}
+
+/// Index of the thumb value closest to `value` (the thumb a pointer grabs)
+///
+/// On a tie the later thumb wins when the pointer is past it, so stacked
+/// thumbs at the maximum can still be pulled apart.
+pub fn get_closest_value_index(values: &[f64], value: f64) -> usize {
+    let mut closest = 0;
+    for (index, current) in values.iter().enumerate() {
+        let distance = (current - value).abs();
+        let best = (values[closest] - value).abs();
+        if distance < best || (distance == best && value > *current) {
+            closest = index;
+        }
+    }
+    closest
+}
+
+/// Replace the value of thumb `index` and keep the values sorted
+///
+/// Returns the new values and the index the moved thumb ended up at, or
+/// `None` when the move would bring two thumbs closer than
+/// `min_steps_between_thumbs` steps.
+pub fn update_slider_values(
+    values: &[f64],
+    index: usize,
+    value: f64,
+    min_distance: f64,
+) -> Option<(Vec<f64>, usize)> {
+    let mut next = values.to_vec();
+    *next.get_mut(index)? = value;
+    next.sort_by(|a, b| a.total_cmp(b));
+
+    let has_space = next
+        .windows(2)
+        .all(|pair| pair[1] - pair[0] >= min_distance - f64::EPSILON);
+    if !has_space {
+        return None;
+    }
+
+    let moved_to = next
+        .iter()
+        .position(|current| *current == value)
+        .unwrap_or(index);
+    Some((next, moved_to))
+}
+
+/// Slider state hook: one or more thumb values within `min`/`max`,
+/// snapped to `step` (controlled or uncontrolled, like `use_switch_state`).
+///
+/// Value changes are reported continuously through `on_value_change`;
+/// `on_value_commit` fires once an interaction ends (pointer up, key press).
+///
+/// # Example
+/// ```rust,ignore
+/// let slider = use_slider_state(
+///     value,
+///     default_value,
+///     on_value_change,
+///     on_value_commit,
+///     min,
+///     max,
+///     step,
+///     min_steps_between_thumbs,
+/// );
+///
+/// // Move the first thumb one step up and report the final value
+/// slider.step_thumb.run((0, 1.0));
+/// slider.commit.run(());
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub fn use_slider_state(
+    value: MaybeProp<Vec<f64>>,
+    default_value: MaybeProp<Vec<f64>>,
+    on_value_change: Option<Callback<Vec<f64>>>,
+    on_value_commit: Option<Callback<Vec<f64>>>,
+    min: MaybeProp<f64>,
+    max: MaybeProp<f64>,
+    step: MaybeProp<f64>,
+    min_steps_between_thumbs: MaybeProp<f64>,
+) -> UseSliderStateReturn {
+    let current_min = Signal::derive(move || min.get().unwrap_or(0.0));
+    let current_max = Signal::derive(move || max.get().unwrap_or(100.0));
+    let current_step = Signal::derive(move || step.get().unwrap_or(1.0));
+    let min_steps = Signal::derive(move || min_steps_between_thumbs.get().unwrap_or(0.0));
+
+    let initial = default_value
+        .get_untracked()
+        .unwrap_or_else(|| vec![current_min.get_untracked()]);
+    let state = use_controllable_state(value, Some(initial).into(), on_value_change);
+
+    // Values at the start of the current interaction, to commit only real changes
+    let values_at_start = StoredValue::new(None::<Vec<f64>>);
+
+    // Set thumb `index` to a raw value; returns the thumb's index after sorting
+    let set_thumb = Callback::new(move |(index, raw): (usize, f64)| {
+        let (min, max, step) = (
+            current_min.get_untracked(),
+            current_max.get_untracked(),
+            current_step.get_untracked(),
+        );
+        let values = state.value.get_untracked();
+        if values_at_start.with_value(Option::is_none) {
+            values_at_start.set_value(Some(values.clone()));
+        }
+        let snapped = snap_to_step(raw, min, max, step);
+        match update_slider_values(&values, index, snapped, min_steps.get_untracked() * step) {
+            Some((next, moved_to)) => {
+                if next != values {
+                    state.set_value.run(next);
+                }
+                moved_to
+            }
+            None => index,
+        }
+    });
+
+    // Move thumb `index` by a number of steps
+    let step_thumb = Callback::new(move |(index, steps): (usize, f64)| {
+        let value = state
+            .value
+            .get_untracked()
+            .get(index)
+            .copied()
+            .unwrap_or_default();
+        set_thumb.run((index, value + steps * current_step.get_untracked()))
+    });
+
+    let commit = Callback::new(move |_: ()| {
+        let Some(start) = values_at_start.try_update_value(Option::take).flatten() else {
+            return;
+        };
+        let values = state.value.get_untracked();
+        if start != values {
+            if let Some(callback) = on_value_commit {
+                callback.run(values);
+            }
+        }
+    });
+
+    UseSliderStateReturn {
+        values: state.value,
+        min: current_min,
+        max: current_max,
+        step: current_step,
+        set_thumb,
+        step_thumb,
+        commit,
+        is_controlled: state.is_controlled,
+    }
+}
+
+/// Return type for use_slider_state hook
+#[derive(Clone, Copy)]
+pub struct UseSliderStateReturn {
+    /// Current thumb values, sorted ascending
+    pub values: Signal<Vec<f64>>,
+    pub min: Signal<f64>,
+    pub max: Signal<f64>,
+    pub step: Signal<f64>,
+    /// Set a thumb to a raw value (snapped and clamped); returns its new index
+    pub set_thumb: Callback<(usize, f64), usize>,
+    /// Move a thumb by a number of steps; returns its new index
+    pub step_thumb: Callback<(usize, f64), usize>,
+    /// End the current interaction and report it through `on_value_commit`
+    pub commit: Callback<()>,
+    /// Whether the component is in controlled mode
+    pub is_controlled: Signal<bool>,
+}
+
+impl UseSliderStateReturn {
+    /// Percentage position of a value along the track
+    pub fn get_percent(&self, value: f64) -> f64 {
+        value_to_percent(value, self.min.get(), self.max.get())
+    }
+}
+
+// TODO: Add tests with proper HydrationCtx
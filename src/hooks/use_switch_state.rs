@@ -5,7 +5,7 @@ use leptos::prelude::*;
 /// for switch components (simpler than checkbox - just on/off).
 ///
 /// # Example
-/// ```rust
+/// ```rust,ignore
 /// let switch_state = use_switch_state(
 ///     checked,
 ///     default_checked,
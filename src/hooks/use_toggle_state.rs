@@ -0,0 +1,71 @@
+use super::use_controllable_state;
+use leptos::prelude::*;
+
+/// Toggle-specific state hook: a pressed/unpressed button
+/// (controlled or uncontrolled, like `use_switch_state`).
+///
+/// # Example
+/// ```rust,ignore
+/// let toggle_state = use_toggle_state(pressed, default_pressed, on_pressed_change);
+///
+/// view! {
+///     <button
+///         aria-pressed=move || toggle_state.get_aria_pressed.get()
+///         data-state=move || toggle_state.get_state_attr.get()
+///         on:click=move |_| toggle_state.toggle.run(())
+///     >
+///         "B"
+///     </button>
+/// }
+/// ```
+pub fn use_toggle_state(
+    pressed: MaybeProp<bool>,
+    default_pressed: MaybeProp<bool>,
+    on_pressed_change: Option<Callback<bool>>,
+) -> UseToggleStateReturn {
+    let state = use_controllable_state(
+        pressed,
+        default_pressed.get_untracked().unwrap_or(false).into(),
+        on_pressed_change,
+    );
+
+    // Toggle function: pressed <-> unpressed
+    let toggle = {
+        let set_value = state.set_value;
+        move |_: ()| {
+            let new_value = !state.value.get_untracked();
+            set_value.run(new_value);
+        }
+    };
+
+    // ARIA pressed attribute value
+    let get_aria_pressed = Memo::new(move |_| if state.value.get() { "true" } else { "false" });
+
+    // Data state attribute value
+    let get_state_attr = Memo::new(move |_| if state.value.get() { "on" } else { "off" });
+
+    UseToggleStateReturn {
+        pressed: state.value,
+        toggle: Callback::new(toggle),
+        get_aria_pressed,
+        get_state_attr,
+        is_controlled: state.is_controlled,
+    }
+}
+
+/// Return type for use_toggle_state hook
+#[derive(Clone, Copy)]
+pub struct UseToggleStateReturn {
+    /// Current pressed state
+    pub pressed: Signal<bool>,
+    /// Toggle between pressed/unpressed
+    pub toggle: Callback<()>,
+    /// Get ARIA pressed attribute value
+    pub get_aria_pressed: Memo<&'static str>,
+    /// Get data-state attribute value ("on" or "off")
+    pub get_state_attr: Memo<&'static str>,
+    /// Whether the component is in controlled mode
+    pub is_controlled: Signal<bool>,
+}
+
+// TODO: Add tests with proper HydrationCtx
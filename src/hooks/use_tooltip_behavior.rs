@@ -1,14 +1,314 @@
+use super::{use_controllable_state, use_escape_key_when, use_related_ids};
 use leptos::prelude::*;
+use std::time::Duration;
 
-// TODO: Implement tooltip behavior hook
-// This will handle:
-// - Hover/focus show/hide logic
-// - Delay timers
-// - Positioning relative to trigger
-// - Escape key and outside click handling
-// - ARIA attributes for tooltip
-
-/// Placeholder for tooltip behavior hook
-pub fn use_tooltip_behavior() {
-    // TODO: Implement
+/// Timing configuration for tooltips (all values in milliseconds)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TooltipDelays {
+    /// Hover time before a tooltip opens
+    pub open_delay: u64,
+    /// Time before a tooltip closes once the pointer leaves
+    pub close_delay: u64,
+    /// Window after a tooltip closes during which the next one opens instantly
+    pub skip_delay: u64,
+}
+
+impl Default for TooltipDelays {
+    fn default() -> Self {
+        Self {
+            open_delay: 700,
+            close_delay: 0,
+            skip_delay: 300,
+        }
+    }
+}
+
+/// Delay group shared by every tooltip under one provider
+///
+/// Once any tooltip in the group has opened, moving to another trigger opens
+/// it without waiting, until `skip_delay` has passed with nothing open.
+#[derive(Clone, Copy)]
+pub struct TooltipDelayGroup {
+    /// Timing used by tooltips that don't override it
+    pub delays: Signal<TooltipDelays>,
+    /// Whether the next tooltip has to wait for `open_delay`
+    pub is_open_delayed: RwSignal<bool>,
+    skip_timer: StoredValue<Option<TimeoutHandle>>,
+}
+
+impl TooltipDelayGroup {
+    /// Create a group using `delays`
+    pub fn new(delays: Signal<TooltipDelays>) -> Self {
+        Self {
+            delays,
+            is_open_delayed: RwSignal::new(true),
+            skip_timer: StoredValue::new(None),
+        }
+    }
+
+    /// A tooltip in the group opened: skip the delay for its neighbours
+    pub fn on_open(&self) {
+        if let Some(handle) = self.skip_timer.try_update_value(Option::take).flatten() {
+            handle.clear();
+        }
+        self.is_open_delayed.set(false);
+    }
+
+    /// A tooltip in the group closed: restore the delay after `skip_delay`
+    pub fn on_close(&self) {
+        let group = *self;
+        let skip_delay = self.delays.get_untracked().skip_delay;
+        let handle = set_timeout_with_handle(
+            move || group.restore_delay(),
+            Duration::from_millis(skip_delay),
+        )
+        .ok();
+        if let Some(previous) = self
+            .skip_timer
+            .try_update_value(|timer| std::mem::replace(timer, handle))
+            .flatten()
+        {
+            previous.clear();
+        }
+    }
+
+    /// `skip_delay` passed with nothing open: the next tooltip waits again
+    fn restore_delay(&self) {
+        self.is_open_delayed.set(true);
+    }
+
+    /// Time the next tooltip waits before opening, `None` to open at once
+    pub(crate) fn get_open_delay(&self, delays: TooltipDelays) -> Option<u64> {
+        self.is_open_delayed
+            .get_untracked()
+            .then_some(delays.open_delay)
+    }
+}
+
+/// Time before closing once the pointer leaves, `None` to close at once
+pub(crate) fn get_close_delay(delays: TooltipDelays) -> Option<u64> {
+    (delays.close_delay > 0).then_some(delays.close_delay)
+}
+
+/// data-state for the open state and how it was reached
+fn get_tooltip_state(open: bool, was_delayed: bool) -> &'static str {
+    match (open, was_delayed) {
+        (false, _) => "closed",
+        (true, true) => "delayed-open",
+        (true, false) => "instant-open",
+    }
+}
+
+/// Return type for use_tooltip_behavior hook
+#[derive(Clone, Copy)]
+pub struct UseTooltipBehaviorReturn {
+    /// Current open state
+    pub open: Signal<bool>,
+    /// Set the open state immediately
+    pub set_open: Callback<bool>,
+    /// Get data-state attribute value ("closed" / "delayed-open" / "instant-open")
+    pub get_state_attr: Memo<&'static str>,
+    /// ID for the trigger element
+    pub trigger_id: Signal<String>,
+    /// ID for the content element (the trigger's aria-describedby)
+    pub content_id: Signal<String>,
+    /// Pointer entered the trigger: open after the group's delay
+    pub on_trigger_enter: Callback<()>,
+    /// Pointer left the trigger: close after `close_delay`
+    pub on_trigger_leave: Callback<()>,
+    /// Pointer entered the content: keep it open
+    pub on_content_enter: Callback<()>,
+    /// Pointer left the content: close after `close_delay`
+    pub on_content_leave: Callback<()>,
+    /// Keyboard focus on the trigger: open immediately
+    pub on_focus: Callback<()>,
+    /// Close immediately (blur, pointer press, Escape)
+    pub close: Callback<()>,
+}
+
+/// Hook for tooltip behavior
+///
+/// Provides:
+/// - Controlled/uncontrolled open state
+/// - Hover open/close delays, skipped when moving between triggers of the same group
+/// - Instant open on keyboard focus, instant close on blur / press / Escape
+/// - Hoverable content (moving the pointer onto the tooltip keeps it open)
+/// - Related ARIA ids for trigger and content
+///
+/// Pass the provider's group to share skip-delay state between tooltips; without
+/// one the tooltip gets a private group.
+///
+/// # Example
+/// ```rust,ignore
+/// let tooltip = use_tooltip_behavior(open, default_open, on_open_change, None, None);
+///
+/// view! {
+///     <button
+///         aria-describedby=move || tooltip.open.get().then(|| tooltip.content_id.get())
+///         on:pointerenter=move |_| tooltip.on_trigger_enter.run(())
+///         on:pointerleave=move |_| tooltip.on_trigger_leave.run(())
+///         on:focus=move |_| tooltip.on_focus.run(())
+///         on:blur=move |_| tooltip.close.run(())
+///     />
+/// }
+/// ```
+pub fn use_tooltip_behavior(
+    open: MaybeProp<bool>,
+    default_open: MaybeProp<bool>,
+    on_open_change: Option<Callback<bool>>,
+    group: Option<TooltipDelayGroup>,
+    delays: Option<Signal<TooltipDelays>>,
+) -> UseTooltipBehaviorReturn {
+    let state = use_controllable_state(
+        open,
+        default_open.get().unwrap_or(false).into(),
+        on_open_change,
+    );
+    let ids = use_related_ids("tooltip");
+
+    let group =
+        group.unwrap_or_else(|| TooltipDelayGroup::new(Signal::derive(TooltipDelays::default)));
+    let delays = delays.unwrap_or(group.delays);
+
+    let is_open = state.value;
+    let was_delayed = RwSignal::new(false);
+    let open_timer = StoredValue::new(None::<TimeoutHandle>);
+    let close_timer = StoredValue::new(None::<TimeoutHandle>);
+
+    let clear_timer = move |timer: StoredValue<Option<TimeoutHandle>>| {
+        if let Some(handle) = timer.try_update_value(Option::take).flatten() {
+            handle.clear();
+        }
+    };
+    let clear_timers = move || {
+        clear_timer(open_timer);
+        clear_timer(close_timer);
+    };
+
+    let set_open = Callback::new(move |value: bool| {
+        if value == is_open.get_untracked() {
+            return;
+        }
+        if value {
+            group.on_open();
+        } else {
+            group.on_close();
+        }
+        state.set_value.run(value);
+    });
+
+    let open_now = move |delayed: bool| {
+        clear_timers();
+        was_delayed.set(delayed);
+        set_open.run(true);
+    };
+
+    let close = Callback::new(move |_: ()| {
+        clear_timers();
+        set_open.run(false);
+    });
+
+    let schedule_close = Callback::new(move |_: ()| {
+        clear_timer(open_timer);
+        let Some(close_delay) = get_close_delay(delays.get_untracked()) else {
+            close.run(());
+            return;
+        };
+        let handle =
+            set_timeout_with_handle(move || close.run(()), Duration::from_millis(close_delay)).ok();
+        close_timer.set_value(handle);
+    });
+
+    let on_trigger_enter = Callback::new(move |_: ()| {
+        clear_timer(close_timer);
+        if is_open.get_untracked() || open_timer.with_value(Option::is_some) {
+            return;
+        }
+        let Some(open_delay) = group.get_open_delay(delays.get_untracked()) else {
+            open_now(false);
+            return;
+        };
+        let handle = set_timeout_with_handle(
+            move || {
+                open_timer.set_value(None);
+                open_now(true);
+            },
+            Duration::from_millis(open_delay),
+        )
+        .ok();
+        open_timer.set_value(handle);
+    });
+
+    let on_content_enter = Callback::new(move |_: ()| clear_timer(close_timer));
+    let on_focus = Callback::new(move |_: ()| open_now(false));
+
+    let get_state_attr = Memo::new(move |_| get_tooltip_state(is_open.get(), was_delayed.get()));
+
+    use_escape_key_when(is_open, Callback::new(move |_| close.run(())));
+
+    on_cleanup(move || {
+        clear_timer(open_timer);
+        clear_timer(close_timer);
+    });
+
+    UseTooltipBehaviorReturn {
+        open: is_open,
+        set_open,
+        get_state_attr,
+        trigger_id: ids.trigger_id,
+        content_id: ids.content_id,
+        on_trigger_enter,
+        on_trigger_leave: schedule_close,
+        on_content_enter,
+        on_content_leave: schedule_close,
+        on_focus,
+        close,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn group() -> TooltipDelayGroup {
+        TooltipDelayGroup::new(Signal::derive(TooltipDelays::default))
+    }
+
+    #[test]
+    fn test_first_tooltip_waits_for_open_delay() {
+        let group = group();
+        assert_eq!(group.get_open_delay(TooltipDelays::default()), Some(700));
+    }
+
+    #[test]
+    fn test_open_tooltip_skips_the_delay_for_its_neighbours() {
+        let group = group();
+        group.on_open();
+        assert_eq!(group.get_open_delay(TooltipDelays::default()), None);
+    }
+
+    #[test]
+    fn test_delay_is_restored_after_skip_delay() {
+        let group = group();
+        group.on_open();
+        group.restore_delay();
+        assert_eq!(group.get_open_delay(TooltipDelays::default()), Some(700));
+    }
+
+    #[test]
+    fn test_zero_close_delay_closes_at_once() {
+        assert_eq!(get_close_delay(TooltipDelays::default()), None);
+        let delays = TooltipDelays {
+            close_delay: 300,
+            ..TooltipDelays::default()
+        };
+        assert_eq!(get_close_delay(delays), Some(300));
+    }
+
+    #[test]
+    fn test_state_attribute_tells_how_the_tooltip_opened() {
+        assert_eq!(get_tooltip_state(false, true), "closed");
+        assert_eq!(get_tooltip_state(true, true), "delayed-open");
+        assert_eq!(get_tooltip_state(true, false), "instant-open");
+    }
 }
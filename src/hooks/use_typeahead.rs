@@ -0,0 +1,79 @@
+use crate::utils::typeahead_match;
+use leptos::prelude::*;
+use std::time::Duration;
+
+/// Time after the last key press before the typeahead search is cleared
+const TYPEAHEAD_RESET_MS: u64 = 1000;
+
+/// Return type for use_typeahead hook
+#[derive(Clone, Copy)]
+pub struct UseTypeaheadReturn {
+    /// Current search string (empty when no search is in progress)
+    pub search: Signal<String>,
+    /// Append a typed character given `(key, item labels, current index)`
+    /// and return the index of the item to highlight
+    pub on_key: Callback<(String, Vec<String>, Option<usize>), Option<usize>>,
+    /// Clear the search immediately
+    pub reset: Callback<()>,
+}
+
+/// Hook for typeahead ("type to select") in lists of items
+///
+/// Keys typed in quick succession build up a search string that is matched
+/// against the item labels; the search resets after a second of inactivity.
+///
+/// # Example
+/// ```rust,ignore
+/// let typeahead = use_typeahead();
+///
+/// let on_keydown = move |event: KeyboardEvent| {
+///     if event.key().chars().count() == 1 {
+///         let labels = items.iter().map(|item| item.text_content().unwrap_or_default()).collect();
+///         if let Some(index) = typeahead.on_key.run((event.key(), labels, current_index)) {
+///             let _ = items[index].focus();
+///         }
+///     }
+/// };
+/// ```
+pub fn use_typeahead() -> UseTypeaheadReturn {
+    let search = RwSignal::new(String::new());
+    let timer = StoredValue::new(None::<TimeoutHandle>);
+
+    let clear_timer = move || {
+        if let Some(handle) = timer.try_update_value(Option::take).flatten() {
+            handle.clear();
+        }
+    };
+
+    let reset = Callback::new(move |_: ()| {
+        clear_timer();
+        search.set(String::new());
+    });
+
+    let on_key = Callback::new(
+        move |(key, labels, current): (String, Vec<String>, Option<usize>)| {
+            let next_search = format!("{}{key}", search.get_untracked());
+            search.set(next_search.clone());
+
+            clear_timer();
+            let handle = set_timeout_with_handle(
+                move || search.set(String::new()),
+                Duration::from_millis(TYPEAHEAD_RESET_MS),
+            )
+            .ok();
+            timer.set_value(handle);
+
+            typeahead_match(&labels, &next_search, current)
+        },
+    );
+
+    on_cleanup(clear_timer);
+
+    UseTypeaheadReturn {
+        search: search.into(),
+        on_key,
+        reset,
+    }
+}
+
+// TODO: Add tests with proper HydrationCtx
@@ -5,7 +5,7 @@ pub mod hooks;
 pub mod utils;
 
 pub use components::*;
-pub use hooks::CheckedState;
+pub use hooks::{Align, CheckedState, Side};
 use leptos::prelude::*;
 
 /// Theme context for light/dark mode
@@ -73,48 +73,53 @@ pub fn App() -> impl IntoView {
                 Theme::Dark => "dark",
             }
         >
-            // Header with title and theme toggle
-            <header
-                class="px-4 py-4 sm:px-6 sm:py-6 bg-white data-[theme=dark]:bg-dark-bg"
-                data-theme=move || match theme.get() {
-                    Theme::Light => "light",
-                    Theme::Dark => "dark",
-                }
-            >
-                <div class="w-full flex flex-col sm:flex-row justify-between items-start sm:items-center gap-4">
-                    <div class="flex-1">
-                        <h1 class="text-2xl sm:text-3xl font-bold mb-2">
-                            "Leptographic"
-                        </h1>
-                        <p
-                            class="text-xs sm:text-sm text-gray-600 data-[theme=dark]:text-gray-400"
-                            data-theme=move || match theme.get() {
-                                Theme::Light => "light",
-                                Theme::Dark => "dark",
-                            }
-                        >
-                            "A Leptos UI system with Switch, Progress, and Separator components - styled with Tailwind CSS 4."
-                        </p>
-                    </div>
-                    <div class="flex-shrink-0">
-                        <ThemeToggle theme=theme />
+            <ToastProvider>
+                // Header with title and theme toggle
+                <header
+                    class="px-4 py-4 sm:px-6 sm:py-6 bg-white data-[theme=dark]:bg-dark-bg"
+                    data-theme=move || match theme.get() {
+                        Theme::Light => "light",
+                        Theme::Dark => "dark",
+                    }
+                >
+                    <div class="w-full flex flex-col sm:flex-row justify-between items-start sm:items-center gap-4">
+                        <div class="flex-1">
+                            <h1 class="text-2xl sm:text-3xl font-bold mb-2">
+                                "Leptographic"
+                            </h1>
+                            <p
+                                class="text-xs sm:text-sm text-gray-600 data-[theme=dark]:text-gray-400"
+                                data-theme=move || match theme.get() {
+                                    Theme::Light => "light",
+                                    Theme::Dark => "dark",
+                                }
+                            >
+                                "A Leptos UI system with Switch, Progress, and Separator components - styled with Tailwind CSS 4."
+                            </p>
+                        </div>
+                        <div class="flex-shrink-0">
+                            <ThemeToggle theme=theme />
+                        </div>
                     </div>
-                </div>
-            </header>
+                </header>
+
+                // Main content with five swimlanes
+                <main class="w-full px-4 py-2">
+                    <ComponentShowcase theme=theme />
+                </main>
 
-            // Main content with five swimlanes
-            <main class="w-full px-4 py-2">
-                <ComponentShowcase theme=theme />
-            </main>
+                // Toasts pushed with use_toast() anywhere in the app
+                <ToastViewport />
+            </ToastProvider>
         </div>
     }
 }
 
-/// Five-column component showcase inspired by Ant Design
+/// Component showcase: one vertical tab per component, one demo at a time
 #[component]
 fn ComponentShowcase(theme: RwSignal<Theme>) -> impl IntoView {
     view! {
-        <div class="flex min-h-screen">
+        <Tabs orientation="vertical" default_value="Checkbox" class="min-h-screen gap-0">
             // Swimlane 1: Component Names (always protected)
             <div
                 class="w-48 flex-shrink-0 p-2 bg-white data-[theme=dark]:bg-dark-bg"
@@ -132,67 +137,255 @@ fn ComponentShowcase(theme: RwSignal<Theme>) -> impl IntoView {
                 >
                     "Components"
                 </h3>
-                <div class="space-y-1">
-                    <ComponentNavItem name="Checkbox" active=true theme=theme />
-                    <ComponentNavItem name="Switch" active=false theme=theme />
-                    <ComponentNavItem name="Progress" active=false theme=theme />
-                    <ComponentNavItem name="Separator" active=false theme=theme />
-                </div>
+                <TabsList aria_label="Components" class="w-full bg-transparent! p-0!">
+                    <ComponentNavItem name="Checkbox" theme=theme />
+                    <ComponentNavItem name="Switch" theme=theme />
+                    <ComponentNavItem name="Progress" theme=theme />
+                    <ComponentNavItem name="Separator" theme=theme />
+                    <ComponentNavItem name="Dialog" theme=theme />
+                    <ComponentNavItem name="Alert Dialog" theme=theme />
+                    <ComponentNavItem name="Popover" theme=theme />
+                    <ComponentNavItem name="Tooltip" theme=theme />
+                    <ComponentNavItem name="Dropdown Menu" theme=theme />
+                    <ComponentNavItem name="Context Menu" theme=theme />
+                    <ComponentNavItem name="Menubar" theme=theme />
+                    <ComponentNavItem name="Navigation Menu" theme=theme />
+                    <ComponentNavItem name="Select" theme=theme />
+                    <ComponentNavItem name="Combobox" theme=theme />
+                    <ComponentNavItem name="Radio Group" theme=theme />
+                    <ComponentNavItem name="Slider" theme=theme />
+                    <ComponentNavItem name="Tabs" theme=theme />
+                    <ComponentNavItem name="Accordion" theme=theme />
+                    <ComponentNavItem name="Collapsible" theme=theme />
+                    <ComponentNavItem name="Toggle" theme=theme />
+                    <ComponentNavItem name="Toolbar" theme=theme />
+                    <ComponentNavItem name="Scroll Area" theme=theme />
+                    <ComponentNavItem name="Avatar" theme=theme />
+                    <ComponentNavItem name="Toast" theme=theme />
+                    <ComponentNavItem name="Hover Card" theme=theme />
+                    <ComponentNavItem name="Form" theme=theme />
+                    <ComponentNavItem name="One-Time Password Field" theme=theme />
+                    <ComponentNavItem name="Password Toggle Field" theme=theme />
+                </TabsList>
             </div>
 
-            // Component Cards Container
-            <div class="flex-1 grid grid-cols-1 sm:grid-cols-2 lg:grid-cols-3 xl:grid-cols-4 gap-3 p-2">
+            // Component Cards Container (the tab panels)
+            <div class="flex-1 max-w-xl p-2">
                 // Checkbox Component
-                <div>
+                <TabsContent value="Checkbox">
                     <ComponentCard title="Checkbox" theme=theme>
                         <CheckboxShowcase _theme=theme />
                     </ComponentCard>
-                </div>
+                </TabsContent>
 
                 // Switch Component
-                <div>
+                <TabsContent value="Switch">
                     <ComponentCard title="Switch" theme=theme>
                         <SwitchShowcase _theme=theme />
                     </ComponentCard>
-                </div>
+                </TabsContent>
 
                 // Progress Component
-                <div>
+                <TabsContent value="Progress">
                     <ComponentCard title="Progress" theme=theme>
                         <ProgressShowcase _theme=theme />
                     </ComponentCard>
-                </div>
+                </TabsContent>
 
                 // Separator Component
-                <div>
+                <TabsContent value="Separator">
                     <ComponentCard title="Separator" theme=theme>
                         <SeparatorShowcase _theme=theme />
                     </ComponentCard>
-                </div>
+                </TabsContent>
+
+                // Dialog Component
+                <TabsContent value="Dialog">
+                    <ComponentCard title="Dialog" theme=theme>
+                        <DialogShowcase _theme=theme />
+                    </ComponentCard>
+                </TabsContent>
+
+                // Alert Dialog Component
+                <TabsContent value="Alert Dialog">
+                    <ComponentCard title="Alert Dialog" theme=theme>
+                        <AlertDialogShowcase _theme=theme />
+                    </ComponentCard>
+                </TabsContent>
+
+                // Popover Component
+                <TabsContent value="Popover">
+                    <ComponentCard title="Popover" theme=theme>
+                        <PopoverShowcase _theme=theme />
+                    </ComponentCard>
+                </TabsContent>
+
+                // Tooltip Component
+                <TabsContent value="Tooltip">
+                    <ComponentCard title="Tooltip" theme=theme>
+                        <TooltipShowcase _theme=theme />
+                    </ComponentCard>
+                </TabsContent>
+
+                // Dropdown Menu Component
+                <TabsContent value="Dropdown Menu">
+                    <ComponentCard title="Dropdown Menu" theme=theme>
+                        <DropdownMenuShowcase _theme=theme />
+                    </ComponentCard>
+                </TabsContent>
+
+                // Context Menu Component
+                <TabsContent value="Context Menu">
+                    <ComponentCard title="Context Menu" theme=theme>
+                        <ContextMenuShowcase _theme=theme />
+                    </ComponentCard>
+                </TabsContent>
+
+                // Menubar Component
+                <TabsContent value="Menubar">
+                    <ComponentCard title="Menubar" theme=theme>
+                        <MenubarShowcase _theme=theme />
+                    </ComponentCard>
+                </TabsContent>
+
+                // Navigation Menu Component
+                <TabsContent value="Navigation Menu">
+                    <ComponentCard title="Navigation Menu" theme=theme>
+                        <NavigationMenuShowcase _theme=theme />
+                    </ComponentCard>
+                </TabsContent>
+
+                // Select Component
+                <TabsContent value="Select">
+                    <ComponentCard title="Select" theme=theme>
+                        <SelectShowcase _theme=theme />
+                    </ComponentCard>
+                </TabsContent>
+
+                // Combobox Component
+                <TabsContent value="Combobox">
+                    <ComponentCard title="Combobox" theme=theme>
+                        <ComboboxShowcase _theme=theme />
+                    </ComponentCard>
+                </TabsContent>
+
+                // Radio Group Component
+                <TabsContent value="Radio Group">
+                    <ComponentCard title="Radio Group" theme=theme>
+                        <RadioGroupShowcase _theme=theme />
+                    </ComponentCard>
+                </TabsContent>
+
+                // Slider Component
+                <TabsContent value="Slider">
+                    <ComponentCard title="Slider" theme=theme>
+                        <SliderShowcase _theme=theme />
+                    </ComponentCard>
+                </TabsContent>
+
+                // Tabs Component
+                <TabsContent value="Tabs">
+                    <ComponentCard title="Tabs" theme=theme>
+                        <TabsShowcase _theme=theme />
+                    </ComponentCard>
+                </TabsContent>
+
+                // Accordion Component
+                <TabsContent value="Accordion">
+                    <ComponentCard title="Accordion" theme=theme>
+                        <AccordionShowcase _theme=theme />
+                    </ComponentCard>
+                </TabsContent>
+
+                // Collapsible Component
+                <TabsContent value="Collapsible">
+                    <ComponentCard title="Collapsible" theme=theme>
+                        <CollapsibleShowcase _theme=theme />
+                    </ComponentCard>
+                </TabsContent>
+
+                // Toggle Component
+                <TabsContent value="Toggle">
+                    <ComponentCard title="Toggle" theme=theme>
+                        <ToggleShowcase _theme=theme />
+                    </ComponentCard>
+                </TabsContent>
+
+                // Toolbar Component
+                <TabsContent value="Toolbar">
+                    <ComponentCard title="Toolbar" theme=theme>
+                        <ToolbarShowcase _theme=theme />
+                    </ComponentCard>
+                </TabsContent>
+
+                // Scroll Area Component
+                <TabsContent value="Scroll Area">
+                    <ComponentCard title="Scroll Area" theme=theme>
+                        <ScrollAreaShowcase _theme=theme />
+                    </ComponentCard>
+                </TabsContent>
+
+                // Avatar Component
+                <TabsContent value="Avatar">
+                    <ComponentCard title="Avatar" theme=theme>
+                        <AvatarShowcase _theme=theme />
+                    </ComponentCard>
+                </TabsContent>
+
+                // Toast Component
+                <TabsContent value="Toast">
+                    <ComponentCard title="Toast" theme=theme>
+                        <ToastShowcase _theme=theme />
+                    </ComponentCard>
+                </TabsContent>
+
+                // Hover Card Component
+                <TabsContent value="Hover Card">
+                    <ComponentCard title="Hover Card" theme=theme>
+                        <HoverCardShowcase _theme=theme />
+                    </ComponentCard>
+                </TabsContent>
+
+                // Form Component
+                <TabsContent value="Form">
+                    <ComponentCard title="Form" theme=theme>
+                        <FormShowcase _theme=theme />
+                    </ComponentCard>
+                </TabsContent>
+
+                // One-Time Password Field Component
+                <TabsContent value="One-Time Password Field">
+                    <ComponentCard title="One-Time Password Field" theme=theme>
+                        <OneTimePasswordFieldShowcase _theme=theme />
+                    </ComponentCard>
+                </TabsContent>
+
+                // Password Toggle Field Component
+                <TabsContent value="Password Toggle Field">
+                    <ComponentCard title="Password Toggle Field" theme=theme>
+                        <PasswordToggleFieldShowcase _theme=theme />
+                    </ComponentCard>
+                </TabsContent>
             </div>
-        </div>
+        </Tabs>
     }
 }
 
-/// Navigation item for component list
+/// Navigation item for component list (a vertical tab of the showcase)
 #[component]
-fn ComponentNavItem(name: &'static str, active: bool, theme: RwSignal<Theme>) -> impl IntoView {
+fn ComponentNavItem(name: &'static str, theme: RwSignal<Theme>) -> impl IntoView {
     view! {
-        <div
-            class=move || format!("px-2 py-1 text-sm cursor-pointer transition-colors tracking-wide {}",
-                if active {
-                    "text-gray-900 font-normal data-[theme=dark]:text-white"
-                } else {
-                    "text-gray-500 hover:text-[#605ED6] font-light"
-                }
-            )
-            data-theme=move || match theme.get() {
+        <TabsTrigger
+            value=name
+            // Plain list styling instead of the default tab look
+            class="justify-start! px-2! py-1! text-sm tracking-wide font-light! text-gray-500! hover:text-[#605ED6]! data-[state=active]:bg-transparent! data-[state=active]:shadow-none! data-[state=active]:font-normal! data-[state=active]:text-gray-900! data-[theme=dark]:data-[state=active]:text-white!"
+            attr:data-theme=move || match theme.get() {
                 Theme::Light => "light",
                 Theme::Dark => "dark",
             }
         >
             {name}
-        </div>
+        </TabsTrigger>
     }
 }
 
@@ -217,6 +410,78 @@ fn ComponentCard(
         "Separator" => {
             "https://github.com/jakenelwood/Leptographic/blob/main/src/components/separator.rs"
         }
+        "Dialog" => {
+            "https://github.com/jakenelwood/Leptographic/blob/main/src/components/dialog.rs"
+        }
+        "Alert Dialog" => {
+            "https://github.com/jakenelwood/Leptographic/blob/main/src/components/alert_dialog.rs"
+        }
+        "Popover" => {
+            "https://github.com/jakenelwood/Leptographic/blob/main/src/components/popover.rs"
+        }
+        "Tooltip" => {
+            "https://github.com/jakenelwood/Leptographic/blob/main/src/components/tooltip.rs"
+        }
+        "Dropdown Menu" => {
+            "https://github.com/jakenelwood/Leptographic/blob/main/src/components/dropdown_menu.rs"
+        }
+        "Context Menu" => {
+            "https://github.com/jakenelwood/Leptographic/blob/main/src/components/context_menu.rs"
+        }
+        "Menubar" => {
+            "https://github.com/jakenelwood/Leptographic/blob/main/src/components/menubar.rs"
+        }
+        "Navigation Menu" => {
+            "https://github.com/jakenelwood/Leptographic/blob/main/src/components/navigation_menu.rs"
+        }
+        "Select" => {
+            "https://github.com/jakenelwood/Leptographic/blob/main/src/components/select.rs"
+        }
+        "Combobox" => {
+            "https://github.com/jakenelwood/Leptographic/blob/main/src/components/combobox.rs"
+        }
+        "Radio Group" => {
+            "https://github.com/jakenelwood/Leptographic/blob/main/src/components/radio_group.rs"
+        }
+        "Slider" => {
+            "https://github.com/jakenelwood/Leptographic/blob/main/src/components/slider.rs"
+        }
+        "Tabs" => {
+            "https://github.com/jakenelwood/Leptographic/blob/main/src/components/tabs.rs"
+        }
+        "Accordion" => {
+            "https://github.com/jakenelwood/Leptographic/blob/main/src/components/accordion.rs"
+        }
+        "Collapsible" => {
+            "https://github.com/jakenelwood/Leptographic/blob/main/src/components/collapsible.rs"
+        }
+        "Toggle" => {
+            "https://github.com/jakenelwood/Leptographic/blob/main/src/components/toggle.rs"
+        }
+        "Toolbar" => {
+            "https://github.com/jakenelwood/Leptographic/blob/main/src/components/toolbar.rs"
+        }
+        "Scroll Area" => {
+            "https://github.com/jakenelwood/Leptographic/blob/main/src/components/scroll_area.rs"
+        }
+        "Avatar" => {
+            "https://github.com/jakenelwood/Leptographic/blob/main/src/components/avatar.rs"
+        }
+        "Toast" => {
+            "https://github.com/jakenelwood/Leptographic/blob/main/src/components/toast.rs"
+        }
+        "Hover Card" => {
+            "https://github.com/jakenelwood/Leptographic/blob/main/src/components/hover_card.rs"
+        }
+        "Form" => {
+            "https://github.com/jakenelwood/Leptographic/blob/main/src/components/form.rs"
+        }
+        "One-Time Password Field" => {
+            "https://github.com/jakenelwood/Leptographic/blob/main/src/components/one_time_password_field.rs"
+        }
+        "Password Toggle Field" => {
+            "https://github.com/jakenelwood/Leptographic/blob/main/src/components/password_toggle_field.rs"
+        }
         _ => "https://github.com/jakenelwood/Leptographic/tree/main/src/components",
     };
 
@@ -259,15 +524,26 @@ fn ComponentCard(
 #[component]
 fn CheckboxShowcase(_theme: RwSignal<Theme>) -> impl IntoView {
     view! {
-        <div class="flex items-center space-x-3">
-            <Checkbox id="demo-checkbox-1">
-                <CheckboxIndicator>
-                    <CheckIconSvg />
-                </CheckboxIndicator>
-            </Checkbox>
-            <label for="demo-checkbox-1" class="cursor-pointer text-white text-base">
-                "Accept terms"
-            </label>
+        <div class="flex flex-col gap-4">
+            <div class="flex items-center space-x-3">
+                <Checkbox id="demo-checkbox-1">
+                    <CheckboxIndicator>
+                        <CheckIconSvg />
+                    </CheckboxIndicator>
+                </Checkbox>
+                <Label r#for="demo-checkbox-1">
+                    "Accept terms"
+                </Label>
+            </div>
+            // Wrapping label: no ids needed
+            <Label class="flex items-center space-x-3">
+                <Checkbox id="demo-checkbox-2" default_checked=CheckedState::True>
+                    <CheckboxIndicator>
+                        <CheckIconSvg />
+                    </CheckboxIndicator>
+                </Checkbox>
+                <span>"Send me product updates"</span>
+            </Label>
         </div>
     }
 }
@@ -280,9 +556,9 @@ fn SwitchShowcase(_theme: RwSignal<Theme>) -> impl IntoView {
             <Switch id="demo-switch-1">
                 <SwitchThumb />
             </Switch>
-            <label for="demo-switch-1" class="cursor-pointer text-white text-base">
+            <Label r#for="demo-switch-1">
                 "Enable notifications"
-            </label>
+            </Label>
         </div>
     }
 }
@@ -350,6 +626,1009 @@ fn SeparatorShowcase(_theme: RwSignal<Theme>) -> impl IntoView {
     }
 }
 
+/// Dialog component showcase
+#[component]
+fn DialogShowcase(_theme: RwSignal<Theme>) -> impl IntoView {
+    view! {
+        <Dialog>
+            <DialogTrigger class="bg-white text-black hover:bg-hover-purple">
+                "Edit profile"
+            </DialogTrigger>
+            <DialogPortal>
+                <DialogOverlay />
+                <DialogContent>
+                    <DialogTitle>"Edit profile"</DialogTitle>
+                    <DialogDescription>
+                        "Make changes to your profile here. Click save when you're done."
+                    </DialogDescription>
+                    <div class="mt-4 flex items-center gap-3">
+                        <label for="demo-dialog-name" class="text-sm w-16">"Name"</label>
+                        <input
+                            id="demo-dialog-name"
+                            class="flex-1 rounded border border-gray-300 px-2 py-1 text-sm focus:outline-none focus:ring-2 focus:ring-black"
+                            value="Pedro Duarte"
+                        />
+                    </div>
+                    <div class="mt-6 flex justify-end">
+                        <DialogClose class="bg-black text-white hover:bg-gray-800">
+                            "Save changes"
+                        </DialogClose>
+                    </div>
+                </DialogContent>
+            </DialogPortal>
+        </Dialog>
+    }
+}
+
+/// Alert Dialog component showcase
+#[component]
+fn AlertDialogShowcase(_theme: RwSignal<Theme>) -> impl IntoView {
+    view! {
+        <AlertDialog>
+            <AlertDialogTrigger class="bg-white text-black hover:bg-hover-purple">
+                "Delete workspace"
+            </AlertDialogTrigger>
+            <AlertDialogPortal>
+                <AlertDialogOverlay />
+                <AlertDialogContent>
+                    <AlertDialogTitle>"Are you absolutely sure?"</AlertDialogTitle>
+                    <AlertDialogDescription>
+                        "This action cannot be undone. This will permanently delete the workspace and all of its data."
+                    </AlertDialogDescription>
+                    <div class="mt-6 flex justify-end gap-3">
+                        <AlertDialogCancel class="bg-gray-100 text-gray-900 hover:bg-gray-200">
+                            "Cancel"
+                        </AlertDialogCancel>
+                        <AlertDialogAction class="bg-red-600 text-white hover:bg-red-700">
+                            "Yes, delete workspace"
+                        </AlertDialogAction>
+                    </div>
+                </AlertDialogContent>
+            </AlertDialogPortal>
+        </AlertDialog>
+    }
+}
+
+/// Popover component showcase
+#[component]
+fn PopoverShowcase(_theme: RwSignal<Theme>) -> impl IntoView {
+    view! {
+        <Popover>
+            <PopoverTrigger class="bg-white text-black hover:bg-hover-purple">
+                "Filters"
+            </PopoverTrigger>
+            <PopoverContent side_offset=8.0>
+                <div class="flex flex-col gap-2">
+                    <p class="text-sm font-medium">"Dimensions"</p>
+                    <label class="flex items-center justify-between gap-3 text-sm">
+                        "Width"
+                        <input
+                            class="w-28 rounded border border-gray-300 px-2 py-1 text-sm focus:outline-none focus:ring-2 focus:ring-black"
+                            value="100%"
+                        />
+                    </label>
+                    <label class="flex items-center justify-between gap-3 text-sm">
+                        "Height"
+                        <input
+                            class="w-28 rounded border border-gray-300 px-2 py-1 text-sm focus:outline-none focus:ring-2 focus:ring-black"
+                            value="25px"
+                        />
+                    </label>
+                </div>
+                <PopoverClose
+                    aria_label="Close"
+                    class="absolute top-2 right-2 h-6 w-6 rounded-full text-gray-500 hover:bg-gray-100 focus:outline-none focus:ring-2 focus:ring-black"
+                >
+                    "×"
+                </PopoverClose>
+                <PopoverArrow />
+            </PopoverContent>
+        </Popover>
+    }
+}
+
+/// Tooltip component showcase
+#[component]
+fn TooltipShowcase(_theme: RwSignal<Theme>) -> impl IntoView {
+    view! {
+        <TooltipProvider delay_duration=500_u64>
+            <div class="flex items-center gap-2">
+                <Tooltip>
+                    <TooltipTrigger class="bg-white text-black hover:bg-hover-purple">
+                        "Bold"
+                    </TooltipTrigger>
+                    <TooltipContent side_offset=6.0>
+                        "Bold (Ctrl+B)"
+                        <TooltipArrow />
+                    </TooltipContent>
+                </Tooltip>
+                <Tooltip>
+                    <TooltipTrigger class="bg-white text-black hover:bg-hover-purple">
+                        "Italic"
+                    </TooltipTrigger>
+                    <TooltipContent side_offset=6.0>
+                        "Italic (Ctrl+I)"
+                        <TooltipArrow />
+                    </TooltipContent>
+                </Tooltip>
+            </div>
+        </TooltipProvider>
+    }
+}
+
+/// Dropdown Menu component showcase
+#[component]
+fn DropdownMenuShowcase(_theme: RwSignal<Theme>) -> impl IntoView {
+    view! {
+        <DropdownMenu>
+            <DropdownMenuTrigger class="bg-white text-black hover:bg-hover-purple">
+                "Row actions"
+            </DropdownMenuTrigger>
+            <DropdownMenuContent side_offset=4.0>
+                <DropdownMenuItem>"Edit"</DropdownMenuItem>
+                <DropdownMenuItem>"Duplicate"</DropdownMenuItem>
+                <DropdownMenuSub>
+                    <DropdownMenuSubTrigger>"Move to"</DropdownMenuSubTrigger>
+                    <DropdownMenuSubContent>
+                        <DropdownMenuItem>"Backlog"</DropdownMenuItem>
+                        <DropdownMenuItem>"In progress"</DropdownMenuItem>
+                        <DropdownMenuItem>"Done"</DropdownMenuItem>
+                    </DropdownMenuSubContent>
+                </DropdownMenuSub>
+                <DropdownMenuSeparator />
+                <DropdownMenuCheckboxItem default_checked=CheckedState::True>
+                    <DropdownMenuItemIndicator>"✓"</DropdownMenuItemIndicator>
+                    "Show details"
+                </DropdownMenuCheckboxItem>
+                <DropdownMenuSeparator />
+                <DropdownMenuLabel inset=true>"Priority"</DropdownMenuLabel>
+                <DropdownMenuRadioGroup default_value="medium">
+                    <DropdownMenuRadioItem value="low">
+                        <DropdownMenuItemIndicator>"•"</DropdownMenuItemIndicator>
+                        "Low"
+                    </DropdownMenuRadioItem>
+                    <DropdownMenuRadioItem value="medium">
+                        <DropdownMenuItemIndicator>"•"</DropdownMenuItemIndicator>
+                        "Medium"
+                    </DropdownMenuRadioItem>
+                    <DropdownMenuRadioItem value="high">
+                        <DropdownMenuItemIndicator>"•"</DropdownMenuItemIndicator>
+                        "High"
+                    </DropdownMenuRadioItem>
+                </DropdownMenuRadioGroup>
+                <DropdownMenuSeparator />
+                <DropdownMenuItem disabled=true>"Archive"</DropdownMenuItem>
+            </DropdownMenuContent>
+        </DropdownMenu>
+    }
+}
+
+/// Context Menu component showcase
+#[component]
+fn ContextMenuShowcase(_theme: RwSignal<Theme>) -> impl IntoView {
+    view! {
+        <ContextMenu>
+            <ContextMenuTrigger class="flex h-24 w-40 items-center justify-center rounded border border-dashed border-white text-sm text-white focus:outline-none focus-visible:ring-2 focus-visible:ring-white">
+                "Right-click here"
+            </ContextMenuTrigger>
+            <ContextMenuContent>
+                <ContextMenuItem>"Back"</ContextMenuItem>
+                <ContextMenuItem>"Reload"</ContextMenuItem>
+                <ContextMenuSub>
+                    <ContextMenuSubTrigger>"More tools"</ContextMenuSubTrigger>
+                    <ContextMenuSubContent>
+                        <ContextMenuItem>"Save page as…"</ContextMenuItem>
+                        <ContextMenuItem>"Developer tools"</ContextMenuItem>
+                    </ContextMenuSubContent>
+                </ContextMenuSub>
+                <ContextMenuSeparator />
+                <ContextMenuCheckboxItem default_checked=CheckedState::True>
+                    <ContextMenuItemIndicator>"✓"</ContextMenuItemIndicator>
+                    "Show bookmarks"
+                </ContextMenuCheckboxItem>
+            </ContextMenuContent>
+        </ContextMenu>
+    }
+}
+
+/// Menubar component showcase
+#[component]
+fn MenubarShowcase(_theme: RwSignal<Theme>) -> impl IntoView {
+    view! {
+        <Menubar>
+            <MenubarMenu value="file">
+                <MenubarTrigger>"File"</MenubarTrigger>
+                <MenubarContent>
+                    <MenubarItem>"New Tab"</MenubarItem>
+                    <MenubarItem>"New Window"</MenubarItem>
+                    <MenubarSeparator />
+                    <MenubarSub>
+                        <MenubarSubTrigger>"Share"</MenubarSubTrigger>
+                        <MenubarSubContent>
+                            <MenubarItem>"Email link"</MenubarItem>
+                            <MenubarItem>"Messages"</MenubarItem>
+                        </MenubarSubContent>
+                    </MenubarSub>
+                    <MenubarSeparator />
+                    <MenubarItem>"Print…"</MenubarItem>
+                </MenubarContent>
+            </MenubarMenu>
+            <MenubarMenu value="edit">
+                <MenubarTrigger>"Edit"</MenubarTrigger>
+                <MenubarContent>
+                    <MenubarItem>"Undo"</MenubarItem>
+                    <MenubarItem>"Redo"</MenubarItem>
+                    <MenubarSeparator />
+                    <MenubarItem>"Cut"</MenubarItem>
+                    <MenubarItem>"Copy"</MenubarItem>
+                    <MenubarItem>"Paste"</MenubarItem>
+                </MenubarContent>
+            </MenubarMenu>
+            <MenubarMenu value="view">
+                <MenubarTrigger>"View"</MenubarTrigger>
+                <MenubarContent>
+                    <MenubarCheckboxItem default_checked=CheckedState::True>
+                        <MenubarItemIndicator>"✓"</MenubarItemIndicator>
+                        "Show toolbar"
+                    </MenubarCheckboxItem>
+                    <MenubarCheckboxItem>
+                        <MenubarItemIndicator>"✓"</MenubarItemIndicator>
+                        "Show full URLs"
+                    </MenubarCheckboxItem>
+                </MenubarContent>
+            </MenubarMenu>
+        </Menubar>
+    }
+}
+
+/// Navigation Menu component showcase
+#[component]
+fn NavigationMenuShowcase(_theme: RwSignal<Theme>) -> impl IntoView {
+    view! {
+        <div class="relative flex justify-center pb-56">
+            <NavigationMenu>
+                <NavigationMenuList>
+                    <NavigationMenuItem value="learn">
+                        <NavigationMenuTrigger>"Learn"</NavigationMenuTrigger>
+                        <NavigationMenuContent class="w-[400px]">
+                            <ul class="grid gap-2">
+                                <li>
+                                    <NavigationMenuLink href="#getting-started" class="block rounded p-2 text-sm hover:bg-gray-100">
+                                        "Getting started"
+                                    </NavigationMenuLink>
+                                </li>
+                                <li>
+                                    <NavigationMenuLink href="#styling" class="block rounded p-2 text-sm hover:bg-gray-100">
+                                        "Styling"
+                                    </NavigationMenuLink>
+                                </li>
+                                <li>
+                                    <NavigationMenuLink href="#accessibility" class="block rounded p-2 text-sm hover:bg-gray-100">
+                                        "Accessibility"
+                                    </NavigationMenuLink>
+                                </li>
+                            </ul>
+                        </NavigationMenuContent>
+                    </NavigationMenuItem>
+                    <NavigationMenuItem value="overview">
+                        <NavigationMenuTrigger>"Overview"</NavigationMenuTrigger>
+                        <NavigationMenuContent class="w-[500px]">
+                            <ul class="grid grid-cols-2 gap-2">
+                                <li>
+                                    <NavigationMenuLink href="#introduction" class="block rounded p-2 text-sm hover:bg-gray-100">
+                                        "Introduction"
+                                    </NavigationMenuLink>
+                                </li>
+                                <li>
+                                    <NavigationMenuLink href="#releases" class="block rounded p-2 text-sm hover:bg-gray-100">
+                                        "Releases"
+                                    </NavigationMenuLink>
+                                </li>
+                            </ul>
+                        </NavigationMenuContent>
+                    </NavigationMenuItem>
+                    <NavigationMenuItem>
+                        <NavigationMenuLink href="https://github.com/jakenelwood/Leptographic">
+                            "GitHub"
+                        </NavigationMenuLink>
+                    </NavigationMenuItem>
+                    <NavigationMenuIndicator />
+                </NavigationMenuList>
+                <NavigationMenuViewport />
+            </NavigationMenu>
+        </div>
+    }
+}
+
+/// Select component showcase
+#[component]
+fn SelectShowcase(_theme: RwSignal<Theme>) -> impl IntoView {
+    let (fruit, set_fruit) = signal("apple".to_string());
+
+    view! {
+        <div class="space-y-4">
+            <Select
+                name="fruit"
+                value=fruit
+                on_value_change=Callback::new(move |value| set_fruit.set(value))
+            >
+                <SelectTrigger aria_label="Fruit">
+                    <SelectValue placeholder="Select a fruit…" />
+                </SelectTrigger>
+                <SelectContent>
+                    <SelectScrollUpButton>"▲"</SelectScrollUpButton>
+                    <SelectViewport>
+                        <SelectGroup>
+                            <SelectLabel>"Fruits"</SelectLabel>
+                            <SelectItem value="apple">
+                                <SelectItemIndicator>"✓"</SelectItemIndicator>
+                                <SelectItemText>"Apple"</SelectItemText>
+                            </SelectItem>
+                            <SelectItem value="banana">
+                                <SelectItemIndicator>"✓"</SelectItemIndicator>
+                                <SelectItemText>"Banana"</SelectItemText>
+                            </SelectItem>
+                            <SelectItem value="blueberry">
+                                <SelectItemIndicator>"✓"</SelectItemIndicator>
+                                <SelectItemText>"Blueberry"</SelectItemText>
+                            </SelectItem>
+                        </SelectGroup>
+                        <SelectSeparator />
+                        <SelectGroup>
+                            <SelectLabel>"Vegetables"</SelectLabel>
+                            <SelectItem value="carrot">
+                                <SelectItemIndicator>"✓"</SelectItemIndicator>
+                                <SelectItemText>"Carrot"</SelectItemText>
+                            </SelectItem>
+                            <SelectItem value="leek" disabled=true>
+                                <SelectItemIndicator>"✓"</SelectItemIndicator>
+                                <SelectItemText>"Leek"</SelectItemText>
+                            </SelectItem>
+                        </SelectGroup>
+                    </SelectViewport>
+                    <SelectScrollDownButton>"▼"</SelectScrollDownButton>
+                </SelectContent>
+            </Select>
+            <p class="text-sm text-gray-600">"Selected: " {move || fruit.get()}</p>
+
+            <Select name="size">
+                <SelectTrigger aria_label="Size">
+                    <SelectValue placeholder="Size (popper)" />
+                </SelectTrigger>
+                <SelectContent position=SelectPosition::Popper>
+                    <SelectViewport>
+                        <SelectItem value="s">
+                            <SelectItemText>"Small"</SelectItemText>
+                        </SelectItem>
+                        <SelectItem value="m">
+                            <SelectItemText>"Medium"</SelectItemText>
+                        </SelectItem>
+                        <SelectItem value="l">
+                            <SelectItemText>"Large"</SelectItemText>
+                        </SelectItem>
+                    </SelectViewport>
+                </SelectContent>
+            </Select>
+        </div>
+    }
+}
+
+/// Combobox component showcase
+#[component]
+fn ComboboxShowcase(_theme: RwSignal<Theme>) -> impl IntoView {
+    let (query, set_query) = signal(String::new());
+    // Stand-in for a server function search
+    let people = LocalResource::new(move || {
+        let query = query.get();
+        async move {
+            [
+                "Ada Lovelace",
+                "Alan Turing",
+                "Grace Hopper",
+                "Linus Torvalds",
+                "Margaret Hamilton",
+            ]
+            .into_iter()
+            .filter(|name| combobox_filter(name, &query))
+            .map(str::to_string)
+            .collect::<Vec<_>>()
+        }
+    });
+
+    view! {
+        <div class="space-y-4">
+            <Combobox name="framework">
+                <ComboboxInput placeholder="Framework (strict)" aria_label="Framework" />
+                <ComboboxTrigger />
+                <ComboboxContent>
+                    <ComboboxEmpty>"No framework found"</ComboboxEmpty>
+                    <ComboboxItem value="leptos" text_value="Leptos">
+                        <ComboboxItemIndicator>"✓"</ComboboxItemIndicator>
+                        "Leptos"
+                    </ComboboxItem>
+                    <ComboboxItem value="dioxus" text_value="Dioxus">
+                        <ComboboxItemIndicator>"✓"</ComboboxItemIndicator>
+                        "Dioxus"
+                    </ComboboxItem>
+                    <ComboboxItem value="yew" text_value="Yew">
+                        <ComboboxItemIndicator>"✓"</ComboboxItemIndicator>
+                        "Yew"
+                    </ComboboxItem>
+                    <ComboboxItem value="sycamore" text_value="Sycamore" disabled=true>
+                        <ComboboxItemIndicator>"✓"</ComboboxItemIndicator>
+                        "Sycamore"
+                    </ComboboxItem>
+                </ComboboxContent>
+            </Combobox>
+
+            <Combobox name="tags" mode=ComboboxMode::Multiple default_value=vec!["rust".to_string()]>
+                <ComboboxChips />
+                <ComboboxInput placeholder="Add tags…" aria_label="Tags" />
+                <ComboboxContent>
+                    <ComboboxEmpty>"No tags found"</ComboboxEmpty>
+                    <ComboboxItem value="rust" text_value="Rust">
+                        <ComboboxItemIndicator>"✓"</ComboboxItemIndicator>
+                        "Rust"
+                    </ComboboxItem>
+                    <ComboboxItem value="wasm" text_value="WebAssembly">
+                        <ComboboxItemIndicator>"✓"</ComboboxItemIndicator>
+                        "WebAssembly"
+                    </ComboboxItem>
+                    <ComboboxItem value="ssr" text_value="Server rendering">
+                        <ComboboxItemIndicator>"✓"</ComboboxItemIndicator>
+                        "Server rendering"
+                    </ComboboxItem>
+                </ComboboxContent>
+            </Combobox>
+
+            <Combobox
+                name="assignee"
+                mode=ComboboxMode::FreeText
+                should_filter=false
+                loading=Signal::derive(move || people.get().is_none())
+                on_input_value_change=Callback::new(move |text| set_query.set(text))
+            >
+                <ComboboxInput placeholder="Assignee (async, free text)" aria_label="Assignee" />
+                <ComboboxContent>
+                    <ComboboxLoading>"Searching…"</ComboboxLoading>
+                    <ComboboxEmpty>"No people found"</ComboboxEmpty>
+                    {move || {
+                        people
+                            .get()
+                            .unwrap_or_default()
+                            .into_iter()
+                            .map(|name| view! { <ComboboxItem value=name.clone()>{name}</ComboboxItem> })
+                            .collect_view()
+                    }}
+                </ComboboxContent>
+            </Combobox>
+        </div>
+    }
+}
+
+/// Radio Group component showcase
+#[component]
+fn RadioGroupShowcase(_theme: RwSignal<Theme>) -> impl IntoView {
+    view! {
+        <RadioGroup name="density" default_value="comfortable" aria_label="View density">
+            <div class="flex items-center space-x-3">
+                <RadioGroupItem value="default" id="demo-radio-default">
+                    <RadioGroupIndicator />
+                </RadioGroupItem>
+                <Label r#for="demo-radio-default">
+                    "Default"
+                </Label>
+            </div>
+            <div class="flex items-center space-x-3">
+                <RadioGroupItem value="comfortable" id="demo-radio-comfortable">
+                    <RadioGroupIndicator />
+                </RadioGroupItem>
+                <Label r#for="demo-radio-comfortable">
+                    "Comfortable"
+                </Label>
+            </div>
+            <div class="flex items-center space-x-3">
+                <RadioGroupItem value="compact" id="demo-radio-compact">
+                    <RadioGroupIndicator />
+                </RadioGroupItem>
+                <Label r#for="demo-radio-compact">
+                    "Compact"
+                </Label>
+            </div>
+        </RadioGroup>
+    }
+}
+
+/// Slider component showcase
+#[component]
+fn SliderShowcase(_theme: RwSignal<Theme>) -> impl IntoView {
+    let (volume, set_volume) = signal(vec![50.0]);
+    let (committed, set_committed) = signal(vec![20.0, 80.0]);
+
+    view! {
+        <div class="space-y-6">
+            <div class="space-y-2">
+                <Slider
+                    name="volume"
+                    value=volume
+                    on_value_change=Callback::new(move |value| set_volume.set(value))
+                >
+                    <SliderTrack>
+                        <SliderRange />
+                    </SliderTrack>
+                    <SliderThumb aria_label="Volume" />
+                </Slider>
+                <p class="text-sm text-gray-400">"Volume: " {move || volume.get()[0]}</p>
+            </div>
+            <div class="space-y-2">
+                <Slider
+                    name="price"
+                    default_value=vec![20.0, 80.0]
+                    step=5.0
+                    min_steps_between_thumbs=2.0
+                    on_value_commit=Callback::new(move |value| set_committed.set(value))
+                >
+                    <SliderTrack>
+                        <SliderRange />
+                    </SliderTrack>
+                    <SliderThumb index=0 aria_label="Minimum price" />
+                    <SliderThumb index=1 aria_label="Maximum price" />
+                </Slider>
+                <p class="text-sm text-gray-400">
+                    "Committed range: " {move || format!("{:?}", committed.get())}
+                </p>
+            </div>
+            <Slider orientation="vertical" default_value=vec![30.0] inverted=true>
+                <SliderTrack>
+                    <SliderRange />
+                </SliderTrack>
+                <SliderThumb aria_label="Level" />
+            </Slider>
+        </div>
+    }
+}
+
+/// Tabs component showcase
+#[component]
+fn TabsShowcase(_theme: RwSignal<Theme>) -> impl IntoView {
+    let (tab, set_tab) = signal("account".to_string());
+
+    view! {
+        <div class="space-y-4">
+            <Tabs value=tab on_value_change=Callback::new(move |value| set_tab.set(value))>
+                <TabsList aria_label="Manage your account">
+                    <TabsTrigger value="account">"Account"</TabsTrigger>
+                    <TabsTrigger value="password">"Password"</TabsTrigger>
+                    <TabsTrigger value="billing" disabled=true>"Billing"</TabsTrigger>
+                </TabsList>
+                <TabsContent value="account">"Make changes to your account here."</TabsContent>
+                <TabsContent value="password" force_mount=true>
+                    "Change your password here."
+                </TabsContent>
+            </Tabs>
+            <Tabs
+                default_value="one"
+                orientation="vertical"
+                activation_mode=TabsActivationMode::Manual
+            >
+                <TabsList aria_label="Manual activation">
+                    <TabsTrigger value="one">"One"</TabsTrigger>
+                    <TabsTrigger value="two">"Two"</TabsTrigger>
+                </TabsList>
+                <TabsContent value="one">"Arrow keys move focus; Enter activates."</TabsContent>
+                <TabsContent value="two">"Second panel"</TabsContent>
+            </Tabs>
+        </div>
+    }
+}
+
+/// Accordion component showcase
+#[component]
+fn AccordionShowcase(_theme: RwSignal<Theme>) -> impl IntoView {
+    view! {
+        <div class="space-y-4">
+            <Accordion r#type="single" collapsible=true default_value=vec!["shipping".to_string()]>
+                <AccordionItem value="shipping">
+                    <AccordionHeader>
+                        <AccordionTrigger>"Do you ship abroad?" <span aria-hidden="true">"▾"</span></AccordionTrigger>
+                    </AccordionHeader>
+                    <AccordionContent>
+                        <p class="pb-2">"Yes, we ship to most countries."</p>
+                    </AccordionContent>
+                </AccordionItem>
+                <AccordionItem value="returns">
+                    <AccordionHeader>
+                        <AccordionTrigger>"Can I return an order?"</AccordionTrigger>
+                    </AccordionHeader>
+                    <AccordionContent>
+                        <p class="pb-2">"Within 30 days of delivery."</p>
+                    </AccordionContent>
+                </AccordionItem>
+                <AccordionItem value="gift" disabled=true>
+                    <AccordionHeader>
+                        <AccordionTrigger>"Gift wrapping (coming soon)"</AccordionTrigger>
+                    </AccordionHeader>
+                    <AccordionContent>"Not available yet."</AccordionContent>
+                </AccordionItem>
+            </Accordion>
+            <Accordion r#type="multiple">
+                <AccordionItem value="notifications">
+                    <AccordionHeader>
+                        <AccordionTrigger>"Notifications"</AccordionTrigger>
+                    </AccordionHeader>
+                    <AccordionContent>
+                        <p class="pb-2">"Email and push settings."</p>
+                    </AccordionContent>
+                </AccordionItem>
+                <AccordionItem value="privacy">
+                    <AccordionHeader>
+                        <AccordionTrigger>"Privacy"</AccordionTrigger>
+                    </AccordionHeader>
+                    <AccordionContent>
+                        <p class="pb-2">"Who can see your profile."</p>
+                    </AccordionContent>
+                </AccordionItem>
+            </Accordion>
+        </div>
+    }
+}
+
+/// Collapsible component showcase
+#[component]
+fn CollapsibleShowcase(_theme: RwSignal<Theme>) -> impl IntoView {
+    let (open, set_open) = signal(false);
+
+    view! {
+        <div class="space-y-2">
+            <Collapsible open=open on_open_change=Callback::new(move |value| set_open.set(value))>
+                <div class="flex items-center justify-between">
+                    <span class="text-sm text-white">"@leptos starred 3 repositories"</span>
+                    <CollapsibleTrigger class="rounded bg-white px-2 py-1 text-black">
+                        {move || if open.get() { "Hide" } else { "Show" }}
+                    </CollapsibleTrigger>
+                </div>
+                <div class="mt-2 rounded bg-white/10 px-3 py-1 text-sm text-white">"leptos-rs/leptos"</div>
+                <CollapsibleContent>
+                    <div class="mt-2 rounded bg-white/10 px-3 py-1 text-sm text-white">"leptos-rs/cargo-leptos"</div>
+                    <div class="mt-2 rounded bg-white/10 px-3 py-1 text-sm text-white">"leptos-rs/leptos-use"</div>
+                </CollapsibleContent>
+            </Collapsible>
+        </div>
+    }
+}
+
+/// Toggle component showcase
+#[component]
+fn ToggleShowcase(_theme: RwSignal<Theme>) -> impl IntoView {
+    let (view_mode, set_view_mode) = signal(vec!["list".to_string()]);
+
+    view! {
+        <div class="space-y-3">
+            <Toggle aria_label="Toggle bold" default_pressed=true>
+                <strong>"B"</strong>
+            </Toggle>
+            <ToggleGroup r#type="multiple" aria_label="Text formatting">
+                <ToggleGroupItem value="bold" aria_label="Bold"><strong>"B"</strong></ToggleGroupItem>
+                <ToggleGroupItem value="italic" aria_label="Italic"><em>"I"</em></ToggleGroupItem>
+                <ToggleGroupItem value="underline" aria_label="Underline"><u>"U"</u></ToggleGroupItem>
+            </ToggleGroup>
+            <ToggleGroup
+                aria_label="View"
+                value=view_mode
+                on_value_change=Callback::new(move |value| set_view_mode.set(value))
+            >
+                <ToggleGroupItem value="list">"List"</ToggleGroupItem>
+                <ToggleGroupItem value="grid">"Grid"</ToggleGroupItem>
+            </ToggleGroup>
+        </div>
+    }
+}
+
+/// Toolbar component showcase
+#[component]
+fn ToolbarShowcase(_theme: RwSignal<Theme>) -> impl IntoView {
+    view! {
+        <Toolbar aria_label="Formatting options">
+            <ToolbarToggleGroup r#type="multiple" aria_label="Text formatting">
+                <ToolbarToggleItem value="bold" aria_label="Bold"><strong>"B"</strong></ToolbarToggleItem>
+                <ToolbarToggleItem value="italic" aria_label="Italic"><em>"I"</em></ToolbarToggleItem>
+                <ToolbarToggleItem value="underline" aria_label="Underline"><u>"U"</u></ToolbarToggleItem>
+            </ToolbarToggleGroup>
+            <ToolbarSeparator />
+            <ToolbarToggleGroup default_value=vec!["left".to_string()] aria_label="Text alignment">
+                <ToolbarToggleItem value="left" aria_label="Left aligned">"L"</ToolbarToggleItem>
+                <ToolbarToggleItem value="center" aria_label="Center aligned">"C"</ToolbarToggleItem>
+            </ToolbarToggleGroup>
+            <ToolbarSeparator />
+            <ToolbarLink href="#" class="ml-auto">"Edited 2h ago"</ToolbarLink>
+            <ToolbarButton>"Share"</ToolbarButton>
+        </Toolbar>
+    }
+}
+
+/// Scroll Area component showcase
+#[component]
+fn ScrollAreaShowcase(_theme: RwSignal<Theme>) -> impl IntoView {
+    let tags = (1..=40)
+        .map(|n| format!("v1.2.0-beta.{n}"))
+        .collect::<Vec<_>>();
+
+    view! {
+        <ScrollArea class="h-72 w-48 rounded border border-white/10">
+            <ScrollAreaViewport>
+                <div class="px-5 py-4">
+                    <h4 class="mb-4 text-sm font-medium text-white">"Tags"</h4>
+                    {tags
+                        .into_iter()
+                        .map(|tag| {
+                            view! {
+                                <div class="mt-2.5 border-t border-white/10 pt-2.5 text-sm text-white/80">
+                                    {tag}
+                                </div>
+                            }
+                        })
+                        .collect_view()}
+                </div>
+            </ScrollAreaViewport>
+            <ScrollAreaScrollbar orientation="vertical">
+                <ScrollAreaThumb />
+            </ScrollAreaScrollbar>
+            <ScrollAreaScrollbar orientation="horizontal">
+                <ScrollAreaThumb />
+            </ScrollAreaScrollbar>
+            <ScrollAreaCorner />
+        </ScrollArea>
+    }
+}
+
+/// Avatar component showcase
+#[component]
+fn AvatarShowcase(_theme: RwSignal<Theme>) -> impl IntoView {
+    view! {
+        <div class="flex items-center gap-4">
+            <Avatar>
+                <AvatarImage src="https://github.com/gbj.png" alt="Greg Johnston" />
+                <AvatarFallback delay_ms=600>"GJ"</AvatarFallback>
+            </Avatar>
+            // Broken URL: falls back to the initials
+            <Avatar>
+                <AvatarImage src="/missing-avatar.png" alt="Pedro Duarte" />
+                <AvatarFallback>"PD"</AvatarFallback>
+            </Avatar>
+            <Avatar>
+                <AvatarFallback>"CT"</AvatarFallback>
+            </Avatar>
+        </div>
+    }
+}
+
+/// Toast component showcase
+#[component]
+fn ToastShowcase(_theme: RwSignal<Theme>) -> impl IntoView {
+    let toasts = use_toast();
+    let button_class = "rounded px-3 py-2 text-sm font-medium bg-white text-black hover:bg-hover-purple cursor-pointer";
+
+    view! {
+        <div class="flex flex-wrap gap-3">
+            <button
+                type="button"
+                class=button_class
+                on:click=move |_| {
+                    toasts
+                        .toast
+                        .run(ToastOptions {
+                            title: "Scheduled: Catch up".to_string(),
+                            description: Some("Wednesday, February 10, 2027 at 5:57 PM".to_string()),
+                            action: Some(ToastActionOptions {
+                                label: "Undo".to_string(),
+                                alt_text: "Goto schedule to undo".to_string(),
+                                on_click: Callback::new(|_| {}),
+                            }),
+                            ..Default::default()
+                        });
+                }
+            >
+                "Add to calendar"
+            </button>
+            <button
+                type="button"
+                class=button_class
+                on:click=move |_| {
+                    toasts
+                        .toast
+                        .run(ToastOptions {
+                            title: "Could not save".to_string(),
+                            description: Some("Check your connection and try again.".to_string()),
+                            duration: Some(10_000),
+                            ..Default::default()
+                        });
+                }
+            >
+                "Simulate save error"
+            </button>
+        </div>
+    }
+}
+
+/// Hover Card component showcase
+#[component]
+fn HoverCardShowcase(_theme: RwSignal<Theme>) -> impl IntoView {
+    view! {
+        <p class="text-sm">
+            "Reported by "
+            <HoverCard>
+                <HoverCardTrigger href="https://github.com/leptos-rs">"@leptos-rs"</HoverCardTrigger>
+                <HoverCardContent side_offset=6.0>
+                    <div class="flex gap-3">
+                        <Avatar>
+                            <AvatarImage src="https://github.com/leptos-rs.png" alt="Leptos" />
+                            <AvatarFallback delay_ms=600>"L"</AvatarFallback>
+                        </Avatar>
+                        <div class="flex flex-col gap-1">
+                            <span class="font-bold">"Leptos"</span>
+                            <span class="text-white/70">"@leptos-rs"</span>
+                            <span>"Build fast web applications with Rust."</span>
+                        </div>
+                    </div>
+                    <HoverCardArrow />
+                </HoverCardContent>
+            </HoverCard>
+            " in #1234."
+        </p>
+    }
+}
+
+/// Form component showcase
+#[component]
+fn FormShowcase(_theme: RwSignal<Theme>) -> impl IntoView {
+    // Pretend the server already has this address
+    let email_taken = RwSignal::new(false);
+    let submitted = RwSignal::new(None::<String>);
+
+    view! {
+        <div class="w-full max-w-sm">
+            <Form
+                on_submit=move |ev: leptos::ev::SubmitEvent| {
+                    ev.prevent_default();
+                    use wasm_bindgen::JsCast;
+                    let email = ev
+                        .target()
+                        .and_then(|form| form.dyn_into::<web_sys::Element>().ok())
+                        .and_then(|form| form.query_selector("[name='email']").ok().flatten())
+                        .and_then(|input| input.dyn_into::<web_sys::HtmlInputElement>().ok())
+                        .map(|input| input.value())
+                        .unwrap_or_default();
+                    if email == "taken@example.com" {
+                        email_taken.set(true);
+                        submitted.set(None);
+                    } else {
+                        submitted.set(Some(email));
+                    }
+                }
+                on_clear_server_errors=move |_| email_taken.set(false)
+            >
+                <FormField name="email" server_invalid=email_taken>
+                    <FormLabel>"Email"</FormLabel>
+                    <FormControl
+                        r#type="email"
+                        required=true
+                        placeholder="taken@example.com"
+                    />
+                    <FormMessage>"We will never share your email."</FormMessage>
+                    <FormMessage r#match=FormMatch::ValueMissing class="text-red-400">
+                        "Please enter your email"
+                    </FormMessage>
+                    <FormMessage r#match=FormMatch::TypeMismatch class="text-red-400">
+                        "Please provide a valid email"
+                    </FormMessage>
+                    <FormMessage force_match=email_taken class="text-red-400">
+                        "This email is already registered"
+                    </FormMessage>
+                </FormField>
+                <FormField name="username">
+                    <FormLabel>"Username"</FormLabel>
+                    <FormControl required=true min_length=3_u32 />
+                    <FormMessage r#match=FormMatch::TooShort class="text-red-400" />
+                    <FormMessage
+                        r#match=FormMatch::Custom(
+                            Callback::new(|value: String| value.contains(' ')),
+                        )
+                        class="text-red-400"
+                    >
+                        "Usernames cannot contain spaces"
+                    </FormMessage>
+                    <FormValidityState let:validity>
+                        <span class="text-xs text-gray-400">
+                            {validity
+                                .map(|validity| if validity.valid { "✓ Looks good" } else { "" })}
+                        </span>
+                    </FormValidityState>
+                </FormField>
+                <FormSubmit>"Sign up"</FormSubmit>
+                {move || {
+                    submitted
+                        .get()
+                        .map(|email| {
+                            view! { <p class="text-sm text-white">"Signed up as " {email}</p> }
+                        })
+                }}
+            </Form>
+        </div>
+    }
+}
+
+/// One-Time Password Field component showcase
+#[component]
+fn OneTimePasswordFieldShowcase(_theme: RwSignal<Theme>) -> impl IntoView {
+    let code = RwSignal::new(String::new());
+    let verified = RwSignal::new(None::<String>);
+
+    view! {
+        <form
+            class="flex flex-col gap-4"
+            on:submit=move |ev: leptos::ev::SubmitEvent| {
+                ev.prevent_default();
+                verified.set(Some(code.get_untracked()));
+            }
+        >
+            <OneTimePasswordField
+                name="code"
+                value=code
+                on_value_change=move |value: String| {
+                    verified.set(None);
+                    code.set(value);
+                }
+                auto_submit=true
+            >
+                {(0..6).map(|index| view! { <OneTimePasswordFieldInput index=index /> }).collect_view()}
+            </OneTimePasswordField>
+            <p class="text-sm text-gray-400">
+                {move || match verified.get() {
+                    Some(code) => format!("Submitted {code}"),
+                    None => "Type or paste a 6-digit code".to_string(),
+                }}
+            </p>
+        </form>
+    }
+}
+
+/// Password Toggle Field component showcase
+#[component]
+fn PasswordToggleFieldShowcase(_theme: RwSignal<Theme>) -> impl IntoView {
+    let submitted = RwSignal::new(false);
+
+    view! {
+        <form
+            class="flex w-full max-w-sm flex-col gap-3"
+            on:submit=move |ev: leptos::ev::SubmitEvent| {
+                ev.prevent_default();
+                submitted.set(true);
+            }
+        >
+            <Label r#for="demo-password">"Password"</Label>
+            <PasswordToggleField id="demo-password">
+                <div class="relative">
+                    <PasswordToggleFieldInput
+                        name="password"
+                        autocomplete="new-password"
+                        placeholder="At least 8 characters"
+                    />
+                    <PasswordToggleFieldToggle show_label="Show password" hide_label="Hide password" />
+                </div>
+            </PasswordToggleField>
+            <button
+                type="submit"
+                class="rounded px-3 py-2 text-sm font-medium bg-white text-black hover:bg-hover-purple cursor-pointer"
+            >
+                "Save"
+            </button>
+            <Show when=move || submitted.get()>
+                <p class="text-sm text-gray-400">"Saved - the password is hidden again"</p>
+            </Show>
+        </form>
+    }
+}
+
 #[cfg(feature = "hydrate")]
 #[wasm_bindgen::prelude::wasm_bindgen]
 pub fn hydrate() {
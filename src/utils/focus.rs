@@ -0,0 +1,72 @@
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement};
+
+/// Selector matching elements that can receive keyboard focus
+pub const FOCUSABLE_SELECTOR: &str = "a[href]:not([tabindex='-1']), button:not([disabled]):not([tabindex='-1']), input:not([disabled]):not([type='hidden']):not([tabindex='-1']), select:not([disabled]):not([tabindex='-1']), textarea:not([disabled]):not([tabindex='-1']), [tabindex]:not([tabindex='-1'])";
+
+/// Collect the elements inside `container` matching `selector`, in DOM order
+pub fn query_elements(container: &Element, selector: &str) -> Vec<HtmlElement> {
+    let Ok(nodes) = container.query_selector_all(selector) else {
+        return Vec::new();
+    };
+
+    (0..nodes.length())
+        .filter_map(|index| nodes.item(index))
+        .filter_map(|node| node.dyn_into::<HtmlElement>().ok())
+        .collect()
+}
+
+/// Collect the focusable elements inside `container`, skipping hidden ones
+pub fn get_focusable_elements(container: &Element, selector: &str) -> Vec<HtmlElement> {
+    query_elements(container, selector)
+        .into_iter()
+        .filter(|element| !element.hidden() && element.get_client_rects().length() > 0)
+        .collect()
+}
+
+/// The element that currently has focus, if any
+pub fn active_element() -> Option<HtmlElement> {
+    web_sys::window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.active_element())
+        .and_then(|element| element.dyn_into::<HtmlElement>().ok())
+}
+
+/// Whether `element` is `container` itself or one of its descendants
+pub fn contains_element(container: &Element, element: &Element) -> bool {
+    container.contains(Some(element))
+}
+
+/// Focus the first focusable element in `container`, or the container itself
+///
+/// Returns `true` when focus was moved to a descendant.
+pub fn focus_first(container: &Element, selector: &str) -> bool {
+    if let Some(first) = get_focusable_elements(container, selector).first() {
+        let _ = first.focus();
+        return true;
+    }
+
+    if let Some(container) = container.dyn_ref::<HtmlElement>() {
+        let _ = container.focus();
+    }
+    false
+}
+
+/// Focus the last focusable element in `container`
+pub fn focus_last(container: &Element, selector: &str) -> bool {
+    if let Some(last) = get_focusable_elements(container, selector).last() {
+        let _ = last.focus();
+        return true;
+    }
+    false
+}
+
+/// Focus an element by id, returning `true` when the element exists
+pub fn focus_by_id(id: &str) -> bool {
+    web_sys::window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.get_element_by_id(id))
+        .and_then(|element| element.dyn_into::<HtmlElement>().ok())
+        .map(|element| element.focus().is_ok())
+        .unwrap_or(false)
+}
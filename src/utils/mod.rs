@@ -1 +1,6 @@
 // Utility modules will be added as needed
+pub mod focus;
+pub mod navigation;
+
+pub use focus::*;
+pub use navigation::*;
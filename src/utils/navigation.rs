@@ -0,0 +1,137 @@
+/// Keyboard movement within a list of items (menus, toolbars, tab lists, ...)
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Movement {
+    First,
+    Last,
+    Next,
+    Previous,
+}
+
+/// Index reached by applying `movement` to `current` in a list of `len` items
+///
+/// With no current item, Next starts at the first item and Previous at the last.
+/// Without `looping`, moving past either end stays on the end item.
+pub fn move_index(
+    current: Option<usize>,
+    len: usize,
+    movement: Movement,
+    looping: bool,
+) -> Option<usize> {
+    if len == 0 {
+        return None;
+    }
+    let last = len - 1;
+
+    let next = match (movement, current) {
+        (Movement::First, _) => 0,
+        (Movement::Last, _) => last,
+        (Movement::Next, None) => 0,
+        (Movement::Previous, None) => last,
+        (Movement::Next, Some(index)) if index >= last => {
+            if looping {
+                0
+            } else {
+                last
+            }
+        }
+        (Movement::Next, Some(index)) => index + 1,
+        (Movement::Previous, Some(0)) => {
+            if looping {
+                last
+            } else {
+                0
+            }
+        }
+        (Movement::Previous, Some(index)) => index.min(len) - 1,
+    };
+
+    Some(next)
+}
+
+/// Keys that jump to the first / last item of a roving focus group
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum JumpKeys {
+    /// Only arrow keys move (radio groups)
+    None,
+    /// Home and End (accordions)
+    HomeEnd,
+    /// Home/PageUp and End/PageDown (tab lists, toolbars, toggle groups)
+    HomeEndPage,
+}
+
+/// Movement for a key in a roving focus group (radio groups, tab lists, toolbars, ...)
+///
+/// Without an orientation every arrow key moves; with one only the arrows
+/// along it do. `rtl` swaps Left and Right; `jump_keys` picks the keys that
+/// go to the ends.
+pub fn get_roving_focus_movement(
+    key: &str,
+    orientation: Option<&str>,
+    rtl: bool,
+    jump_keys: JumpKeys,
+) -> Option<Movement> {
+    let horizontal = orientation != Some("vertical");
+    let vertical = orientation != Some("horizontal");
+    let (left, right) = if rtl {
+        (Movement::Next, Movement::Previous)
+    } else {
+        (Movement::Previous, Movement::Next)
+    };
+    let page_keys = jump_keys == JumpKeys::HomeEndPage;
+    let jumps = jump_keys != JumpKeys::None;
+
+    match key {
+        "ArrowDown" if vertical => Some(Movement::Next),
+        "ArrowUp" if vertical => Some(Movement::Previous),
+        "ArrowRight" if horizontal => Some(right),
+        "ArrowLeft" if horizontal => Some(left),
+        "Home" if jumps => Some(Movement::First),
+        "End" if jumps => Some(Movement::Last),
+        "PageUp" if page_keys => Some(Movement::First),
+        "PageDown" if page_keys => Some(Movement::Last),
+        _ => None,
+    }
+}
+
+/// Item to highlight for a typeahead `search` over `labels`
+///
+/// Matching is case-insensitive and starts after the current item, wrapping
+/// around. Repeating one character ("aaa") cycles through the items starting
+/// with it. Returns `None` when nothing else matches.
+pub fn typeahead_match(labels: &[String], search: &str, current: Option<usize>) -> Option<usize> {
+    let mut chars = search.chars();
+    let first = chars.next()?;
+    let is_repeated = chars.all(|c| c == first);
+    let search = if is_repeated {
+        first.to_lowercase().collect::<String>()
+    } else {
+        search.to_lowercase()
+    };
+    let single_char = search.chars().count() == 1;
+
+    let start = current.unwrap_or(0).min(labels.len());
+    let next = (0..labels.len())
+        .map(|offset| (start + offset) % labels.len())
+        .filter(|index| !(single_char && Some(*index) == current))
+        .find(|index| labels[*index].trim().to_lowercase().starts_with(&search));
+
+    next.filter(|index| Some(*index) != current)
+}
+
+/// Whether `point` lies inside `polygon` (ray casting, vertices in order)
+pub fn is_point_in_polygon(point: (f64, f64), polygon: &[(f64, f64)]) -> bool {
+    let (x, y) = point;
+    let mut inside = false;
+
+    let mut j = polygon.len().wrapping_sub(1);
+    for i in 0..polygon.len() {
+        let (xi, yi) = polygon[i];
+        let (xj, yj) = polygon[j];
+        if (yi > y) != (yj > y) && x < (xj - xi) * (y - yi) / (yj - yi) + xi {
+            inside = !inside;
+        }
+        j = i;
+    }
+
+    inside
+}
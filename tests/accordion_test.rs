@@ -0,0 +1,27 @@
+use leptos_radix_ui::get_accordion_movement;
+use leptos_radix_ui::utils::Movement;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_header_movement() {
+        assert_eq!(
+            get_accordion_movement("ArrowDown", "vertical", false),
+            Some(Movement::Next)
+        );
+        assert_eq!(
+            get_accordion_movement("ArrowRight", "vertical", false),
+            None
+        );
+        assert_eq!(
+            get_accordion_movement("ArrowRight", "horizontal", true),
+            Some(Movement::Previous)
+        );
+        assert_eq!(
+            get_accordion_movement("End", "vertical", false),
+            Some(Movement::Last)
+        );
+    }
+}
@@ -0,0 +1,15 @@
+use leptos_radix_ui::hooks::ImageLoadingStatus;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_image_loading_status_attribute_values() {
+        assert_eq!(ImageLoadingStatus::default(), ImageLoadingStatus::Idle);
+        assert_eq!(ImageLoadingStatus::Idle.to_string(), "idle");
+        assert_eq!(ImageLoadingStatus::Loading.to_string(), "loading");
+        assert_eq!(ImageLoadingStatus::Loaded.to_string(), "loaded");
+        assert_eq!(ImageLoadingStatus::Error.to_string(), "error");
+    }
+}
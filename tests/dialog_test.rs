@@ -0,0 +1,16 @@
+use leptos_radix_ui::hooks::UseDialogBehaviorOptions;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dialog_behavior_defaults_to_dismissable_modal() {
+        let options = UseDialogBehaviorOptions::default();
+        assert!(options.modal);
+        assert!(options.close_on_escape);
+        assert!(options.close_on_outside_click);
+        assert_eq!(options.initial_focus, None);
+        assert_eq!(options.id_prefix, "dialog");
+    }
+}
@@ -0,0 +1,61 @@
+use leptos_radix_ui::utils::{is_point_in_polygon, move_index, typeahead_match, Movement};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn labels(items: &[&str]) -> Vec<String> {
+        items.iter().map(|item| item.to_string()).collect()
+    }
+
+    #[test]
+    fn test_move_index_without_looping() {
+        assert_eq!(move_index(None, 3, Movement::Next, false), Some(0));
+        assert_eq!(move_index(None, 3, Movement::Previous, false), Some(2));
+        assert_eq!(move_index(Some(1), 3, Movement::Next, false), Some(2));
+        assert_eq!(move_index(Some(2), 3, Movement::Next, false), Some(2));
+        assert_eq!(move_index(Some(0), 3, Movement::Previous, false), Some(0));
+        assert_eq!(move_index(Some(1), 3, Movement::First, false), Some(0));
+        assert_eq!(move_index(Some(1), 3, Movement::Last, false), Some(2));
+        assert_eq!(move_index(None, 0, Movement::Next, false), None);
+    }
+
+    #[test]
+    fn test_move_index_with_looping() {
+        assert_eq!(move_index(Some(2), 3, Movement::Next, true), Some(0));
+        assert_eq!(move_index(Some(0), 3, Movement::Previous, true), Some(2));
+    }
+
+    #[test]
+    fn test_typeahead_matches_prefix_after_current() {
+        let items = labels(&["Copy", "Cut", "Paste", "Crop"]);
+        assert_eq!(typeahead_match(&items, "p", None), Some(2));
+        assert_eq!(typeahead_match(&items, "cr", Some(0)), Some(3));
+        assert_eq!(typeahead_match(&items, "CU", None), Some(1));
+        assert_eq!(typeahead_match(&items, "x", None), None);
+    }
+
+    #[test]
+    fn test_typeahead_repeated_character_cycles() {
+        let items = labels(&["Copy", "Cut", "Paste", "Crop"]);
+        assert_eq!(typeahead_match(&items, "c", Some(0)), Some(1));
+        assert_eq!(typeahead_match(&items, "cc", Some(1)), Some(3));
+        assert_eq!(typeahead_match(&items, "ccc", Some(3)), Some(0));
+    }
+
+    #[test]
+    fn test_point_in_grace_polygon() {
+        // Fan from the pointer exit point to a submenu on the right
+        let polygon = [
+            (95.0, 50.0),
+            (120.0, 20.0),
+            (220.0, 20.0),
+            (220.0, 200.0),
+            (120.0, 200.0),
+        ];
+        assert!(is_point_in_polygon((110.0, 60.0), &polygon));
+        assert!(is_point_in_polygon((150.0, 150.0), &polygon));
+        assert!(!is_point_in_polygon((100.0, 150.0), &polygon));
+        assert!(!is_point_in_polygon((250.0, 50.0), &polygon));
+    }
+}
@@ -0,0 +1,23 @@
+use leptos_radix_ui::{FormMatch, FormValidity};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validity_matches_native_flags() {
+        let validity = FormValidity {
+            value_missing: true,
+            ..Default::default()
+        };
+        assert!(validity.matches(&FormMatch::ValueMissing));
+        assert!(!validity.matches(&FormMatch::TypeMismatch));
+        assert!(!validity.matches(&FormMatch::Valid));
+
+        let valid = FormValidity {
+            valid: true,
+            ..Default::default()
+        };
+        assert!(valid.matches(&FormMatch::Valid));
+    }
+}
@@ -0,0 +1,70 @@
+use leptos_radix_ui::hooks::{compute_position, Align, FloatingOptions, Rect, Side};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VIEWPORT: (f64, f64) = (800.0, 600.0);
+
+    fn anchor() -> Rect {
+        Rect {
+            x: 100.0,
+            y: 100.0,
+            width: 40.0,
+            height: 20.0,
+        }
+    }
+
+    #[test]
+    fn test_bottom_center_placement() {
+        let options = FloatingOptions {
+            side_offset: 8.0,
+            ..Default::default()
+        };
+        let placement = compute_position(anchor(), (60.0, 30.0), VIEWPORT, &options);
+
+        assert_eq!(placement.side, Side::Bottom);
+        assert_eq!(placement.x, 90.0);
+        assert_eq!(placement.y, 128.0);
+        assert_eq!(placement.arrow_offset, 30.0);
+    }
+
+    #[test]
+    fn test_flips_to_opposite_side_on_collision() {
+        let options = FloatingOptions {
+            side: Side::Top,
+            ..Default::default()
+        };
+        let placement = compute_position(anchor(), (60.0, 200.0), VIEWPORT, &options);
+
+        assert_eq!(placement.side, Side::Bottom);
+        assert_eq!(placement.y, 120.0);
+    }
+
+    #[test]
+    fn test_shifts_along_cross_axis_to_stay_in_viewport() {
+        let options = FloatingOptions {
+            align: Align::End,
+            collision_padding: 10.0,
+            ..Default::default()
+        };
+        let placement = compute_position(anchor(), (300.0, 30.0), VIEWPORT, &options);
+
+        assert_eq!(placement.x, 10.0);
+        assert_eq!(placement.arrow_offset, 110.0);
+    }
+
+    #[test]
+    fn test_collisions_ignored_when_disabled() {
+        let options = FloatingOptions {
+            side: Side::Left,
+            avoid_collisions: false,
+            ..Default::default()
+        };
+        let placement = compute_position(anchor(), (200.0, 30.0), VIEWPORT, &options);
+
+        assert_eq!(placement.side, Side::Left);
+        assert_eq!(placement.x, -100.0);
+        assert_eq!(placement.y, 95.0);
+    }
+}
@@ -1,4 +1,4 @@
-use leptos_radix_ui::{Progress, ProgressIndicator, Separator};
+use leptos_radix_ui::{ProgressIndicatorProps, ProgressProps, SeparatorProps};
 
 #[cfg(test)]
 mod tests {
@@ -8,23 +8,30 @@ mod tests {
     fn test_progress_component_exists() {
         // Test that Progress component types exist and can be referenced
         // This is a compilation test - if it compiles, the components are properly exported
-        let _progress_type = std::any::type_name::<Progress>();
-        let _indicator_type = std::any::type_name::<ProgressIndicator>();
-        assert!(true);
+        let progress_type = std::any::type_name::<ProgressProps>();
+        let indicator_type = std::any::type_name::<ProgressIndicatorProps>();
+        assert!(progress_type.ends_with("ProgressProps"));
+        assert!(indicator_type.ends_with("ProgressIndicatorProps"));
     }
 
     #[test]
     fn test_separator_component_exists() {
         // Test that Separator component type exists and can be referenced
         // This is a compilation test - if it compiles, the component is properly exported
-        let _separator_type = std::any::type_name::<Separator>();
-        assert!(true);
+        let separator_type = std::any::type_name::<SeparatorProps>();
+        assert!(separator_type.ends_with("SeparatorProps"));
     }
 
     #[test]
     fn test_component_compilation() {
         // Test that the components can be compiled without runtime issues
         // This ensures the component definitions are syntactically correct
-        assert!(true);
+        let names = [
+            std::any::type_name::<ProgressProps>(),
+            std::any::type_name::<SeparatorProps>(),
+        ];
+        assert!(names
+            .iter()
+            .all(|name| name.starts_with("leptos_radix_ui::")));
     }
 }
@@ -0,0 +1,56 @@
+use leptos_radix_ui::get_radio_movement;
+use leptos_radix_ui::utils::Movement;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_all_arrows_move_without_orientation() {
+        assert_eq!(
+            get_radio_movement("ArrowDown", None, false),
+            Some(Movement::Next)
+        );
+        assert_eq!(
+            get_radio_movement("ArrowRight", None, false),
+            Some(Movement::Next)
+        );
+        assert_eq!(
+            get_radio_movement("ArrowUp", None, false),
+            Some(Movement::Previous)
+        );
+        assert_eq!(
+            get_radio_movement("ArrowLeft", None, false),
+            Some(Movement::Previous)
+        );
+        assert_eq!(get_radio_movement("Enter", None, false), None);
+    }
+
+    #[test]
+    fn test_orientation_limits_arrows() {
+        assert_eq!(
+            get_radio_movement("ArrowRight", Some("vertical"), false),
+            None
+        );
+        assert_eq!(
+            get_radio_movement("ArrowDown", Some("horizontal"), false),
+            None
+        );
+        assert_eq!(
+            get_radio_movement("ArrowDown", Some("vertical"), false),
+            Some(Movement::Next)
+        );
+    }
+
+    #[test]
+    fn test_rtl_swaps_left_and_right() {
+        assert_eq!(
+            get_radio_movement("ArrowLeft", None, true),
+            Some(Movement::Next)
+        );
+        assert_eq!(
+            get_radio_movement("ArrowRight", None, true),
+            Some(Movement::Previous)
+        );
+    }
+}
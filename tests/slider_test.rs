@@ -0,0 +1,46 @@
+use leptos_radix_ui::hooks::{
+    get_closest_value_index, snap_to_step, update_slider_values, value_to_percent,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snap_to_step() {
+        assert_eq!(snap_to_step(42.0, 0.0, 100.0, 5.0), 40.0);
+        assert_eq!(snap_to_step(43.0, 0.0, 100.0, 5.0), 45.0);
+        assert_eq!(snap_to_step(0.30000000000000004, 0.0, 1.0, 0.1), 0.3);
+        assert_eq!(snap_to_step(120.0, 0.0, 100.0, 1.0), 100.0);
+        assert_eq!(snap_to_step(-3.0, 0.0, 100.0, 1.0), 0.0);
+    }
+
+    #[test]
+    fn test_value_to_percent() {
+        assert_eq!(value_to_percent(25.0, 0.0, 100.0), 25.0);
+        assert_eq!(value_to_percent(15.0, 10.0, 20.0), 50.0);
+        assert_eq!(value_to_percent(5.0, 10.0, 10.0), 0.0);
+    }
+
+    #[test]
+    fn test_closest_thumb() {
+        assert_eq!(get_closest_value_index(&[20.0, 80.0], 30.0), 0);
+        assert_eq!(get_closest_value_index(&[20.0, 80.0], 70.0), 1);
+        // Stacked thumbs: moving past them grabs the later one
+        assert_eq!(get_closest_value_index(&[100.0, 100.0], 100.0), 0);
+        assert_eq!(get_closest_value_index(&[50.0, 50.0], 60.0), 1);
+    }
+
+    #[test]
+    fn test_thumbs_stay_sorted() {
+        let (values, index) = update_slider_values(&[20.0, 80.0], 0, 90.0, 0.0).unwrap();
+        assert_eq!(values, vec![80.0, 90.0]);
+        assert_eq!(index, 1);
+    }
+
+    #[test]
+    fn test_min_steps_between_thumbs() {
+        assert!(update_slider_values(&[20.0, 80.0], 0, 75.0, 10.0).is_none());
+        assert!(update_slider_values(&[20.0, 80.0], 0, 70.0, 10.0).is_some());
+    }
+}
@@ -0,0 +1,62 @@
+use leptos_radix_ui::utils::Movement;
+use leptos_radix_ui::{get_tabs_movement, TabsActivationMode};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_activation_mode_defaults_to_automatic() {
+        assert_eq!(TabsActivationMode::default(), TabsActivationMode::Automatic);
+    }
+
+    #[test]
+    fn test_horizontal_movement() {
+        assert_eq!(
+            get_tabs_movement("ArrowRight", "horizontal", false),
+            Some(Movement::Next)
+        );
+        assert_eq!(
+            get_tabs_movement("ArrowLeft", "horizontal", false),
+            Some(Movement::Previous)
+        );
+        assert_eq!(get_tabs_movement("ArrowDown", "horizontal", false), None);
+    }
+
+    #[test]
+    fn test_vertical_movement() {
+        assert_eq!(
+            get_tabs_movement("ArrowDown", "vertical", false),
+            Some(Movement::Next)
+        );
+        assert_eq!(
+            get_tabs_movement("ArrowUp", "vertical", true),
+            Some(Movement::Previous)
+        );
+        assert_eq!(get_tabs_movement("ArrowRight", "vertical", false), None);
+    }
+
+    #[test]
+    fn test_rtl_swaps_left_and_right() {
+        assert_eq!(
+            get_tabs_movement("ArrowRight", "horizontal", true),
+            Some(Movement::Previous)
+        );
+        assert_eq!(
+            get_tabs_movement("ArrowLeft", "horizontal", true),
+            Some(Movement::Next)
+        );
+    }
+
+    #[test]
+    fn test_home_and_end() {
+        assert_eq!(
+            get_tabs_movement("Home", "vertical", false),
+            Some(Movement::First)
+        );
+        assert_eq!(
+            get_tabs_movement("End", "horizontal", true),
+            Some(Movement::Last)
+        );
+    }
+}
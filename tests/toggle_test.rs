@@ -0,0 +1,51 @@
+use leptos_radix_ui::utils::{get_roving_focus_movement, JumpKeys, Movement};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roving_focus_movement() {
+        assert_eq!(
+            get_roving_focus_movement("ArrowRight", None, false, JumpKeys::HomeEndPage),
+            Some(Movement::Next)
+        );
+        assert_eq!(
+            get_roving_focus_movement("ArrowRight", None, true, JumpKeys::HomeEndPage),
+            Some(Movement::Previous)
+        );
+        assert_eq!(
+            get_roving_focus_movement(
+                "ArrowDown",
+                Some("horizontal"),
+                false,
+                JumpKeys::HomeEndPage
+            ),
+            None
+        );
+        assert_eq!(
+            get_roving_focus_movement("End", Some("vertical"), false, JumpKeys::HomeEndPage),
+            Some(Movement::Last)
+        );
+    }
+
+    #[test]
+    fn test_roving_focus_jump_keys() {
+        assert_eq!(
+            get_roving_focus_movement("PageUp", None, false, JumpKeys::HomeEndPage),
+            Some(Movement::First)
+        );
+        assert_eq!(
+            get_roving_focus_movement("PageUp", None, false, JumpKeys::HomeEnd),
+            None
+        );
+        assert_eq!(
+            get_roving_focus_movement("Home", None, false, JumpKeys::HomeEnd),
+            Some(Movement::First)
+        );
+        assert_eq!(
+            get_roving_focus_movement("End", None, false, JumpKeys::None),
+            None
+        );
+    }
+}